@@ -0,0 +1,681 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use crate::naming::{pascal_case, snake_case, field_name, variant_name};
+use crate::schema::{Function, Namespace, Property};
+
+/// A Rust type for a schema property, and which derives it allows.
+#[derive(Debug, Clone)]
+struct RustType {
+    name: String,
+    kind: Kind,
+    eq: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Primitive,
+    String,
+    Bytes,
+    Serde,
+    /// A struct with binary fields, which serializes through its `to_js_value` instead of `Serialize`
+    /// so they become `ArrayBuffer`s rather than arrays.
+    Binary,
+    /// Anything the generator can't type, kept as a raw `JsValue`.
+    Opaque,
+}
+
+impl RustType {
+    fn new(name: &str, kind: Kind, eq: bool) -> Self {
+        Self { name: name.to_owned(), kind, eq }
+    }
+
+    fn opaque() -> Self {
+        Self::new("JsValue", Kind::Opaque, false)
+    }
+}
+
+pub struct Generator<'a> {
+    namespace: &'a Namespace,
+    /// The types declared so far, keyed by Rust name.
+    declared: BTreeMap<String, RustType>,
+    items: Vec<String>,
+    uses_promise: bool,
+    uses_future: bool,
+    uses_reflect: bool,
+    uses_error: bool,
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(namespace: &'a Namespace) -> Self {
+        Self {
+            namespace,
+            declared: BTreeMap::new(),
+            items: Vec::new(),
+            uses_promise: false,
+            uses_future: false,
+            uses_reflect: false,
+            uses_error: false,
+        }
+    }
+
+    fn js_namespace(&self) -> String {
+        std::iter::once("chrome")
+            .chain(self.namespace.namespace.split('.'))
+            .map(|part| format!("\"{}\"", part))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn generate(mut self) -> String {
+        for ty in &self.namespace.types {
+            if ty.nodoc {
+                continue;
+            }
+            if let Some(id) = &ty.id {
+                self.declare(&pascal_case(id), ty);
+            }
+        }
+
+        let functions = self.functions();
+        let events = self.events();
+
+        let mut out = String::new();
+        out.push_str("use wasm_bindgen::prelude::*;\n");
+        match (self.uses_promise, self.uses_reflect) {
+            (true, true) => out.push_str("use js_sys::{Promise, Reflect};\n"),
+            (true, false) => out.push_str("use js_sys::Promise;\n"),
+            (false, true) => out.push_str("use js_sys::Reflect;\n"),
+            (false, false) => {}
+        }
+        if self.uses_future {
+            out.push_str("use wasm_bindgen_futures::JsFuture;\n");
+        }
+        out.push_str("use serde::{Serialize, Deserialize};\n");
+        if self.uses_promise {
+            out.push_str("use crate::utils::resolve;\n");
+        }
+        if self.uses_error {
+            out.push_str("use crate::error::Error;\n");
+        }
+
+        for item in self.items.iter().chain(&functions).chain(&events) {
+            out.push('\n');
+            out.push_str(item);
+        }
+
+        out
+    }
+
+    /// Declares a named type (enum or struct) and returns how to refer to it.
+    fn declare(&mut self, name: &str, property: &Property) -> RustType {
+        if let Some(ty) = self.declared.get(name) {
+            return ty.clone();
+        }
+
+        if let Some(values) = &property.enum_values {
+            self.declared.insert(name.to_owned(), RustType::new(name, Kind::Serde, true));
+
+            let mut item = String::new();
+            item.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]\n");
+            item.push_str("#[serde(rename_all = \"camelCase\")]\n");
+            writeln!(item, "pub enum {} {{", name).unwrap();
+            for value in values {
+                let (variant, renamed) = variant_name(value.name());
+                if renamed {
+                    writeln!(item, "    #[serde(rename = \"{}\")]", value.name()).unwrap();
+                }
+                writeln!(item, "    {},", variant).unwrap();
+            }
+            item.push_str("}\n");
+            self.items.push(item);
+
+            return RustType::new(name, Kind::Serde, true);
+        }
+
+        if let Some(properties) = &property.properties {
+            // Registered first so self-referencing types terminate.
+            self.declared.insert(name.to_owned(), RustType::new(name, Kind::Serde, false));
+
+            let (fields, untyped): (Vec<_>, Vec<_>) = properties
+                .iter()
+                .filter(|(_, p)| !p.nodoc)
+                .map(|(key, p)| {
+                    let ty = self.rust_type(&format!("{}{}", name, pascal_case(key)), p);
+                    (key.clone(), field_name(key), ty, p.optional)
+                })
+                .partition(|(_, _, ty, _)| ty.kind != Kind::Opaque);
+
+            let eq = fields.iter().all(|(_, _, ty, _)| ty.eq);
+            let default = fields.iter().all(|(_, _, _, optional)| *optional);
+            let binary = fields.iter().any(|(_, _, ty, _)| matches!(ty.kind, Kind::Bytes | Kind::Binary));
+            let kind = if binary { Kind::Binary } else { Kind::Serde };
+            self.declared.insert(name.to_owned(), RustType::new(name, kind, eq));
+
+            let mut derives = vec!["Debug", "Clone"];
+            if default {
+                derives.push("Default");
+            }
+            derives.push("PartialEq");
+            if eq {
+                derives.push("Eq");
+            }
+            if !binary {
+                derives.push("Serialize");
+            }
+            derives.push("Deserialize");
+
+            let mut item = String::new();
+            if !untyped.is_empty() {
+                // serde_wasm_bindgen 0.3 can't carry a raw `JsValue` through a struct.
+                let keys: Vec<String> = untyped.iter().map(|(key, _, _, _)| format!("`{}`", key)).collect();
+                writeln!(item, "/// Leaves out {}, which the generator can't type.", keys.join(", ")).unwrap();
+            }
+            writeln!(item, "#[derive({})]", derives.join(", ")).unwrap();
+            item.push_str("#[serde(rename_all = \"camelCase\")]\n");
+            writeln!(item, "pub struct {} {{", name).unwrap();
+            for (key, field, ty, optional) in &fields {
+                if snake_to_camel(field.trim_start_matches("r#")) != *key {
+                    writeln!(item, "    #[serde(rename = \"{}\")]", key).unwrap();
+                }
+                match ty.kind {
+                    Kind::Bytes => {
+                        if *optional {
+                            item.push_str("    #[serde(default, deserialize_with = \"crate::utils::deserialize_optional_bytes\")]\n");
+                            writeln!(item, "    pub {}: Option<Vec<u8>>,", field).unwrap();
+                        } else {
+                            item.push_str("    #[serde(deserialize_with = \"crate::utils::deserialize_bytes\")]\n");
+                            writeln!(item, "    pub {}: Vec<u8>,", field).unwrap();
+                        }
+                    }
+                    _ if *optional => writeln!(item, "    pub {}: Option<{}>,", field, ty.name).unwrap(),
+                    _ => writeln!(item, "    pub {}: {},", field, ty.name).unwrap(),
+                }
+            }
+            item.push_str("}\n");
+            self.items.push(item);
+
+            if binary {
+                let conversion = self.binary_conversion(name, &fields);
+                self.items.push(conversion);
+            }
+
+            return RustType::new(name, kind, eq);
+        }
+
+        let ty = self.rust_type(name, &Property { enum_values: None, properties: None, ..property.clone() });
+        if ty.kind != Kind::Opaque {
+            self.items.push(format!("pub type {} = {};\n", name, ty.name));
+        }
+
+        ty
+    }
+
+    /// Serializes a struct's other fields through a borrowed copy of it, then sets its binary
+    /// fields as `ArrayBuffer`s, the way `wallpaper::set_wallpaper` does by hand.
+    fn binary_conversion(&mut self, name: &str, fields: &[(String, String, RustType, bool)]) -> String {
+        self.uses_reflect = true;
+        self.uses_error = true;
+
+        let mut item = String::new();
+        item.push_str("#[derive(Serialize)]\n");
+        item.push_str("#[serde(rename_all = \"camelCase\")]\n");
+        writeln!(item, "struct {}Fields<'a> {{", name).unwrap();
+        for (key, field, ty, optional) in fields {
+            if matches!(ty.kind, Kind::Bytes | Kind::Binary) {
+                continue;
+            }
+            if snake_to_camel(field.trim_start_matches("r#")) != *key {
+                writeln!(item, "    #[serde(rename = \"{}\")]", key).unwrap();
+            }
+            if *optional {
+                writeln!(item, "    {}: &'a Option<{}>,", field, ty.name).unwrap();
+            } else {
+                writeln!(item, "    {}: &'a {},", field, ty.name).unwrap();
+            }
+        }
+        item.push_str("}\n\n");
+
+        writeln!(item, "impl {} {{", name).unwrap();
+        item.push_str("    pub(crate) fn to_js_value(&self) -> Result<JsValue, Error> {\n");
+        writeln!(item, "        let value = serde_wasm_bindgen::to_value(&{}Fields {{", name).unwrap();
+        for (_, field, ty, _) in fields {
+            if !matches!(ty.kind, Kind::Bytes | Kind::Binary) {
+                writeln!(item, "            {}: &self.{},", field, field).unwrap();
+            }
+        }
+        item.push_str("        })?;\n");
+        for (key, field, ty, optional) in fields {
+            let convert = match ty.kind {
+                Kind::Bytes => "crate::utils::to_array_buffer({}).into()",
+                Kind::Binary => "{}.to_js_value()?",
+                _ => continue,
+            };
+            if *optional {
+                writeln!(item, "\n        if let Some({}) = &self.{} {{", field, field).unwrap();
+                writeln!(item, "            Reflect::set(&value, &\"{}\".into(), &{})?;", key, convert.replace("{}", field)).unwrap();
+                item.push_str("        }\n");
+            } else {
+                let this = format!("self.{}", field);
+                let this = if ty.kind == Kind::Bytes { format!("&{}", this) } else { this };
+                writeln!(item, "\n        Reflect::set(&value, &\"{}\".into(), &{})?;", key, convert.replace("{}", &this)).unwrap();
+            }
+        }
+        item.push_str("\n        Ok(value)\n    }\n}\n");
+        item
+    }
+
+    /// The Rust type for a property, declaring inline enums and objects as `name`.
+    fn rust_type(&mut self, name: &str, property: &Property) -> RustType {
+        if let Some(reference) = &property.reference {
+            return match reference.rsplit_once('.') {
+                Some((namespace, ty)) if namespace != self.namespace.namespace => {
+                    let path = format!("crate::{}::{}", snake_case(namespace), pascal_case(ty));
+                    RustType::new(&path, Kind::Serde, false)
+                }
+                Some((_, ty)) => self.local_type(ty),
+                None => self.local_type(reference),
+            };
+        }
+
+        if property.enum_values.is_some() || property.properties.is_some() {
+            return self.declare(name, property);
+        }
+
+        match property.kind.as_deref() {
+            Some("string") => RustType::new("String", Kind::String, true),
+            Some("integer") => RustType::new("i32", Kind::Primitive, true),
+            Some("number") => RustType::new("f64", Kind::Primitive, false),
+            Some("boolean") => RustType::new("bool", Kind::Primitive, true),
+            Some("binary") => RustType::new("Vec<u8>", Kind::Bytes, true),
+            Some("array") => match &property.items {
+                Some(items) => {
+                    let item = self.rust_type(name, items);
+                    match item.kind {
+                        Kind::Opaque | Kind::Bytes | Kind::Binary => RustType::opaque(),
+                        _ => RustType::new(&format!("Vec<{}>", item.name), Kind::Serde, item.eq),
+                    }
+                }
+                None => RustType::opaque(),
+            },
+            _ => RustType::opaque(),
+        }
+    }
+
+    fn local_type(&mut self, id: &str) -> RustType {
+        let name = pascal_case(id);
+
+        if let Some(ty) = self.declared.get(&name) {
+            return ty.clone();
+        }
+
+        match self.namespace.types.iter().find(|t| t.id.as_deref() == Some(id)).cloned() {
+            Some(ty) => self.declare(&name, &ty),
+            None => RustType::opaque(),
+        }
+    }
+
+    fn functions(&mut self) -> Vec<String> {
+        let js_namespace = self.js_namespace();
+        let mut externs = Vec::new();
+        let mut wrappers = Vec::new();
+
+        for function in self.namespace.functions.iter().filter(|f| !f.nodoc) {
+            let (params, callback) = split_callback(function);
+            let fn_name = snake_case(&function.name);
+            let prefix = pascal_case(&function.name);
+
+            let params: Vec<(String, RustType, bool)> = params
+                .iter()
+                .map(|p| {
+                    let name = p.name.clone().unwrap_or_default();
+                    let ty = self.rust_type(&format!("{}{}", prefix, pascal_case(&name)), p);
+                    (field_name(&name), ty, p.optional)
+                })
+                .collect();
+
+            let extern_params = params
+                .iter()
+                .map(|(name, ty, optional)| format!("{}: {}", name, extern_type(ty, *optional)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let wrapper_params = params
+                .iter()
+                .map(|(name, ty, optional)| format!("{}: {}", name, wrapper_type(ty, *optional)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let needs_conversion = params.iter().any(|(_, ty, _)| matches!(ty.kind, Kind::Serde | Kind::Bytes | Kind::Binary));
+            let args = params
+                .iter()
+                .map(|(name, ty, optional)| convert_arg(name, ty, *optional))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let result = match &callback {
+                Some(callback) => Some(callback.first().cloned()),
+                None => function.returns.clone().map(Some),
+            };
+
+            let mut attribute = format!("    #[wasm_bindgen(js_namespace = [{}], js_name = {})]\n", js_namespace, function.name);
+
+            match (callback.is_some(), result) {
+                (true, result) => {
+                    self.uses_promise = true;
+                    self.uses_error = true;
+
+                    writeln!(attribute, "    fn _{}({}) -> Promise;", fn_name, extern_params).unwrap();
+                    externs.push(attribute);
+
+                    wrappers.push(match self.output_type(&prefix, result.flatten().as_ref()) {
+                        Some(output) => format!(
+                            "pub async fn {}({}) -> Result<{}, Error> {{\n    resolve(_{}({})).await\n}}\n",
+                            fn_name, wrapper_params, output, fn_name, args,
+                        ),
+                        None => {
+                            self.uses_future = true;
+                            format!(
+                                "pub async fn {}({}) -> Result<JsValue, Error> {{\n    Ok(JsFuture::from(_{}({})).await?)\n}}\n",
+                                fn_name, wrapper_params, fn_name, args,
+                            )
+                        }
+                    });
+                }
+                (false, Some(result)) => {
+                    self.uses_error = true;
+
+                    writeln!(attribute, "    fn _{}({}) -> JsValue;", fn_name, extern_params).unwrap();
+                    externs.push(attribute);
+
+                    wrappers.push(match self.output_type(&prefix, result.as_ref()) {
+                        Some(output) => format!(
+                            "pub fn {}({}) -> Result<{}, Error> {{\n    Ok(serde_wasm_bindgen::from_value(_{}({}))?)\n}}\n",
+                            fn_name, wrapper_params, output, fn_name, args,
+                        ),
+                        None => format!(
+                            "pub fn {}({}) -> Result<JsValue, Error> {{\n    Ok(_{}({}))\n}}\n",
+                            fn_name, wrapper_params, fn_name, args,
+                        ),
+                    });
+                }
+                (false, None) if needs_conversion => {
+                    self.uses_error = true;
+
+                    writeln!(attribute, "    fn _{}({});", fn_name, extern_params).unwrap();
+                    externs.push(attribute);
+
+                    wrappers.push(format!(
+                        "pub fn {}({}) -> Result<(), Error> {{\n    _{}({});\n\n    Ok(())\n}}\n",
+                        fn_name, wrapper_params, fn_name, args,
+                    ));
+                }
+                (false, None) => {
+                    writeln!(attribute, "    pub fn {}({});", fn_name, extern_params).unwrap();
+                    externs.push(attribute);
+                }
+            }
+        }
+
+        if externs.is_empty() {
+            return Vec::new();
+        }
+
+        let mut items = vec![format!("#[wasm_bindgen]\nextern \"C\" {{\n{}}}\n", externs.join("\n"))];
+        items.extend(wrappers);
+        items
+    }
+
+    /// What a function resolves with, or `None` if the generator can't type
+    /// it, in which case the wrapper hands back the raw value.
+    fn output_type(&mut self, prefix: &str, result: Option<&Property>) -> Option<String> {
+        match result {
+            None => Some("()".to_owned()),
+            Some(property) => {
+                let name = format!("{}{}", prefix, pascal_case(property.name.as_deref().unwrap_or("Result")));
+                let ty = self.rust_type(&name, property);
+
+                match ty.kind {
+                    Kind::Opaque => None,
+                    _ if property.optional => Some(format!("Option<{}>", ty.name)),
+                    _ => Some(ty.name),
+                }
+            }
+        }
+    }
+
+    fn events(&mut self) -> Vec<String> {
+        let mut modules = Vec::new();
+
+        for event in self.namespace.events.iter().filter(|e| !e.nodoc) {
+            let prefix = pascal_case(&event.name);
+            let params: Vec<(String, RustType, bool)> = event.parameters
+                .iter()
+                .map(|p| {
+                    let name = p.name.clone().unwrap_or_default();
+                    let ty = self.rust_type(&format!("{}{}", prefix, pascal_case(&name)), p);
+                    (field_name(&name), ty, p.optional)
+                })
+                .collect();
+
+            modules.push(self.event_module(event, &params));
+        }
+
+        modules
+    }
+
+    fn event_module(&self, event: &Function, params: &[(String, RustType, bool)]) -> String {
+        let js_namespace = self.js_namespace();
+        let types: Vec<String> = params
+            .iter()
+            .map(|(_, ty, optional)| {
+                if *optional && ty.kind != Kind::Opaque { format!("Option<{}>", ty.name) } else { ty.name.clone() }
+            })
+            .collect();
+        let imports: Vec<&str> = self.declared
+            .keys()
+            .map(String::as_str)
+            .filter(|name| types.iter().any(|ty| mentions(ty, name)))
+            .collect();
+        let names: Vec<&str> = params.iter().map(|(name, _, _)| name.as_str()).collect();
+
+        let js_args = vec!["JsValue"; params.len()].join(", ");
+        let closure_params = names.iter().map(|name| format!("{}: JsValue", name)).collect::<Vec<_>>().join(", ");
+        let converted = params
+            .iter()
+            .map(|(name, ty, _)| match ty.kind {
+                Kind::Opaque => name.clone(),
+                _ => format!("event_arg!({})", name),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let bound = types.join(", ");
+
+        let (item, stream) = match params.len() {
+            0 => ("()".to_owned(), "stream_of(|mut callback| listen(move || callback(())))".to_owned()),
+            1 => (types[0].clone(), "stream_of(listen)".to_owned()),
+            _ => {
+                let names = names.join(", ");
+                (format!("({})", bound), format!("stream_of(|mut callback| listen(move |{}| callback(({}))))", names, names))
+            }
+        };
+
+        let mut module = String::new();
+        writeln!(module, "pub mod {} {{", snake_case(&event.name)).unwrap();
+        module.push_str("    use wasm_bindgen::prelude::*;\n");
+        match imports.as_slice() {
+            [] => {}
+            [import] => writeln!(module, "    use super::{};", import).unwrap(),
+            imports => writeln!(module, "    use super::{{{}}};", imports.join(", ")).unwrap(),
+        }
+        if params.iter().all(|(_, ty, _)| ty.kind == Kind::Opaque) {
+            module.push_str("    use crate::events::{Event, EventHandle, EventStream, stream_of};\n\n");
+        } else {
+            module.push_str("    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};\n\n");
+        }
+        module.push_str("    #[wasm_bindgen]\n    extern \"C\" {\n");
+        writeln!(module, "        #[wasm_bindgen(js_namespace = [{}, \"{}\"], js_name = addListener)]", js_namespace, event.name).unwrap();
+        writeln!(module, "        pub fn add_listener(callback: &Closure<dyn FnMut({})>);\n", js_args).unwrap();
+        writeln!(module, "        #[wasm_bindgen(thread_local_v2, js_namespace = [{}], js_name = {})]", js_namespace, event.name).unwrap();
+        module.push_str("        static EVENT: Event;\n    }\n\n");
+        writeln!(module, "    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut({})>", js_args).unwrap();
+        writeln!(module, "        where T: FnMut({}) + 'static,\n    {{", bound).unwrap();
+        writeln!(module, "        Closure::wrap(Box::new(move |{}| {{", closure_params).unwrap();
+        writeln!(module, "            callback({});", converted).unwrap();
+        module.push_str("        }))\n    }\n\n");
+        module.push_str("    pub fn listen<T>(callback: T) -> EventHandle\n");
+        writeln!(module, "        where T: FnMut({}) + 'static,\n    {{", bound).unwrap();
+        module.push_str("        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))\n    }\n\n");
+        writeln!(module, "    pub fn stream() -> EventStream<{}> {{\n        {}\n    }}", item, stream).unwrap();
+        module.push_str("}\n");
+        module
+    }
+}
+
+/// Separates the trailing callback parameter used by older schemas from the real parameters.
+fn split_callback(function: &Function) -> (Vec<Property>, Option<Vec<Property>>) {
+    if let Some(returns_async) = &function.returns_async {
+        return (function.parameters.clone(), Some(returns_async.parameters.clone()));
+    }
+
+    let mut params = function.parameters.clone();
+    match params.last() {
+        Some(last) if last.kind.as_deref() == Some("function") => {
+            let callback = params.pop().unwrap();
+            (params, Some(callback.parameters.unwrap_or_default()))
+        }
+        _ => (params, None),
+    }
+}
+
+fn extern_type(ty: &RustType, optional: bool) -> String {
+    match (ty.kind, optional) {
+        (Kind::Primitive, false) => ty.name.clone(),
+        (Kind::Primitive, true) => format!("Option<{}>", ty.name),
+        (Kind::String, false) => "&str".to_owned(),
+        (Kind::String, true) => "Option<String>".to_owned(),
+        _ => "JsValue".to_owned(),
+    }
+}
+
+fn wrapper_type(ty: &RustType, optional: bool) -> String {
+    match (ty.kind, optional) {
+        (Kind::Primitive, _) | (Kind::String, _) => extern_type(ty, optional),
+        (Kind::Bytes, false) => "&[u8]".to_owned(),
+        (Kind::Bytes, true) => "Option<&[u8]>".to_owned(),
+        (Kind::Opaque, _) => "JsValue".to_owned(),
+        (Kind::Serde, false) | (Kind::Binary, false) => format!("&{}", ty.name),
+        (Kind::Serde, true) | (Kind::Binary, true) => format!("Option<&{}>", ty.name),
+    }
+}
+
+fn convert_arg(name: &str, ty: &RustType, optional: bool) -> String {
+    match (ty.kind, optional) {
+        (Kind::Serde, false) => format!("serde_wasm_bindgen::to_value({})?", name),
+        (Kind::Serde, true) => format!("serde_wasm_bindgen::to_value(&{})?", name),
+        (Kind::Bytes, false) => format!("crate::utils::to_array_buffer({}).into()", name),
+        (Kind::Bytes, true) => format!("{}.map(|b| crate::utils::to_array_buffer(b).into()).unwrap_or(JsValue::UNDEFINED)", name),
+        (Kind::Binary, false) => format!("{}.to_js_value()?", name),
+        (Kind::Binary, true) => format!("{}.map(|v| v.to_js_value()).transpose()?.unwrap_or(JsValue::UNDEFINED)", name),
+        _ => name.to_owned(),
+    }
+}
+
+/// Whether `name` appears in `ty` as a whole identifier.
+fn mentions(ty: &str, name: &str) -> bool {
+    ty.split(|c: char| !c.is_alphanumeric() && c != '_' && c != ':')
+        .any(|part| part == name)
+}
+
+fn snake_to_camel(name: &str) -> String {
+    let mut camel = String::new();
+    let mut upper = false;
+
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            camel.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+
+    camel
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema;
+    use super::Generator;
+
+    fn generate(source: &str) -> String {
+        let namespaces = schema::parse(source).unwrap();
+
+        Generator::new(&namespaces[0]).generate()
+    }
+
+    #[test]
+    fn binary_structs_serialize_through_array_buffers() {
+        let out = generate(r#"[{
+            "namespace": "scan",
+            "types": [{"id": "Page", "type": "object", "properties": {
+                "data": {"type": "binary"},
+                "label": {"type": "string"}
+            }}],
+            "functions": [{"name": "save", "type": "function", "parameters": [{"name": "page", "$ref": "Page"}]}]
+        }]"#);
+
+        assert!(out.contains("#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]\n#[serde(rename_all = \"camelCase\")]\npub struct Page {"));
+        assert!(out.contains("struct PageFields<'a> {\n    label: &'a String,\n}"));
+        assert!(out.contains("Reflect::set(&value, &\"data\".into(), &crate::utils::to_array_buffer(&self.data).into())?;"));
+        assert!(out.contains("_save(page.to_js_value()?);"));
+        assert!(out.contains("use js_sys::Reflect;"));
+    }
+
+    #[test]
+    fn other_structs_derive_serialize() {
+        let out = generate(r#"[{
+            "namespace": "scan",
+            "types": [{"id": "Options", "type": "object", "properties": {"label": {"type": "string", "optional": true}}}]
+        }]"#);
+
+        assert!(out.contains("#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]"));
+        assert!(!out.contains("to_js_value"));
+    }
+
+    #[test]
+    fn event_arguments_are_read_with_event_arg() {
+        let out = generate(r#"[{
+            "namespace": "idle",
+            "events": [{"name": "onStateChanged", "type": "function", "parameters": [{"name": "newState", "type": "string"}]}]
+        }]"#);
+
+        assert!(out.contains("use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};"));
+        assert!(out.contains("callback(event_arg!(new_state));"));
+    }
+
+    #[test]
+    fn untyped_fields_are_left_out_with_a_note() {
+        let out = generate(r#"[{
+            "namespace": "scan",
+            "types": [{"id": "Options", "type": "object", "properties": {
+                "label": {"type": "string"},
+                "extra": {"type": "any"}
+            }}]
+        }]"#);
+
+        assert!(out.contains("/// Leaves out `extra`, which the generator can't type.\n#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]"));
+        assert!(!out.contains("pub extra"));
+    }
+
+    #[test]
+    fn untyped_results_are_returned_raw() {
+        let out = generate(r#"[{
+            "namespace": "scan",
+            "functions": [{"name": "read", "type": "function", "parameters": [],
+                "returns_async": {"name": "callback", "parameters": [{"name": "result", "type": "any"}]}}]
+        }]"#);
+
+        assert!(out.contains("pub async fn read() -> Result<JsValue, Error> {\n    Ok(JsFuture::from(_read()).await?)\n}"));
+        assert!(out.contains("use wasm_bindgen_futures::JsFuture;"));
+    }
+}
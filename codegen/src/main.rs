@@ -0,0 +1,63 @@
+//! Generates binding modules from Chromium's extension API schemas.
+//!
+//! Usage: `cargo run -p web-extension-sys-codegen -- path/to/idle.json > src/idle.rs`
+//!
+//! The schemas live in `chrome/common/extensions/api/` and `extensions/common/api/` in the Chromium
+//! source. Only the JSON schemas are supported, not the `.idl` ones. The output follows this crate's
+//! conventions, but is a starting point: check it, trim what isn't needed and hand-type anything
+//! it left as a `JsValue` or left out.
+
+mod emit;
+mod naming;
+mod schema;
+
+use std::process;
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+
+    let path = match args.next() {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: web-extension-sys-codegen <schema.json> [namespace]");
+            process::exit(2);
+        }
+    };
+    let only = args.next();
+
+    let source = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("failed to read {}: {}", path, e);
+        process::exit(1);
+    });
+    let namespaces = schema::parse(&source).unwrap_or_else(|e| {
+        eprintln!("failed to parse {}: {}", path, e);
+        process::exit(1);
+    });
+
+    let namespaces: Vec<_> = namespaces
+        .iter()
+        .filter(|namespace| !namespace.nodoc)
+        .filter(|namespace| only.as_ref().is_none_or(|only| &namespace.namespace == only))
+        .collect();
+
+    match namespaces.as_slice() {
+        [] => {
+            eprintln!("no namespaces to generate in {}", path);
+            process::exit(1);
+        }
+        [namespace] => print!("{}", emit::Generator::new(namespace).generate()),
+        namespaces => {
+            for namespace in namespaces {
+                println!("pub mod {} {{", naming::snake_case(&namespace.namespace));
+                for line in emit::Generator::new(namespace).generate().lines() {
+                    if line.is_empty() {
+                        println!();
+                    } else {
+                        println!("    {}", line);
+                    }
+                }
+                println!("}}\n");
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "override",
+    "pub", "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "yield",
+];
+
+/// Keywords that can't be raw identifiers. `Self` is covered by `self`, since names are snake cased first.
+const PATH_KEYWORDS: &[&str] = &["crate", "self", "super"];
+
+/// `onDOMContentLoaded` -> `on_dom_content_loaded`, `system.cpu` -> `system_cpu`.
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !snake.is_empty() && !snake.ends_with('_') {
+                snake.push('_');
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+
+            if (previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_is_lower))
+                && !snake.ends_with('_')
+            {
+                snake.push('_');
+            }
+        }
+
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
+
+/// `tools_menu`, `high-contrast` or `onDOMContentLoaded` -> `ToolsMenu`, `HighContrast`, `OnDomContentLoaded`.
+pub fn pascal_case(name: &str) -> String {
+    snake_case(name)
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// A struct field or parameter name, escaped if it is a keyword: `type` -> `r#type`, `self` -> `self_`.
+pub fn field_name(name: &str) -> String {
+    let name = snake_case(name);
+
+    if PATH_KEYWORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else if KEYWORDS.contains(&name.as_str()) {
+        format!("r#{}", name)
+    } else {
+        name
+    }
+}
+
+/// The variant for an enum value, and whether it needs an explicit `rename` under `rename_all = "camelCase"`.
+pub fn variant_name(value: &str) -> (String, bool) {
+    let mut variant = pascal_case(value);
+
+    if variant.is_empty() || variant.starts_with(|c: char| c.is_ascii_digit()) {
+        variant = format!("V{}", variant);
+    }
+
+    let mut chars = variant.chars();
+    let camel: String = match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    };
+
+    (variant, camel != value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{field_name, pascal_case, snake_case, variant_name};
+
+    #[test]
+    fn snake_case_splits_words_and_acronyms() {
+        assert_eq!(snake_case("idle"), "idle");
+        assert_eq!(snake_case("onStateChanged"), "on_state_changed");
+        assert_eq!(snake_case("onDOMContentLoaded"), "on_dom_content_loaded");
+        assert_eq!(snake_case("getURL"), "get_url");
+        assert_eq!(snake_case("system.cpu"), "system_cpu");
+        assert_eq!(snake_case("high-contrast"), "high_contrast");
+        assert_eq!(snake_case("x509Certificate"), "x509_certificate");
+    }
+
+    #[test]
+    fn pascal_case_joins_words() {
+        assert_eq!(pascal_case("tools_menu"), "ToolsMenu");
+        assert_eq!(pascal_case("high-contrast"), "HighContrast");
+        assert_eq!(pascal_case("onDOMContentLoaded"), "OnDomContentLoaded");
+    }
+
+    #[test]
+    fn field_name_escapes_keywords() {
+        assert_eq!(field_name("tabId"), "tab_id");
+        assert_eq!(field_name("type"), "r#type");
+        assert_eq!(field_name("override"), "r#override");
+        assert_eq!(field_name("self"), "self_");
+        assert_eq!(field_name("Self"), "self_");
+        assert_eq!(field_name("crate"), "crate_");
+        assert_eq!(field_name("super"), "super_");
+    }
+
+    #[test]
+    fn variant_name_reports_renames() {
+        assert_eq!(variant_name("active"), ("Active".to_owned(), false));
+        assert_eq!(variant_name("sansserif"), ("Sansserif".to_owned(), false));
+        assert_eq!(variant_name("high-contrast"), ("HighContrast".to_owned(), true));
+        assert_eq!(variant_name("LOCKED"), ("Locked".to_owned(), true));
+        assert_eq!(variant_name("2d"), ("V2d".to_owned(), true));
+    }
+}
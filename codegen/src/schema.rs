@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+use serde::Deserialize;
+
+/// One entry of a Chromium extension API schema file, e.g. `chrome/common/extensions/api/idle.json`.
+#[derive(Debug, Deserialize)]
+pub struct Namespace {
+    pub namespace: String,
+    #[serde(default)]
+    pub types: Vec<Property>,
+    #[serde(default)]
+    pub functions: Vec<Function>,
+    #[serde(default)]
+    pub events: Vec<Function>,
+    #[serde(default)]
+    pub nodoc: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Property {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    #[serde(rename = "$ref")]
+    pub reference: Option<String>,
+    #[serde(default)]
+    pub optional: bool,
+    #[serde(rename = "enum")]
+    pub enum_values: Option<Vec<EnumValue>>,
+    pub items: Option<Box<Property>>,
+    pub properties: Option<BTreeMap<String, Property>>,
+    /// The arguments of a `"type": "function"` callback parameter.
+    pub parameters: Option<Vec<Property>>,
+    #[serde(default)]
+    pub nodoc: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum EnumValue {
+    Plain(String),
+    Described { name: String },
+}
+
+impl EnumValue {
+    pub fn name(&self) -> &str {
+        match self {
+            EnumValue::Plain(name) | EnumValue::Described { name } => name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Function {
+    pub name: String,
+    #[serde(default)]
+    pub parameters: Vec<Property>,
+    #[serde(rename = "returns_async")]
+    pub returns_async: Option<Callback>,
+    pub returns: Option<Property>,
+    #[serde(default)]
+    pub nodoc: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Callback {
+    #[serde(default)]
+    pub parameters: Vec<Property>,
+}
+
+/// Parses a schema file, which is JSON with `//` comments.
+pub fn parse(source: &str) -> serde_json::Result<Vec<Namespace>> {
+    serde_json::from_str(&strip_comments(source))
+}
+
+fn strip_comments(source: &str) -> String {
+    let mut stripped = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            stripped.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    stripped.push(escaped);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '/' && chars.peek() == Some(&'/') {
+            for c in chars.by_ref() {
+                if c == '\n' {
+                    stripped.push('\n');
+                    break;
+                }
+            }
+        } else {
+            in_string = c == '"';
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_comments;
+
+    #[test]
+    fn strip_comments_drops_line_comments() {
+        assert_eq!(strip_comments("// Copyright\n[1, // one\n2]"), "\n[1, \n2]");
+        assert_eq!(strip_comments("[1] // no trailing newline"), "[1] ");
+    }
+
+    #[test]
+    fn strip_comments_keeps_slashes_in_strings() {
+        let source = r#"{"url": "https://example.com/", "quoted": "say \"//hi\"", "slash": "\\"} // done"#;
+
+        assert_eq!(strip_comments(source), r#"{"url": "https://example.com/", "quoted": "say \"//hi\"", "slash": "\\"} "#);
+    }
+
+    #[test]
+    fn strip_comments_keeps_single_slashes() {
+        assert_eq!(strip_comments("[4 / 2]"), "[4 / 2]");
+    }
+}
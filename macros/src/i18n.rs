@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use serde_json::Value;
+use syn::{Error, Ident, LitStr};
+
+pub fn define_messages(input: TokenStream) -> syn::Result<TokenStream> {
+    let path: LitStr = syn::parse2(input)?;
+    let span = path.span();
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| Error::new(span, "CARGO_MANIFEST_DIR is not set"))?;
+    let full_path = PathBuf::from(manifest_dir).join(path.value());
+
+    let contents = std::fs::read_to_string(&full_path)
+        .map_err(|e| Error::new(span, format!("failed to read {}: {}", full_path.display(), e)))?;
+    let messages: BTreeMap<String, Value> = serde_json::from_str(&contents)
+        .map_err(|e| Error::new(span, format!("failed to parse {}: {}", full_path.display(), e)))?;
+
+    check_unique_names(messages.keys(), span)?;
+
+    let functions = messages
+        .iter()
+        .map(|(key, message)| message_fn(key, message, span))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // Makes cargo rebuild the invoking crate whenever the messages file changes.
+    let full_path = full_path.to_string_lossy();
+
+    Ok(quote! {
+        const _: &str = include_str!(#full_path);
+
+        #(#functions)*
+    })
+}
+
+fn message_fn(key: &str, message: &Value, span: Span) -> syn::Result<TokenStream> {
+    let text = message
+        .get("message")
+        .and_then(Value::as_str)
+        .ok_or_else(|| Error::new(span, format!("message `{}` has no \"message\" string", key)))?;
+
+    let mut names: Vec<Option<String>> = Vec::new();
+
+    for n in substitution_indices(text) {
+        note_substitution(&mut names, n, None);
+    }
+
+    if let Some(Value::Object(placeholders)) = message.get("placeholders") {
+        for (name, placeholder) in placeholders {
+            let content = placeholder.get("content").and_then(Value::as_str).unwrap_or("");
+
+            for n in substitution_indices(content) {
+                // Only a placeholder that is exactly "$N" says what the argument means.
+                let name = if content.trim() == format!("${}", n) {
+                    Some(name.to_lowercase())
+                } else {
+                    None
+                };
+
+                note_substitution(&mut names, n, name);
+            }
+        }
+    }
+
+    let params: Vec<Ident> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let name = match name {
+                Some(name) if names.iter().filter(|other| other.as_ref() == Some(name)).count() == 1 => name.clone(),
+                _ => format!("arg{}", i + 1),
+            };
+
+            ident(&name, span)
+        })
+        .collect::<syn::Result<_>>()?;
+
+    let name = ident(&fn_name(key), span)?;
+
+    Ok(quote! {
+        #[doc = #text]
+        pub fn #name(#(#params: &str),*) -> String {
+            ::web_extension_sys::i18n::get_message(#key, &[#(#params),*])
+        }
+    })
+}
+
+fn note_substitution(names: &mut Vec<Option<String>>, n: usize, name: Option<String>) {
+    if names.len() < n {
+        names.resize(n, None);
+    }
+
+    if name.is_some() {
+        names[n - 1] = name;
+    }
+}
+
+/// Finds the `$1`..`$9` references in a message or placeholder, skipping `$$` escapes.
+fn substitution_indices(text: &str) -> Vec<usize> {
+    let mut indices = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            continue;
+        }
+
+        match chars.peek() {
+            Some('$') => {
+                chars.next();
+            }
+            Some(d @ '1'..='9') => {
+                indices.push(*d as usize - '0' as usize);
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+
+    indices
+}
+
+/// Message keys are case-insensitive, so the function is named after the lowercased key.
+fn fn_name(key: &str) -> String {
+    let name = key.to_lowercase();
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+fn check_unique_names<'a>(keys: impl Iterator<Item = &'a String>, span: Span) -> syn::Result<()> {
+    let mut seen = BTreeMap::new();
+
+    for key in keys {
+        if let Some(other) = seen.insert(fn_name(key), key) {
+            return Err(Error::new(span, format!("messages `{}` and `{}` differ only in case", other, key)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Falls back to a raw identifier for keywords, which fails for `self`, `super` and `crate`.
+fn ident(name: &str, span: Span) -> syn::Result<Ident> {
+    syn::parse_str(name)
+        .or_else(|_| syn::parse_str(&format!("r#{}", name)))
+        .map_err(|_| Error::new(span, format!("`{}` can't be used as a Rust name", name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+    use super::{check_unique_names, ident, substitution_indices};
+
+    #[test]
+    fn substitution_indices_finds_references_in_order() {
+        assert_eq!(substitution_indices("no references"), Vec::<usize>::new());
+        assert_eq!(substitution_indices("$1 and $2"), vec![1, 2]);
+        assert_eq!(substitution_indices("$2$1$2"), vec![2, 1, 2]);
+        assert_eq!(substitution_indices("costs $$1 or $9"), vec![9]);
+        assert_eq!(substitution_indices("$0, $a, $USER$ and a trailing $"), Vec::<usize>::new());
+        assert_eq!(substitution_indices("$$$1"), vec![1]);
+    }
+
+    #[test]
+    fn keys_differing_only_in_case_are_an_error() {
+        let keys = ["Hello".to_owned(), "goodbye".to_owned()];
+        assert!(check_unique_names(keys.iter(), Span::call_site()).is_ok());
+
+        let keys = ["Hello".to_owned(), "hello".to_owned()];
+        assert!(check_unique_names(keys.iter(), Span::call_site()).is_err());
+    }
+
+    #[test]
+    fn keywords_become_raw_identifiers_where_allowed() {
+        assert_eq!(ident("greeting", Span::call_site()).unwrap().to_string(), "greeting");
+        assert_eq!(ident("type", Span::call_site()).unwrap().to_string(), "r#type");
+
+        for name in ["self", "super", "crate"] {
+            assert!(ident(name, Span::call_site()).is_err());
+        }
+    }
+}
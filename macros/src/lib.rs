@@ -0,0 +1,36 @@
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+mod i18n;
+mod message;
+mod service;
+
+#[proc_macro]
+pub fn define_messages(input: TokenStream) -> TokenStream {
+    i18n::define_messages(input.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives wire (de)serialization, a `<Name>Handler` trait, and `dispatch`/`send`/`listen` for a message enum.
+///
+/// Messages are encoded as `{ "type": "Variant", "payload": ... }`, so the enum must not also derive
+/// `Serialize` or `Deserialize`. Listeners answer with a `runtime::Reply`, which `send` unwraps.
+/// Mark a variant with `#[response(Type)]` to set what its handler returns. `#[serde(...)]` attributes
+/// on the enum, its variants and their fields apply to the wire format.
+#[proc_macro_derive(ExtensionMessage, attributes(response, serde))]
+pub fn derive_extension_message(input: TokenStream) -> TokenStream {
+    message::derive_extension_message(input.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Turns a trait of `async fn`s and `BoxStream`-returning methods into an RPC service, generating a
+/// `<Name>Client` to call it and a `<Name>Server` to serve an implementation of it. See the `rpc` module.
+#[proc_macro_attribute]
+pub fn service(attr: TokenStream, item: TokenStream) -> TokenStream {
+    service::service(attr.into(), item.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
@@ -0,0 +1,297 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, Type};
+
+struct MessageVariant {
+    ident: Ident,
+    method: Ident,
+    fields: Fields,
+    /// The handler method's parameter names: the field names, or `arg0`, `arg1`... for tuple variants.
+    params: Vec<Ident>,
+    /// What the fields are bound to in generated code, so a field called `handler` or `sender`
+    /// can't shadow `dispatch`'s own arguments.
+    bindings: Vec<Ident>,
+    serde_attrs: Vec<Attribute>,
+    response: Type,
+}
+
+pub fn derive_extension_message(input: TokenStream) -> syn::Result<TokenStream> {
+    let input: DeriveInput = syn::parse2(input)?;
+    let name = &input.ident;
+    let vis = &input.vis;
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "ExtensionMessage does not support generic enums"));
+    }
+
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => return Err(Error::new(Span::call_site(), "ExtensionMessage can only be derived for enums")),
+    };
+
+    let variants = data.variants
+        .iter()
+        .map(|variant| {
+            let params = match &variant.fields {
+                Fields::Named(fields) => fields.named.iter().map(|f| f.ident.clone().unwrap()).collect(),
+                Fields::Unnamed(fields) => (0..fields.unnamed.len()).map(|i| format_ident!("arg{}", i)).collect(),
+                Fields::Unit => Vec::new(),
+            };
+
+            Ok(MessageVariant {
+                ident: variant.ident.clone(),
+                method: format_ident!("{}", snake_case(&variant.ident.to_string())),
+                fields: variant.fields.clone(),
+                bindings: (0..params.len()).map(|i| format_ident!("__field{}", i)).collect(),
+                params,
+                serde_attrs: serde_attrs(&variant.attrs),
+                response: response_type(&variant.attrs)?,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let handler = format_ident!("{}Handler", name);
+    let tagged = format_ident!("__{}Tagged", name);
+    let tagged_ref = format_ident!("__{}TaggedRef", name);
+    let container_attrs = serde_attrs(&input.attrs);
+
+    let handler_methods = variants.iter().map(|v| {
+        let method = &v.method;
+        let response = &v.response;
+        let params = field_types(&v.fields).into_iter().zip(&v.params).map(|(ty, param)| quote!(#param: #ty));
+        let sender = if v.params.iter().any(|param| param == "sender") {
+            format_ident!("__sender")
+        } else {
+            format_ident!("sender")
+        };
+
+        quote! {
+            fn #method(&self, #(#params,)* #sender: ::web_extension_sys::runtime::MessageSender)
+                -> impl ::std::future::Future<Output = #response>;
+        }
+    });
+
+    let owned_variants = variants.iter().map(|v| {
+        let ident = &v.ident;
+        let attrs = &v.serde_attrs;
+        let fields = strip_non_serde_attrs(&v.fields);
+        quote!(#(#attrs)* #ident #fields)
+    });
+
+    let ref_variants = variants.iter().map(|v| {
+        let ident = &v.ident;
+        let attrs = &v.serde_attrs;
+        let mut fields = strip_non_serde_attrs(&v.fields);
+        for field in fields.iter_mut() {
+            let ty = &field.ty;
+            field.ty = syn::parse_quote!(&'a #ty);
+        }
+        quote!(#(#attrs)* #ident #fields)
+    });
+
+    let to_ref = variants.iter().map(|v| {
+        let ident = &v.ident;
+        let pattern = pattern(&v.fields, &v.bindings);
+        quote!(#name::#ident #pattern => #tagged_ref::#ident #pattern)
+    });
+
+    let from_owned = variants.iter().map(|v| {
+        let ident = &v.ident;
+        let pattern = pattern(&v.fields, &v.bindings);
+        quote!(#tagged::#ident #pattern => #name::#ident #pattern)
+    });
+
+    let dispatch_arms = variants.iter().map(|v| {
+        let ident = &v.ident;
+        let method = &v.method;
+        let bindings = &v.bindings;
+        let pattern = pattern(&v.fields, &v.bindings);
+        quote! {
+            #name::#ident #pattern => {
+                let response = handler.#method(#(#bindings,)* sender).await;
+                ::web_extension_sys::__private::to_json_value(&response)
+            }
+        }
+    });
+
+    let serde_path = "::web_extension_sys::__private::serde";
+
+    Ok(quote! {
+        #vis trait #handler {
+            #(#handler_methods)*
+        }
+
+        impl #name {
+            /// Runs the handler method for this variant and serializes what it returns.
+            pub async fn dispatch<H: #handler>(
+                self,
+                handler: &H,
+                sender: ::web_extension_sys::runtime::MessageSender,
+            ) -> Result<::web_extension_sys::__private::wasm_bindgen::JsValue, ::web_extension_sys::error::Error> {
+                match self {
+                    #(#dispatch_arms)*
+                }
+            }
+
+            /// Sends this message to the extension's other contexts via `runtime.sendMessage`.
+            /// Fails with the handler's error if it failed.
+            pub async fn send<R>(&self) -> Result<R, ::web_extension_sys::error::Error>
+                where R: ::web_extension_sys::__private::serde::de::DeserializeOwned,
+            {
+                ::web_extension_sys::runtime::send_message::<_, ::web_extension_sys::runtime::Reply<R>>(self)
+                    .await?
+                    .into_result()
+            }
+
+            /// Sends this message to the content scripts of a tab via `tabs.sendMessage`.
+            /// Fails with the handler's error if it failed.
+            pub async fn send_to_tab<R>(&self, tab_id: i32) -> Result<R, ::web_extension_sys::error::Error>
+                where R: ::web_extension_sys::__private::serde::de::DeserializeOwned,
+            {
+                ::web_extension_sys::tabs::send_message::<_, ::web_extension_sys::runtime::Reply<R>>(tab_id, self)
+                    .await?
+                    .into_result()
+            }
+
+            /// Answers every `runtime.onMessage` payload that parses as this enum.
+            /// Other messages are left for other listeners.
+            pub fn listen<H: #handler + 'static>(handler: H) -> ::web_extension_sys::events::EventHandle {
+                let handler = ::std::rc::Rc::new(handler);
+
+                ::web_extension_sys::runtime::on_message::listen(move |message, sender, send_response| {
+                    let message: #name = match ::web_extension_sys::__private::serde_wasm_bindgen::from_value(message) {
+                        Ok(message) => message,
+                        Err(_) => return false,
+                    };
+                    let handler = ::std::rc::Rc::clone(&handler);
+
+                    ::web_extension_sys::__private::wasm_bindgen_futures::spawn_local(async move {
+                        let _ = send_response.send_result(message.dispatch(&*handler, sender).await);
+                    });
+
+                    true
+                })
+            }
+        }
+
+        const _: () = {
+            #[derive(::web_extension_sys::__private::serde::Deserialize)]
+            #[serde(crate = #serde_path, tag = "type", content = "payload")]
+            #(#container_attrs)*
+            enum #tagged {
+                #(#owned_variants,)*
+            }
+
+            #[derive(::web_extension_sys::__private::serde::Serialize)]
+            #[serde(crate = #serde_path, tag = "type", content = "payload")]
+            #(#container_attrs)*
+            enum #tagged_ref<'a> {
+                #(#ref_variants,)*
+                #[serde(skip)]
+                #[allow(dead_code)]
+                __Phantom(::std::marker::PhantomData<&'a ()>),
+            }
+
+            impl ::web_extension_sys::__private::serde::Serialize for #name {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where S: ::web_extension_sys::__private::serde::Serializer,
+                {
+                    let tagged = match self {
+                        #(#to_ref,)*
+                    };
+
+                    ::web_extension_sys::__private::serde::Serialize::serialize(&tagged, serializer)
+                }
+            }
+
+            impl<'de> ::web_extension_sys::__private::serde::Deserialize<'de> for #name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where D: ::web_extension_sys::__private::serde::Deserializer<'de>,
+                {
+                    let tagged = <#tagged as ::web_extension_sys::__private::serde::Deserialize>::deserialize(deserializer)?;
+
+                    Ok(match tagged {
+                        #(#from_owned,)*
+                    })
+                }
+            }
+        };
+    })
+}
+
+fn response_type(attrs: &[Attribute]) -> syn::Result<Type> {
+    match attrs.iter().find(|attr| attr.path().is_ident("response")) {
+        Some(attr) => attr.parse_args(),
+        None => Ok(syn::parse_quote!(())),
+    }
+}
+
+fn field_types(fields: &Fields) -> Vec<&Type> {
+    fields.iter().map(|field| &field.ty).collect()
+}
+
+/// The `#[serde(...)]` attributes, which carry renames and the like over to the wire format.
+fn serde_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("serde")).cloned().collect()
+}
+
+/// Keeps only `#[serde(...)]` attributes so field renames carry over to the wire format.
+fn strip_non_serde_attrs(fields: &Fields) -> Fields {
+    let mut fields = fields.clone();
+
+    for field in fields.iter_mut() {
+        field.attrs.retain(|attr| attr.path().is_ident("serde"));
+        field.vis = syn::Visibility::Inherited;
+    }
+
+    fields
+}
+
+fn pattern(fields: &Fields, bindings: &[Ident]) -> TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!({ #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(( #(#bindings),* )),
+        Fields::Unit => quote!(),
+    }
+}
+
+/// `GetHTTPStatus` becomes `get_http_status`: a run of capitals is one word,
+/// except for its last letter when a lowercase letter follows.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+
+            if !previous.is_uppercase() || next_is_lowercase {
+                snake.push('_');
+            }
+        }
+
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::snake_case;
+
+    #[test]
+    fn snake_case_keeps_acronyms_together() {
+        assert_eq!(snake_case("Ping"), "ping");
+        assert_eq!(snake_case("GetTab"), "get_tab");
+        assert_eq!(snake_case("HTTP"), "http");
+        assert_eq!(snake_case("HTTPRequest"), "http_request");
+        assert_eq!(snake_case("GetURL"), "get_url");
+        assert_eq!(snake_case("OpenURLInTab"), "open_url_in_tab");
+        assert_eq!(snake_case("Fetch2Items"), "fetch2_items");
+    }
+}
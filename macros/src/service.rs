@@ -0,0 +1,191 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, FnArg, GenericArgument, Ident, ItemTrait, Pat, PathArguments, ReceiverKind, ReturnType, TraitItem, Type};
+
+enum Output {
+    Unary(Type),
+    Stream(Type),
+}
+
+struct ServiceMethod {
+    ident: Ident,
+    args: Vec<(Ident, Type)>,
+    output: Output,
+}
+
+pub fn service(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    if !attr.is_empty() {
+        return Err(Error::new_spanned(attr, "rpc::service takes no arguments"));
+    }
+
+    let mut service: ItemTrait = syn::parse2(item)?;
+    let name = service.ident.clone();
+    let vis = service.vis.clone();
+
+    if !service.generics.params.is_empty() {
+        return Err(Error::new_spanned(&service.generics, "rpc::service does not support generic traits"));
+    }
+
+    let mut methods = Vec::new();
+
+    for item in service.items.iter_mut() {
+        let method = match item {
+            TraitItem::Fn(method) => method,
+            _ => return Err(Error::new_spanned(item, "rpc::service traits can only contain methods")),
+        };
+        let sig = &mut method.sig;
+
+        match sig.inputs.first() {
+            Some(FnArg::Receiver(receiver)) if matches!(receiver.kind, ReceiverKind::Reference(_, _, None)) => {}
+            _ => return Err(Error::new_spanned(&sig.ident, "service methods must take `&self`")),
+        }
+
+        let args = sig.inputs
+            .iter()
+            .skip(1)
+            .map(|arg| match arg {
+                FnArg::Typed(arg) => match &*arg.pat {
+                    Pat::Ident(pat) => Ok((pat.ident.clone(), (*arg.ty).clone())),
+                    pat => Err(Error::new_spanned(pat, "service method arguments must be plain names")),
+                },
+                FnArg::Receiver(receiver) => Err(Error::new_spanned(receiver, "unexpected receiver")),
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let returns = match &sig.output {
+            ReturnType::Default => syn::parse_quote!(()),
+            ReturnType::Type(_, ty) => (**ty).clone(),
+        };
+
+        let output = if sig.asyncness.take().is_some() {
+            sig.output = syn::parse_quote!(-> impl ::std::future::Future<Output = #returns>);
+            Output::Unary(returns)
+        } else {
+            match stream_item(&returns) {
+                Some(item) => Output::Stream(item),
+                None => return Err(Error::new_spanned(
+                    &sig.ident,
+                    "service methods must be `async fn` or return `rpc::BoxStream<T>`",
+                )),
+            }
+        };
+
+        methods.push(ServiceMethod { ident: sig.ident.clone(), args, output });
+    }
+
+    let service_name = name.to_string();
+    let client = format_ident!("{}Client", name);
+    let server = format_ident!("{}Server", name);
+
+    let client_methods = methods.iter().map(|m| {
+        let ident = &m.ident;
+        let method = ident.to_string();
+        let params = m.args.iter().map(|(name, ty)| quote!(#name: #ty));
+        let names = m.args.iter().map(|(name, _)| name);
+
+        match &m.output {
+            Output::Unary(returns) => quote! {
+                pub async fn #ident(&self, #(#params),*) -> Result<#returns, ::web_extension_sys::error::Error> {
+                    self.0.call(#method, &(#(#names,)*)).await
+                }
+            },
+            Output::Stream(item) => quote! {
+                pub fn #ident(&self, #(#params),*)
+                    -> Result<::web_extension_sys::rpc::ResponseStream<#item>, ::web_extension_sys::error::Error>
+                {
+                    self.0.call_stream(#method, &(#(#names,)*))
+                }
+            },
+        }
+    });
+
+    let dispatch_arms = methods.iter().map(|m| {
+        let ident = &m.ident;
+        let method = ident.to_string();
+        let names: Vec<_> = m.args.iter().map(|(name, _)| name).collect();
+        let types = m.args.iter().map(|(_, ty)| ty);
+
+        let reply = match &m.output {
+            Output::Unary(_) => quote! {
+                ::web_extension_sys::rpc::Reply::Unary(Box::pin(async move {
+                    ::web_extension_sys::rpc::encode(&self.0.#ident(#(#names),*).await)
+                }))
+            },
+            Output::Stream(_) => quote! {
+                ::web_extension_sys::rpc::Reply::Stream(::web_extension_sys::rpc::encode_stream(self.0.#ident(#(#names),*)))
+            },
+        };
+
+        quote! {
+            #method => {
+                let (#(#names,)*): (#(#types,)*) = ::web_extension_sys::rpc::decode(payload)?;
+                Ok(#reply)
+            }
+        }
+    });
+
+    Ok(quote! {
+        #service
+
+        /// Calls the service over a port to the background.
+        #[derive(Clone)]
+        #vis struct #client(::web_extension_sys::rpc::Client);
+
+        impl #client {
+            pub fn new() -> Self {
+                Self(::web_extension_sys::rpc::Client::new(#service_name))
+            }
+
+            #(#client_methods)*
+        }
+
+        impl Default for #client {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        /// Serves an implementation of the service to other contexts.
+        #vis struct #server<S>(pub S);
+
+        impl<S: #name + 'static> #server<S> {
+            pub fn listen(service: S) -> ::web_extension_sys::events::EventHandle {
+                ::web_extension_sys::rpc::serve(#service_name, #server(service))
+            }
+        }
+
+        impl<S: #name + 'static> ::web_extension_sys::rpc::Dispatch for #server<S> {
+            fn dispatch(
+                self: ::std::rc::Rc<Self>,
+                method: &str,
+                payload: ::web_extension_sys::__private::wasm_bindgen::JsValue,
+            ) -> Result<::web_extension_sys::rpc::Reply, ::web_extension_sys::error::Error> {
+                match method {
+                    #(#dispatch_arms)*
+                    _ => Err(::web_extension_sys::error::Error::runtime(format!("{} has no method {}", #service_name, method))),
+                }
+            }
+        }
+    })
+}
+
+/// The `T` of a `BoxStream<T>` return type.
+fn stream_item(ty: &Type) -> Option<Type> {
+    let path = match ty {
+        Type::Path(ty) => &ty.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+
+    if segment.ident != "BoxStream" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(item) => Some(item.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Serialize, Deserialize};
+use crate::types::ChromeSetting;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AnimationPolicy {
+    Allowed,
+    Once,
+    None,
+}
+
+pub fn animation_policy() -> Result<ChromeSetting<AnimationPolicy>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.animationPolicy")
+}
+
+pub fn autoclick() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.autoclick")
+}
+
+pub fn caret_highlight() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.caretHighlight")
+}
+
+pub fn cursor_color() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.cursorColor")
+}
+
+pub fn cursor_highlight() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.cursorHighlight")
+}
+
+pub fn dictation() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.dictation")
+}
+
+pub fn docked_magnifier() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.dockedMagnifier")
+}
+
+pub fn focus_highlight() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.focusHighlight")
+}
+
+pub fn high_contrast() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.highContrast")
+}
+
+pub fn large_cursor() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.largeCursor")
+}
+
+pub fn screen_magnifier() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.screenMagnifier")
+}
+
+pub fn select_to_speak() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.selectToSpeak")
+}
+
+pub fn spoken_feedback() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.spokenFeedback")
+}
+
+pub fn sticky_keys() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.stickyKeys")
+}
+
+pub fn switch_access() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.switchAccess")
+}
+
+pub fn virtual_keyboard() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("accessibilityFeatures.virtualKeyboard")
+}
@@ -0,0 +1,218 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StreamType {
+    Input,
+    Output,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DeviceType {
+    Headphone,
+    Mic,
+    Usb,
+    Bluetooth,
+    Hdmi,
+    InternalSpeaker,
+    InternalMic,
+    FrontMic,
+    RearMic,
+    KeyboardMic,
+    Hotword,
+    Lineout,
+    PostMixLoopback,
+    PostDspLoopback,
+    AlsaLoopback,
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioDeviceInfo {
+    pub id: String,
+    pub stream_type: StreamType,
+    pub device_type: DeviceType,
+    pub display_name: String,
+    pub device_name: String,
+    pub is_active: bool,
+    pub level: i32,
+    pub stable_device_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceFilter {
+    pub stream_types: Option<Vec<StreamType>>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceIdLists {
+    pub input: Option<Vec<String>>,
+    pub output: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceProperties {
+    /// Volume for output devices or gain for input devices, from 0 to 100.
+    pub level: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelChangedEvent {
+    pub device_id: String,
+    pub level: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MuteChangedEvent {
+    pub stream_type: StreamType,
+    pub is_muted: bool,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "audio"], js_name = getDevices)]
+    fn _get_devices(filter: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "audio"], js_name = setActiveDevices)]
+    fn _set_active_devices(ids: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "audio"], js_name = setProperties)]
+    fn _set_properties(id: &str, properties: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "audio"], js_name = getMute)]
+    fn _get_mute(stream_type: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "audio"], js_name = setMute)]
+    fn _set_mute(stream_type: JsValue, is_muted: bool) -> Promise;
+}
+
+pub async fn get_devices(filter: &DeviceFilter) -> Result<Vec<AudioDeviceInfo>, Error> {
+    resolve(_get_devices(serde_wasm_bindgen::to_value(filter)?)).await
+}
+
+pub async fn set_active_devices(ids: &DeviceIdLists) -> Result<(), Error> {
+    resolve(_set_active_devices(serde_wasm_bindgen::to_value(ids)?)).await
+}
+
+pub async fn set_properties(id: &str, properties: &DeviceProperties) -> Result<(), Error> {
+    resolve(_set_properties(id, serde_wasm_bindgen::to_value(properties)?)).await
+}
+
+pub async fn get_mute(stream_type: StreamType) -> Result<bool, Error> {
+    resolve(_get_mute(serde_wasm_bindgen::to_value(&stream_type)?)).await
+}
+
+pub async fn set_mute(stream_type: StreamType, is_muted: bool) -> Result<(), Error> {
+    resolve(_set_mute(serde_wasm_bindgen::to_value(&stream_type)?, is_muted)).await
+}
+
+pub mod on_device_list_changed {
+    use wasm_bindgen::prelude::*;
+    use super::AudioDeviceInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "audio", "onDeviceListChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "audio"], js_name = onDeviceListChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(Vec<AudioDeviceInfo>) + 'static,
+    {
+        Closure::wrap(Box::new(move |devices: JsValue| {
+            callback(event_arg!(devices));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(Vec<AudioDeviceInfo>) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<Vec<AudioDeviceInfo>> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_level_changed {
+    use wasm_bindgen::prelude::*;
+    use super::LevelChangedEvent;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "audio", "onLevelChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "audio"], js_name = onLevelChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(LevelChangedEvent) + 'static,
+    {
+        Closure::wrap(Box::new(move |event: JsValue| {
+            callback(event_arg!(event));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(LevelChangedEvent) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<LevelChangedEvent> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_mute_changed {
+    use wasm_bindgen::prelude::*;
+    use super::MuteChangedEvent;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "audio", "onMuteChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "audio"], js_name = onMuteChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(MuteChangedEvent) + 'static,
+    {
+        Closure::wrap(Box::new(move |event: JsValue| {
+            callback(event_arg!(event));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(MuteChangedEvent) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<MuteChangedEvent> {
+        stream_of(listen)
+    }
+}
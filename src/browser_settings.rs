@@ -0,0 +1,124 @@
+use serde::{Serialize, Deserialize};
+use crate::types::ChromeSetting;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContextMenuShowEvent {
+    Mouseup,
+    Mousedown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImageAnimationBehavior {
+    Normal,
+    None,
+    Once,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NewTabPosition {
+    AfterCurrent,
+    RelatedAfterCurrent,
+    AtEnd,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OverrideDocumentColors {
+    HighContrastOnly,
+    Never,
+    Always,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ColorScheme {
+    Light,
+    Dark,
+    Auto,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsVersionRestrictionConfig {
+    pub minimum: Option<String>,
+    pub maximum: Option<String>,
+}
+
+pub fn allow_popups_for_user_events() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("browserSettings.allowPopupsForUserEvents")
+}
+
+pub fn cache_enabled() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("browserSettings.cacheEnabled")
+}
+
+pub fn close_tabs_by_double_click() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("browserSettings.closeTabsByDoubleClick")
+}
+
+pub fn context_menu_show_event() -> Result<ChromeSetting<ContextMenuShowEvent>, Error> {
+    ChromeSetting::from_path("browserSettings.contextMenuShowEvent")
+}
+
+/// Read-only.
+pub fn homepage_override() -> Result<ChromeSetting<String>, Error> {
+    ChromeSetting::from_path("browserSettings.homepageOverride")
+}
+
+pub fn image_animation_behavior() -> Result<ChromeSetting<ImageAnimationBehavior>, Error> {
+    ChromeSetting::from_path("browserSettings.imageAnimationBehavior")
+}
+
+/// Read-only.
+pub fn new_tab_page_override() -> Result<ChromeSetting<String>, Error> {
+    ChromeSetting::from_path("browserSettings.newTabPageOverride")
+}
+
+pub fn new_tab_position() -> Result<ChromeSetting<NewTabPosition>, Error> {
+    ChromeSetting::from_path("browserSettings.newTabPosition")
+}
+
+pub fn open_bookmarks_in_new_tabs() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("browserSettings.openBookmarksInNewTabs")
+}
+
+pub fn open_search_results_in_new_tabs() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("browserSettings.openSearchResultsInNewTabs")
+}
+
+pub fn open_urlbar_results_in_new_tabs() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("browserSettings.openUrlbarResultsInNewTabs")
+}
+
+pub fn override_content_color_scheme() -> Result<ChromeSetting<ColorScheme>, Error> {
+    ChromeSetting::from_path("browserSettings.overrideContentColorScheme")
+}
+
+pub fn override_document_colors() -> Result<ChromeSetting<OverrideDocumentColors>, Error> {
+    ChromeSetting::from_path("browserSettings.overrideDocumentColors")
+}
+
+/// Read-only.
+pub fn tls_version_restriction_config() -> Result<ChromeSetting<TlsVersionRestrictionConfig>, Error> {
+    ChromeSetting::from_path("browserSettings.tlsVersionRestrictionConfig")
+}
+
+pub fn use_document_fonts() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("browserSettings.useDocumentFonts")
+}
+
+pub fn web_notifications_disabled() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("browserSettings.webNotificationsDisabled")
+}
+
+pub fn zoom_full_page() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("browserSettings.zoomFullPage")
+}
+
+pub fn zoom_site_specific() -> Result<ChromeSetting<bool>, Error> {
+    ChromeSetting::from_path("browserSettings.zoomSiteSpecific")
+}
@@ -0,0 +1,304 @@
+use std::ops::{BitOr, BitOrAssign};
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OriginTypes {
+    pub unprotected_web: Option<bool>,
+    pub protected_web: Option<bool>,
+    pub extension: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovalOptions {
+    pub since: Option<f64>,
+    pub origin_types: Option<OriginTypes>,
+    pub origins: Option<Vec<String>>,
+    pub exclude_origins: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "DataTypeFlags", into = "DataTypeFlags")]
+pub struct DataTypeSet(u32);
+
+impl DataTypeSet {
+    pub const APPCACHE: Self = Self(1 << 0);
+    pub const CACHE: Self = Self(1 << 1);
+    pub const CACHE_STORAGE: Self = Self(1 << 2);
+    pub const COOKIES: Self = Self(1 << 3);
+    pub const DOWNLOADS: Self = Self(1 << 4);
+    pub const FILE_SYSTEMS: Self = Self(1 << 5);
+    pub const FORM_DATA: Self = Self(1 << 6);
+    pub const HISTORY: Self = Self(1 << 7);
+    pub const INDEXED_DB: Self = Self(1 << 8);
+    pub const LOCAL_STORAGE: Self = Self(1 << 9);
+    pub const PASSWORDS: Self = Self(1 << 10);
+    pub const SERVICE_WORKERS: Self = Self(1 << 11);
+    pub const WEB_SQL: Self = Self(1 << 12);
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub const fn all() -> Self {
+        Self((1 << 13) - 1)
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+}
+
+impl BitOr for DataTypeSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for DataTypeSet {
+    fn bitor_assign(&mut self, other: Self) {
+        self.insert(other);
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct DataTypeFlags {
+    #[serde(rename = "appcache", skip_serializing_if = "is_false")]
+    appcache: bool,
+    #[serde(rename = "cache", skip_serializing_if = "is_false")]
+    cache: bool,
+    #[serde(rename = "cacheStorage", skip_serializing_if = "is_false")]
+    cache_storage: bool,
+    #[serde(rename = "cookies", skip_serializing_if = "is_false")]
+    cookies: bool,
+    #[serde(rename = "downloads", skip_serializing_if = "is_false")]
+    downloads: bool,
+    #[serde(rename = "fileSystems", skip_serializing_if = "is_false")]
+    file_systems: bool,
+    #[serde(rename = "formData", skip_serializing_if = "is_false")]
+    form_data: bool,
+    #[serde(rename = "history", skip_serializing_if = "is_false")]
+    history: bool,
+    #[serde(rename = "indexedDB", skip_serializing_if = "is_false")]
+    indexed_db: bool,
+    #[serde(rename = "localStorage", skip_serializing_if = "is_false")]
+    local_storage: bool,
+    #[serde(rename = "passwords", skip_serializing_if = "is_false")]
+    passwords: bool,
+    #[serde(rename = "serviceWorkers", skip_serializing_if = "is_false")]
+    service_workers: bool,
+    #[serde(rename = "webSQL", skip_serializing_if = "is_false")]
+    web_sql: bool,
+}
+
+// Only the selected types are sent, as browsers reject data types they don't know about.
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+impl From<DataTypeFlags> for DataTypeSet {
+    fn from(flags: DataTypeFlags) -> Self {
+        let mut set = DataTypeSet::empty();
+
+        if flags.appcache {
+            set.insert(DataTypeSet::APPCACHE);
+        }
+        if flags.cache {
+            set.insert(DataTypeSet::CACHE);
+        }
+        if flags.cache_storage {
+            set.insert(DataTypeSet::CACHE_STORAGE);
+        }
+        if flags.cookies {
+            set.insert(DataTypeSet::COOKIES);
+        }
+        if flags.downloads {
+            set.insert(DataTypeSet::DOWNLOADS);
+        }
+        if flags.file_systems {
+            set.insert(DataTypeSet::FILE_SYSTEMS);
+        }
+        if flags.form_data {
+            set.insert(DataTypeSet::FORM_DATA);
+        }
+        if flags.history {
+            set.insert(DataTypeSet::HISTORY);
+        }
+        if flags.indexed_db {
+            set.insert(DataTypeSet::INDEXED_DB);
+        }
+        if flags.local_storage {
+            set.insert(DataTypeSet::LOCAL_STORAGE);
+        }
+        if flags.passwords {
+            set.insert(DataTypeSet::PASSWORDS);
+        }
+        if flags.service_workers {
+            set.insert(DataTypeSet::SERVICE_WORKERS);
+        }
+        if flags.web_sql {
+            set.insert(DataTypeSet::WEB_SQL);
+        }
+
+        set
+    }
+}
+
+impl From<DataTypeSet> for DataTypeFlags {
+    fn from(set: DataTypeSet) -> Self {
+        DataTypeFlags {
+            appcache: set.contains(DataTypeSet::APPCACHE),
+            cache: set.contains(DataTypeSet::CACHE),
+            cache_storage: set.contains(DataTypeSet::CACHE_STORAGE),
+            cookies: set.contains(DataTypeSet::COOKIES),
+            downloads: set.contains(DataTypeSet::DOWNLOADS),
+            file_systems: set.contains(DataTypeSet::FILE_SYSTEMS),
+            form_data: set.contains(DataTypeSet::FORM_DATA),
+            history: set.contains(DataTypeSet::HISTORY),
+            indexed_db: set.contains(DataTypeSet::INDEXED_DB),
+            local_storage: set.contains(DataTypeSet::LOCAL_STORAGE),
+            passwords: set.contains(DataTypeSet::PASSWORDS),
+            service_workers: set.contains(DataTypeSet::SERVICE_WORKERS),
+            web_sql: set.contains(DataTypeSet::WEB_SQL),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Settings {
+    pub options: RemovalOptions,
+    pub data_to_remove: DataTypeSet,
+    pub data_removal_permitted: DataTypeSet,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = remove)]
+    fn _remove(options: JsValue, data_to_remove: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = settings)]
+    fn _settings() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeAppcache)]
+    fn _remove_appcache(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeCache)]
+    fn _remove_cache(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeCacheStorage)]
+    fn _remove_cache_storage(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeCookies)]
+    fn _remove_cookies(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeDownloads)]
+    fn _remove_downloads(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeFileSystems)]
+    fn _remove_file_systems(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeFormData)]
+    fn _remove_form_data(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeHistory)]
+    fn _remove_history(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeIndexedDB)]
+    fn _remove_indexed_db(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeLocalStorage)]
+    fn _remove_local_storage(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removePasswords)]
+    fn _remove_passwords(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeServiceWorkers)]
+    fn _remove_service_workers(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "browsingData"], js_name = removeWebSQL)]
+    fn _remove_web_sql(options: JsValue) -> Promise;
+}
+
+pub async fn remove(options: &RemovalOptions, data_to_remove: DataTypeSet) -> Result<(), Error> {
+    let options = serde_wasm_bindgen::to_value(options)?;
+    let data_to_remove = serde_wasm_bindgen::to_value(&data_to_remove)?;
+
+    resolve(_remove(options, data_to_remove)).await
+}
+
+pub async fn settings() -> Result<Settings, Error> {
+    resolve(_settings()).await
+}
+
+pub async fn remove_appcache(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_appcache(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_cache(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_cache(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_cache_storage(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_cache_storage(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_cookies(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_cookies(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_downloads(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_downloads(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_file_systems(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_file_systems(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_form_data(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_form_data(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_history(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_history(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_indexed_db(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_indexed_db(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_local_storage(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_local_storage(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_passwords(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_passwords(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_service_workers(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_service_workers(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_web_sql(options: &RemovalOptions) -> Result<(), Error> {
+    resolve(_remove_web_sql(serde_wasm_bindgen::to_value(options)?)).await
+}
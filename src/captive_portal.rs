@@ -0,0 +1,117 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::types::ChromeSetting;
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum State {
+    Unknown,
+    NotCaptive,
+    UnlockedPortal,
+    LockedPortal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectivityStatus {
+    Captive,
+    Clear,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateChangedDetails {
+    pub state: State,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "captivePortal"], js_name = getState)]
+    fn _get_state() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "captivePortal"], js_name = getLastChecked)]
+    fn _get_last_checked() -> Promise;
+}
+
+pub async fn get_state() -> Result<State, Error> {
+    resolve(_get_state()).await
+}
+
+/// Milliseconds since the last captive portal check.
+pub async fn get_last_checked() -> Result<f64, Error> {
+    resolve(_get_last_checked()).await
+}
+
+/// The URL Firefox requests to detect captive portals.
+pub fn canonical_url() -> Result<ChromeSetting<String>, Error> {
+    ChromeSetting::from_path("captivePortal.canonicalURL")
+}
+
+pub mod on_state_changed {
+    use wasm_bindgen::prelude::*;
+    use super::StateChangedDetails;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "captivePortal", "onStateChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "captivePortal"], js_name = onStateChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(StateChangedDetails) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(StateChangedDetails) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<StateChangedDetails> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_connectivity_available {
+    use wasm_bindgen::prelude::*;
+    use super::ConnectivityStatus;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "captivePortal", "onConnectivityAvailable"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "captivePortal"], js_name = onConnectivityAvailable)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ConnectivityStatus) + 'static,
+    {
+        Closure::wrap(Box::new(move |status: JsValue| {
+            callback(event_arg!(status));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ConnectivityStatus) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ConnectivityStatus> {
+        stream_of(listen)
+    }
+}
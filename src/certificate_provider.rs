@@ -0,0 +1,198 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Promise, Reflect};
+use serde::{Serialize, Deserialize};
+use crate::utils::{deserialize_bytes, resolve, to_array_buffer};
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Algorithm {
+    #[serde(rename = "RSASSA_PKCS1_v1_5_MD5_SHA1")]
+    RsassaPkcs1V15Md5Sha1,
+    #[serde(rename = "RSASSA_PKCS1_v1_5_SHA1")]
+    RsassaPkcs1V15Sha1,
+    #[serde(rename = "RSASSA_PKCS1_v1_5_SHA256")]
+    RsassaPkcs1V15Sha256,
+    #[serde(rename = "RSASSA_PKCS1_v1_5_SHA384")]
+    RsassaPkcs1V15Sha384,
+    #[serde(rename = "RSASSA_PKCS1_v1_5_SHA512")]
+    RsassaPkcs1V15Sha512,
+    #[serde(rename = "RSASSA_PSS_SHA256")]
+    RsassaPssSha256,
+    #[serde(rename = "RSASSA_PSS_SHA384")]
+    RsassaPssSha384,
+    #[serde(rename = "RSASSA_PSS_SHA512")]
+    RsassaPssSha512,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProviderError {
+    GeneralError,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientCertificateInfo {
+    /// DER-encoded certificates, the client certificate first.
+    pub certificate_chain: Vec<Vec<u8>>,
+    pub supported_algorithms: Vec<Algorithm>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SetCertificatesDetails {
+    pub certificates_request_id: Option<i32>,
+    pub error: Option<ProviderError>,
+    pub client_certificates: Vec<ClientCertificateInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportSignatureDetails {
+    pub sign_request_id: i32,
+    pub error: Option<ProviderError>,
+    pub signature: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificatesUpdateRequest {
+    pub certificates_request_id: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureRequest {
+    pub sign_request_id: i32,
+    #[serde(deserialize_with = "deserialize_bytes")]
+    pub input: Vec<u8>,
+    pub algorithm: Algorithm,
+    #[serde(deserialize_with = "deserialize_bytes")]
+    pub certificate: Vec<u8>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SetCertificatesFields {
+    certificates_request_id: Option<i32>,
+    error: Option<ProviderError>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportSignatureFields {
+    sign_request_id: i32,
+    error: Option<ProviderError>,
+}
+
+impl ClientCertificateInfo {
+    fn to_js(&self) -> Result<JsValue, Error> {
+        let chain: Array = self.certificate_chain.iter().map(|certificate| to_array_buffer(certificate)).collect();
+
+        let info = Object::new();
+        Reflect::set(&info, &"certificateChain".into(), &chain)?;
+        Reflect::set(&info, &"supportedAlgorithms".into(), &serde_wasm_bindgen::to_value(&self.supported_algorithms)?)?;
+
+        Ok(info.into())
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "certificateProvider"], js_name = setCertificates)]
+    fn _set_certificates(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "certificateProvider"], js_name = reportSignature)]
+    fn _report_signature(details: JsValue) -> Promise;
+}
+
+pub async fn set_certificates(details: &SetCertificatesDetails) -> Result<(), Error> {
+    let js_details = serde_wasm_bindgen::to_value(&SetCertificatesFields {
+        certificates_request_id: details.certificates_request_id,
+        error: details.error,
+    })?;
+
+    let client_certificates = Array::new();
+    for certificate in &details.client_certificates {
+        client_certificates.push(&certificate.to_js()?);
+    }
+    Reflect::set(&js_details, &"clientCertificates".into(), &client_certificates)?;
+
+    resolve(_set_certificates(js_details)).await
+}
+
+pub async fn report_signature(details: &ReportSignatureDetails) -> Result<(), Error> {
+    let js_details = serde_wasm_bindgen::to_value(&ReportSignatureFields {
+        sign_request_id: details.sign_request_id,
+        error: details.error,
+    })?;
+
+    if let Some(signature) = &details.signature {
+        Reflect::set(&js_details, &"signature".into(), &to_array_buffer(signature))?;
+    }
+
+    resolve(_report_signature(js_details)).await
+}
+
+pub mod on_certificates_update_requested {
+    use wasm_bindgen::prelude::*;
+    use super::CertificatesUpdateRequest;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "certificateProvider", "onCertificatesUpdateRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "certificateProvider"], js_name = onCertificatesUpdateRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(CertificatesUpdateRequest) + 'static,
+    {
+        Closure::wrap(Box::new(move |request: JsValue| {
+            callback(event_arg!(request));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(CertificatesUpdateRequest) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<CertificatesUpdateRequest> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_signature_requested {
+    use wasm_bindgen::prelude::*;
+    use super::SignatureRequest;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "certificateProvider", "onSignatureRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "certificateProvider"], js_name = onSignatureRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(SignatureRequest) + 'static,
+    {
+        Closure::wrap(Box::new(move |request: JsValue| {
+            callback(event_arg!(request));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(SignatureRequest) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<SignatureRequest> {
+        stream_of(listen)
+    }
+}
@@ -0,0 +1,56 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Command {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub shortcut: Option<String>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "commands"], js_name = getAll)]
+    fn _get_all() -> Promise;
+}
+
+pub async fn get_all() -> Result<Vec<Command>, Error> {
+    resolve(_get_all()).await
+}
+
+pub mod on_command {
+    use wasm_bindgen::prelude::*;
+    use crate::tabs::Tab;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "commands", "onCommand"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "commands"], js_name = onCommand)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, JsValue)>
+        where T: FnMut(String, Option<Tab>) + 'static,
+    {
+        Closure::wrap(Box::new(move |command, tab: JsValue| {
+            callback(command, event_arg!(tab));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, Option<Tab>) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, Option<Tab>)> {
+        stream_of(|mut callback| listen(move |command, tab| callback((command, tab))))
+    }
+}
@@ -0,0 +1,252 @@
+//! Shims over the APIs that were renamed or moved between Manifest V2 and V3,
+//! so one codebase can target both.
+//!
+//! The manifest version is read once from `chrome.runtime.getManifest()`.
+//! Calls use the callback form, which Chrome accepts under both manifest
+//! versions, or the promise form where `namespace::current` is `browser`.
+
+use std::cell::Cell;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use js_sys::{Function, Object, Reflect};
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use crate::events::{Event, EventHandle, event_arg};
+use crate::tabs::Tab;
+use crate::namespace::{current, get, Namespace};
+use crate::dynamic::{call_method, call_method_with_callback};
+use crate::error::Error;
+
+thread_local! {
+    static MANIFEST_VERSION: Cell<Option<u8>> = const { Cell::new(None) };
+}
+
+/// The `manifest_version` of the running extension.
+pub fn manifest_version() -> Result<u8, Error> {
+    if let Some(version) = MANIFEST_VERSION.with(Cell::get) {
+        return Ok(version);
+    }
+
+    let runtime = namespace("runtime")?;
+    let get_manifest: Function = Reflect::get(&runtime, &"getManifest".into())?.dyn_into()?;
+    let manifest = get_manifest.call0(&runtime)?;
+    let version = Reflect::get(&manifest, &"manifest_version".into())?
+        .as_f64()
+        .ok_or_else(|| Error::runtime("manifest has no manifest_version"))? as u8;
+
+    MANIFEST_VERSION.with(|cell| cell.set(Some(version)));
+
+    Ok(version)
+}
+
+fn namespace(name: &str) -> Result<Object, Error> {
+    match get(name) {
+        Some(namespace) => Ok(namespace.unchecked_into()),
+        None => Err(Error::Unsupported(name.to_owned())),
+    }
+}
+
+async fn call(namespace: &Object, method: &str, args: &[JsValue]) -> Result<JsValue, Error> {
+    match current() {
+        Namespace::Browser => call_method(namespace, method, args).await,
+        Namespace::Chrome => call_method_with_callback(namespace, method, args).await,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionApi {
+    /// `chrome.action`, used by Manifest V3.
+    Action,
+    /// `chrome.browserAction`, used by Manifest V2.
+    BrowserAction,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TabDetails {
+    pub tab_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTitleDetails {
+    pub title: String,
+    pub tab_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetBadgeTextDetails {
+    pub text: String,
+    pub tab_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetBadgeBackgroundColorDetails {
+    /// A CSS color string such as `"#4688F1"`.
+    pub color: String,
+    pub tab_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetPopupDetails {
+    pub popup: String,
+    pub tab_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetIconDetails {
+    pub path: String,
+    pub tab_id: Option<i32>,
+}
+
+/// The toolbar button, whichever of `chrome.action` and `chrome.browserAction`
+/// the running manifest version provides.
+#[derive(Debug, Clone)]
+pub struct Action {
+    api: ActionApi,
+    namespace: Object,
+}
+
+impl Action {
+    pub fn new() -> Result<Self, Error> {
+        let api = match manifest_version()? {
+            2 => ActionApi::BrowserAction,
+            _ => ActionApi::Action,
+        };
+        let namespace = namespace(match api {
+            ActionApi::Action => "action",
+            ActionApi::BrowserAction => "browserAction",
+        })?;
+
+        Ok(Self { api, namespace })
+    }
+
+    pub fn api(&self) -> ActionApi {
+        self.api
+    }
+
+    async fn call<T: DeserializeOwned>(&self, method: &str, args: &[JsValue]) -> Result<T, Error> {
+        Ok(serde_wasm_bindgen::from_value(call(&self.namespace, method, args).await?)?)
+    }
+
+    pub async fn set_title(&self, details: &SetTitleDetails) -> Result<(), Error> {
+        self.call("setTitle", &[serde_wasm_bindgen::to_value(details)?]).await
+    }
+
+    pub async fn get_title(&self, details: &TabDetails) -> Result<String, Error> {
+        self.call("getTitle", &[serde_wasm_bindgen::to_value(details)?]).await
+    }
+
+    pub async fn set_badge_text(&self, details: &SetBadgeTextDetails) -> Result<(), Error> {
+        self.call("setBadgeText", &[serde_wasm_bindgen::to_value(details)?]).await
+    }
+
+    pub async fn get_badge_text(&self, details: &TabDetails) -> Result<String, Error> {
+        self.call("getBadgeText", &[serde_wasm_bindgen::to_value(details)?]).await
+    }
+
+    pub async fn set_badge_background_color(&self, details: &SetBadgeBackgroundColorDetails) -> Result<(), Error> {
+        self.call("setBadgeBackgroundColor", &[serde_wasm_bindgen::to_value(details)?]).await
+    }
+
+    pub async fn set_popup(&self, details: &SetPopupDetails) -> Result<(), Error> {
+        self.call("setPopup", &[serde_wasm_bindgen::to_value(details)?]).await
+    }
+
+    pub async fn get_popup(&self, details: &TabDetails) -> Result<String, Error> {
+        self.call("getPopup", &[serde_wasm_bindgen::to_value(details)?]).await
+    }
+
+    pub async fn set_icon(&self, details: &SetIconDetails) -> Result<(), Error> {
+        self.call("setIcon", &[serde_wasm_bindgen::to_value(details)?]).await
+    }
+
+    pub async fn enable(&self, tab_id: Option<i32>) -> Result<(), Error> {
+        self.call("enable", &[tab_id.into()]).await
+    }
+
+    pub async fn disable(&self, tab_id: Option<i32>) -> Result<(), Error> {
+        self.call("disable", &[tab_id.into()]).await
+    }
+
+    /// Listens for clicks on the button. Not fired while a popup is set.
+    pub fn on_clicked<T>(&self, mut callback: T) -> Result<EventHandle, Error>
+        where T: FnMut(Tab) + 'static,
+    {
+        let event: Event = Reflect::get(&self.namespace, &"onClicked".into())?.unchecked_into();
+        let listener = Closure::wrap(Box::new(move |tab: JsValue| {
+            callback(event_arg!(tab));
+        }) as Box<dyn FnMut(JsValue)>);
+
+        Ok(EventHandle::new(event, listener))
+    }
+}
+
+/// Which frames of a tab to inject into. With neither `frame_ids` nor
+/// `all_frames` set, only the top frame is used.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectionTarget {
+    pub tab_id: i32,
+    pub frame_ids: Option<Vec<i32>>,
+    pub all_frames: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectionResult<T> {
+    /// Manifest V2 doesn't report frames, so this is only set there when
+    /// `frame_ids` was given.
+    pub frame_id: Option<i32>,
+    pub result: Option<T>,
+}
+
+/// Runs script files in a tab through `chrome.scripting.executeScript` or
+/// `chrome.tabs.executeScript`, depending on the manifest version.
+pub async fn execute_script<T: DeserializeOwned>(target: &InjectionTarget, files: &[&str]) -> Result<Vec<InjectionResult<T>>, Error> {
+    if manifest_version()? >= 3 {
+        let details = Object::new();
+        Reflect::set(&details, &"target".into(), &serde_wasm_bindgen::to_value(target)?)?;
+        Reflect::set(&details, &"files".into(), &serde_wasm_bindgen::to_value(files)?)?;
+
+        let results = call(&namespace("scripting")?, "executeScript", &[details.into()]).await?;
+
+        return Ok(serde_wasm_bindgen::from_value(results)?);
+    }
+
+    let tabs = namespace("tabs")?;
+    let frame_ids = match &target.frame_ids {
+        Some(frame_ids) => frame_ids.iter().copied().map(Some).collect(),
+        None => vec![None],
+    };
+    let mut injection_results = Vec::new();
+
+    for frame_id in frame_ids {
+        let mut results = JsValue::UNDEFINED;
+
+        // Manifest V2 takes one file per call, and only reports the last one's results.
+        for file in files {
+            let details = Object::new();
+            Reflect::set(&details, &"file".into(), &(*file).into())?;
+            Reflect::set(&details, &"allFrames".into(), &target.all_frames.unwrap_or(false).into())?;
+            if let Some(frame_id) = frame_id {
+                Reflect::set(&details, &"frameId".into(), &frame_id.into())?;
+            }
+
+            results = call(&tabs, "executeScript", &[target.tab_id.into(), details.into()]).await?;
+        }
+
+        if results.is_undefined() {
+            continue;
+        }
+
+        let results: Vec<Option<T>> = serde_wasm_bindgen::from_value(results)?;
+        injection_results.extend(results.into_iter().map(|result| InjectionResult { frame_id, result }));
+    }
+
+    Ok(injection_results)
+}
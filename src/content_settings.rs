@@ -0,0 +1,179 @@
+use std::marker::PhantomData;
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AllowBlock {
+    Allow,
+    Block,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AllowBlockAsk {
+    Allow,
+    Block,
+    Ask,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CookiesContentSetting {
+    Allow,
+    Block,
+    SessionOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scope {
+    Regular,
+    IncognitoSessionOnly,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceIdentifier {
+    pub id: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDetails {
+    pub primary_url: String,
+    pub secondary_url: Option<String>,
+    pub resource_identifier: Option<ResourceIdentifier>,
+    pub incognito: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDetails<T> {
+    pub primary_pattern: String,
+    pub secondary_pattern: Option<String>,
+    pub resource_identifier: Option<ResourceIdentifier>,
+    pub setting: T,
+    pub scope: Option<Scope>,
+}
+
+#[derive(Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+struct GetResult<T> {
+    setting: T,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ClearDetails {
+    scope: Option<Scope>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    type JsContentSetting;
+
+    #[wasm_bindgen(method, js_name = get)]
+    fn get(this: &JsContentSetting, details: JsValue) -> Promise;
+
+    #[wasm_bindgen(method, js_name = set)]
+    fn set(this: &JsContentSetting, details: JsValue) -> Promise;
+
+    #[wasm_bindgen(method, js_name = clear)]
+    fn clear(this: &JsContentSetting, details: JsValue) -> Promise;
+
+    #[wasm_bindgen(method, js_name = getResourceIdentifiers)]
+    fn get_resource_identifiers(this: &JsContentSetting) -> Promise;
+}
+
+pub struct ContentSetting<T> {
+    setting: JsContentSetting,
+    value: PhantomData<T>,
+}
+
+impl<T: Serialize + DeserializeOwned> ContentSetting<T> {
+    /// The setting called `name` under `contentSettings`, or `Error::Unsupported`
+    /// if this browser doesn't have it.
+    fn from_name(name: &str) -> Result<Self, Error> {
+        let path = format!("contentSettings.{}", name);
+        let setting = crate::namespace::get(&path).ok_or(Error::Unsupported(path))?;
+
+        Ok(Self {
+            setting: setting.unchecked_into(),
+            value: PhantomData,
+        })
+    }
+
+    pub async fn get(&self, details: &GetDetails) -> Result<T, Error> {
+        let result: GetResult<T> = resolve(self.setting.get(serde_wasm_bindgen::to_value(details)?)).await?;
+
+        Ok(result.setting)
+    }
+
+    pub async fn set(&self, details: &SetDetails<T>) -> Result<(), Error> {
+        resolve(self.setting.set(serde_wasm_bindgen::to_value(details)?)).await
+    }
+
+    pub async fn clear(&self, scope: Option<Scope>) -> Result<(), Error> {
+        let details = ClearDetails { scope };
+
+        resolve(self.setting.clear(serde_wasm_bindgen::to_value(&details)?)).await
+    }
+
+    pub async fn get_resource_identifiers(&self) -> Result<Option<Vec<ResourceIdentifier>>, Error> {
+        resolve(self.setting.get_resource_identifiers()).await
+    }
+}
+
+pub fn automatic_downloads() -> Result<ContentSetting<AllowBlockAsk>, Error> {
+    ContentSetting::from_name("automaticDownloads")
+}
+
+pub fn auto_verify() -> Result<ContentSetting<AllowBlock>, Error> {
+    ContentSetting::from_name("autoVerify")
+}
+
+pub fn camera() -> Result<ContentSetting<AllowBlockAsk>, Error> {
+    ContentSetting::from_name("camera")
+}
+
+pub fn clipboard() -> Result<ContentSetting<AllowBlockAsk>, Error> {
+    ContentSetting::from_name("clipboard")
+}
+
+pub fn cookies() -> Result<ContentSetting<CookiesContentSetting>, Error> {
+    ContentSetting::from_name("cookies")
+}
+
+pub fn images() -> Result<ContentSetting<AllowBlock>, Error> {
+    ContentSetting::from_name("images")
+}
+
+pub fn javascript() -> Result<ContentSetting<AllowBlock>, Error> {
+    ContentSetting::from_name("javascript")
+}
+
+pub fn location() -> Result<ContentSetting<AllowBlockAsk>, Error> {
+    ContentSetting::from_name("location")
+}
+
+pub fn microphone() -> Result<ContentSetting<AllowBlockAsk>, Error> {
+    ContentSetting::from_name("microphone")
+}
+
+pub fn notifications() -> Result<ContentSetting<AllowBlockAsk>, Error> {
+    ContentSetting::from_name("notifications")
+}
+
+pub fn popups() -> Result<ContentSetting<AllowBlock>, Error> {
+    ContentSetting::from_name("popups")
+}
+
+pub fn sound() -> Result<ContentSetting<AllowBlock>, Error> {
+    ContentSetting::from_name("sound")
+}
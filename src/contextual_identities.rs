@@ -0,0 +1,213 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Color {
+    Blue,
+    Turquoise,
+    Green,
+    Yellow,
+    Orange,
+    Red,
+    Pink,
+    Purple,
+    Toolbar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Icon {
+    Fingerprint,
+    Briefcase,
+    Dollar,
+    Cart,
+    Circle,
+    Gift,
+    Vacation,
+    Food,
+    Fruit,
+    Pet,
+    Tree,
+    Chill,
+    Fence,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextualIdentity {
+    pub cookie_store_id: String,
+    pub name: String,
+    pub color: Color,
+    pub color_code: String,
+    pub icon: Icon,
+    pub icon_url: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryDetails {
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDetails {
+    pub name: String,
+    pub color: Color,
+    pub icon: Icon,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDetails {
+    pub name: Option<String>,
+    pub color: Option<Color>,
+    pub icon: Option<Icon>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeInfo {
+    pub contextual_identity: ContextualIdentity,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "contextualIdentities"], js_name = query)]
+    fn _query(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "contextualIdentities"], js_name = get)]
+    fn _get(cookie_store_id: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "contextualIdentities"], js_name = create)]
+    fn _create(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "contextualIdentities"], js_name = update)]
+    fn _update(cookie_store_id: &str, details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "contextualIdentities"], js_name = remove)]
+    fn _remove(cookie_store_id: &str) -> Promise;
+}
+
+pub async fn query(details: &QueryDetails) -> Result<Vec<ContextualIdentity>, Error> {
+    resolve(_query(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn get(cookie_store_id: &str) -> Result<ContextualIdentity, Error> {
+    resolve(_get(cookie_store_id)).await
+}
+
+pub async fn create(details: &CreateDetails) -> Result<ContextualIdentity, Error> {
+    resolve(_create(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn update(cookie_store_id: &str, details: &UpdateDetails) -> Result<ContextualIdentity, Error> {
+    resolve(_update(cookie_store_id, serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn remove(cookie_store_id: &str) -> Result<ContextualIdentity, Error> {
+    resolve(_remove(cookie_store_id)).await
+}
+
+pub mod on_created {
+    use wasm_bindgen::prelude::*;
+    use super::ChangeInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "contextualIdentities", "onCreated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "contextualIdentities"], js_name = onCreated)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ChangeInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |change_info: JsValue| {
+            callback(event_arg!(change_info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ChangeInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ChangeInfo> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_updated {
+    use wasm_bindgen::prelude::*;
+    use super::ChangeInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "contextualIdentities", "onUpdated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "contextualIdentities"], js_name = onUpdated)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ChangeInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |change_info: JsValue| {
+            callback(event_arg!(change_info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ChangeInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ChangeInfo> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_removed {
+    use wasm_bindgen::prelude::*;
+    use super::ChangeInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "contextualIdentities", "onRemoved"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "contextualIdentities"], js_name = onRemoved)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ChangeInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |change_info: JsValue| {
+            callback(event_arg!(change_info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ChangeInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ChangeInfo> {
+        stream_of(listen)
+    }
+}
@@ -0,0 +1,160 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use serde_wasm_bindgen::Serializer;
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Debuggee {
+    pub tab_id: Option<i32>,
+    pub extension_id: Option<String>,
+    pub target_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DebuggerSession {
+    pub tab_id: Option<i32>,
+    pub extension_id: Option<String>,
+    pub target_id: Option<String>,
+    pub session_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetInfoType {
+    Page,
+    BackgroundPage,
+    Worker,
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetInfo {
+    #[serde(rename = "type")]
+    pub target_type: TargetInfoType,
+    pub id: String,
+    pub tab_id: Option<i32>,
+    pub extension_id: Option<String>,
+    pub attached: bool,
+    pub title: String,
+    pub url: String,
+    pub favicon_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DetachReason {
+    TargetClosed,
+    CanceledByUser,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "debugger"], js_name = attach)]
+    fn _attach(target: JsValue, required_version: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "debugger"], js_name = detach)]
+    fn _detach(target: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "debugger"], js_name = sendCommand)]
+    fn _send_command(target: JsValue, method: &str, command_params: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "debugger"], js_name = getTargets)]
+    fn _get_targets() -> Promise;
+}
+
+pub async fn attach(target: &Debuggee, required_version: &str) -> Result<(), Error> {
+    resolve(_attach(serde_wasm_bindgen::to_value(target)?, required_version)).await
+}
+
+pub async fn detach(target: &Debuggee) -> Result<(), Error> {
+    resolve(_detach(serde_wasm_bindgen::to_value(target)?)).await
+}
+
+pub async fn send_command<P, R>(target: &DebuggerSession, method: &str, command_params: &P) -> Result<R, Error>
+    where P: Serialize,
+          R: DeserializeOwned,
+{
+    // CDP params are plain JSON objects, so maps must not become ES2015 Maps.
+    let command_params = command_params.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+
+    resolve(_send_command(serde_wasm_bindgen::to_value(target)?, method, command_params)).await
+}
+
+pub async fn get_targets() -> Result<Vec<TargetInfo>, Error> {
+    resolve(_get_targets()).await
+}
+
+pub mod on_event {
+    use wasm_bindgen::prelude::*;
+    use super::DebuggerSession;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "debugger", "onEvent"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, String, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "debugger"], js_name = onEvent)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, String, JsValue)>
+        where T: FnMut(DebuggerSession, String, JsValue) + 'static,
+    {
+        Closure::wrap(Box::new(move |source: JsValue, method, params: JsValue| {
+            callback(event_arg!(source), method, params);
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(DebuggerSession, String, JsValue) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(DebuggerSession, String, JsValue)> {
+        stream_of(|mut callback| listen(move |source, method, params| callback((source, method, params))))
+    }
+}
+
+pub mod on_detach {
+    use wasm_bindgen::prelude::*;
+    use super::{Debuggee, DetachReason};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "debugger", "onDetach"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "debugger"], js_name = onDetach)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, JsValue)>
+        where T: FnMut(Debuggee, DetachReason) + 'static,
+    {
+        Closure::wrap(Box::new(move |source: JsValue, reason: JsValue| {
+            callback(
+                event_arg!(source),
+                event_arg!(reason),
+            );
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(Debuggee, DetachReason) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(Debuggee, DetachReason)> {
+        stream_of(|mut callback| listen(move |source, reason| callback((source, reason))))
+    }
+}
@@ -0,0 +1,227 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Function, Object, Reflect};
+use serde::{Serialize, Deserialize};
+use web_sys::ImageData;
+use crate::events::UrlFilter;
+use crate::error::Error;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome"], js_name = declarativeContent)]
+    static DECLARATIVE_CONTENT: JsValue;
+}
+
+// Conditions and actions must be created through their constructors so that
+// Chrome tags them with the right `instanceType`.
+fn construct(name: &str, params: &JsValue) -> Result<JsValue, Error> {
+    let constructor: Function = DECLARATIVE_CONTENT
+        .with(|namespace| Reflect::get(namespace, &name.into()))?
+        .dyn_into()?;
+
+    Ok(Reflect::construct(&constructor, &Array::of1(params))?)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageStateMatcher {
+    pub page_url: Option<UrlFilter>,
+    pub css: Option<Vec<String>>,
+    pub is_bookmarked: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestContentScript {
+    pub css: Option<Vec<String>>,
+    pub js: Option<Vec<String>>,
+    pub all_frames: Option<bool>,
+    pub match_about_blank: Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetIcon {
+    image_data: JsValue,
+}
+
+impl SetIcon {
+    pub fn new(image_data: ImageData) -> Self {
+        Self { image_data: image_data.into() }
+    }
+
+    /// Provides the icon at several sizes, keyed by pixel width.
+    pub fn with_sizes(images: &[(u32, ImageData)]) -> Result<Self, Error> {
+        let image_data = Object::new();
+        for (size, image) in images {
+            Reflect::set(&image_data, &size.to_string().into(), image)?;
+        }
+
+        Ok(Self { image_data: image_data.into() })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Action {
+    ShowAction,
+    SetIcon(SetIcon),
+    RequestContentScript(RequestContentScript),
+}
+
+impl Action {
+    fn to_js(&self) -> Result<JsValue, Error> {
+        match self {
+            Action::ShowAction => construct("ShowAction", &Object::new()),
+            Action::SetIcon(set_icon) => {
+                let params = Object::new();
+                Reflect::set(&params, &"imageData".into(), &set_icon.image_data)?;
+                construct("SetIcon", &params)
+            }
+            Action::RequestContentScript(script) => {
+                construct("RequestContentScript", &serde_wasm_bindgen::to_value(script)?)
+            }
+        }
+    }
+
+    fn from_js(value: JsValue) -> Result<Self, Error> {
+        let instance_type = Reflect::get(&value, &"instanceType".into())?.as_string();
+
+        match instance_type.as_deref() {
+            Some("declarativeContent.ShowAction") | Some("declarativeContent.ShowPageAction") => {
+                Ok(Action::ShowAction)
+            }
+            Some("declarativeContent.SetIcon") => Ok(Action::SetIcon(SetIcon {
+                image_data: Reflect::get(&value, &"imageData".into())?,
+            })),
+            Some("declarativeContent.RequestContentScript") => {
+                Ok(Action::RequestContentScript(serde_wasm_bindgen::from_value(value)?))
+            }
+            _ => Err(value.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Rule {
+    pub id: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub conditions: Vec<PageStateMatcher>,
+    pub actions: Vec<Action>,
+    pub priority: Option<i32>,
+}
+
+#[derive(Serialize)]
+struct RuleFields<'a> {
+    id: &'a Option<String>,
+    tags: &'a Option<Vec<String>>,
+    priority: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct RegisteredRule {
+    id: Option<String>,
+    tags: Option<Vec<String>>,
+    conditions: Vec<PageStateMatcher>,
+    priority: Option<i32>,
+}
+
+impl Rule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn condition(mut self, condition: PageStateMatcher) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn action(mut self, action: Action) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    fn to_js(&self) -> Result<JsValue, Error> {
+        let rule = serde_wasm_bindgen::to_value(&RuleFields {
+            id: &self.id,
+            tags: &self.tags,
+            priority: self.priority,
+        })?;
+
+        let conditions = Array::new();
+        for condition in &self.conditions {
+            conditions.push(&construct("PageStateMatcher", &serde_wasm_bindgen::to_value(condition)?)?);
+        }
+
+        let actions = Array::new();
+        for action in &self.actions {
+            actions.push(&action.to_js()?);
+        }
+
+        Reflect::set(&rule, &"conditions".into(), &conditions)?;
+        Reflect::set(&rule, &"actions".into(), &actions)?;
+
+        Ok(rule)
+    }
+
+    fn from_js(value: JsValue) -> Result<Self, Error> {
+        let actions: Array = Reflect::get(&value, &"actions".into())?.dyn_into()?;
+        let rule: RegisteredRule = serde_wasm_bindgen::from_value(value)?;
+
+        Ok(Self {
+            id: rule.id,
+            tags: rule.tags,
+            conditions: rule.conditions,
+            actions: actions.iter().map(Action::from_js).collect::<Result<_, _>>()?,
+            priority: rule.priority,
+        })
+    }
+}
+
+pub mod on_page_changed {
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen_futures::JsFuture;
+    use js_sys::{Array, Function, Promise};
+    use crate::utils::promise_from_callback;
+    use crate::error::Error;
+    use super::Rule;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "declarativeContent", "onPageChanged"], js_name = addRules)]
+        fn _add_rules(rules: &Array, callback: &Function);
+
+        #[wasm_bindgen(js_namespace = ["chrome", "declarativeContent", "onPageChanged"], js_name = removeRules)]
+        fn _remove_rules(rule_identifiers: JsValue, callback: &Function);
+
+        #[wasm_bindgen(js_namespace = ["chrome", "declarativeContent", "onPageChanged"], js_name = getRules)]
+        fn _get_rules(rule_identifiers: JsValue, callback: &Function);
+    }
+
+    async fn rules_from_promise(promise: Promise) -> Result<Vec<Rule>, Error> {
+        let rules: Array = JsFuture::from(promise).await?.dyn_into()?;
+
+        rules.iter().map(Rule::from_js).collect()
+    }
+
+    /// Resolves to the registered rules, with their IDs filled in.
+    pub async fn add_rules(rules: &[Rule]) -> Result<Vec<Rule>, Error> {
+        let js_rules = Array::new();
+        for rule in rules {
+            js_rules.push(&rule.to_js()?);
+        }
+
+        rules_from_promise(promise_from_callback(|callback| _add_rules(&js_rules, &callback))).await
+    }
+
+    /// Removes the given rules, or every rule if `rule_identifiers` is `None`.
+    pub async fn remove_rules(rule_identifiers: Option<&[&str]>) -> Result<(), Error> {
+        let rule_identifiers = serde_wasm_bindgen::to_value(&rule_identifiers)?;
+        JsFuture::from(promise_from_callback(|callback| _remove_rules(rule_identifiers, &callback))).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_rules(rule_identifiers: Option<&[&str]>) -> Result<Vec<Rule>, Error> {
+        let rule_identifiers = serde_wasm_bindgen::to_value(&rule_identifiers)?;
+
+        rules_from_promise(promise_from_callback(|callback| _get_rules(rule_identifiers, &callback))).await
+    }
+}
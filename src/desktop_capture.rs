@@ -0,0 +1,88 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use js_sys::{Array, Function, Promise};
+use serde::{Serialize, Deserialize};
+use crate::tabs::Tab;
+use crate::utils::promise_from_callback;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DesktopCaptureSourceType {
+    Screen,
+    Window,
+    Tab,
+    Audio,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamOptions {
+    can_request_audio_track: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChosenMedia {
+    pub stream_id: String,
+    pub can_request_audio_track: bool,
+}
+
+pub struct DesktopMediaRequest {
+    request_id: i32,
+    promise: Promise,
+}
+
+impl DesktopMediaRequest {
+    pub fn request_id(&self) -> i32 {
+        self.request_id
+    }
+
+    pub fn cancel(&self) {
+        cancel_choose_desktop_media(self.request_id);
+    }
+
+    /// Resolves to `None` if the user dismissed the picker or the request was cancelled.
+    pub async fn chosen(self) -> Result<Option<ChosenMedia>, Error> {
+        let result: Array = JsFuture::from(self.promise).await?.unchecked_into();
+        let stream_id = result.get(0).as_string().unwrap_or_default();
+
+        if stream_id.is_empty() {
+            return Ok(None);
+        }
+
+        let options: Option<StreamOptions> = serde_wasm_bindgen::from_value(result.get(1))?;
+
+        Ok(Some(ChosenMedia {
+            stream_id,
+            can_request_audio_track: options.unwrap_or_default().can_request_audio_track,
+        }))
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "desktopCapture"], js_name = chooseDesktopMedia)]
+    fn _choose_desktop_media(sources: JsValue, target_tab: JsValue, callback: &Function) -> i32;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "desktopCapture"], js_name = cancelChooseDesktopMedia)]
+    pub fn cancel_choose_desktop_media(desktop_media_request_id: i32);
+}
+
+pub fn choose_desktop_media(
+    sources: &[DesktopCaptureSourceType],
+    target_tab: Option<&Tab>,
+) -> Result<DesktopMediaRequest, Error> {
+    let sources = serde_wasm_bindgen::to_value(sources)?;
+    let target_tab = serde_wasm_bindgen::to_value(&target_tab)?;
+    let mut request_id = 0;
+
+    let promise = promise_from_callback(|resolve| {
+        let callback = Closure::once_into_js(move |stream_id: String, options: JsValue| {
+            let _ = resolve.call1(&JsValue::NULL, &Array::of2(&stream_id.into(), &options));
+        });
+
+        request_id = _choose_desktop_media(sources, target_tab, callback.unchecked_ref());
+    });
+
+    Ok(DesktopMediaRequest { request_id, promise })
+}
@@ -0,0 +1,3 @@
+pub mod panels;
+pub mod inspected_window;
+pub mod network;
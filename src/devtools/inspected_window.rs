@@ -0,0 +1,222 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Array;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+#[cfg(not(feature = "safari"))]
+use wasm_bindgen_futures::JsFuture;
+#[cfg(not(feature = "safari"))]
+use js_sys::Function;
+#[cfg(not(feature = "safari"))]
+use crate::utils::promise_from_callback;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvalOptions {
+    #[serde(rename = "frameURL")]
+    pub frame_url: Option<String>,
+    pub use_content_script_context: Option<bool>,
+    pub script_execution_context: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReloadOptions {
+    pub ignore_cache: Option<bool>,
+    pub user_agent: Option<String>,
+    pub injected_script: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExceptionInfo {
+    pub code: Option<String>,
+    pub description: Option<String>,
+    pub is_error: bool,
+    pub is_exception: bool,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceContent {
+    pub content: Option<String>,
+    pub encoding: String,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(js_namespace = ["chrome", "devtools", "inspectedWindow"], js_name = eval)]
+    fn _eval(expression: &str, options: JsValue, callback: &Function);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "devtools", "inspectedWindow"], js_name = reload)]
+    fn _reload(reload_options: JsValue);
+
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(js_namespace = ["chrome", "devtools", "inspectedWindow"], js_name = getResources)]
+    fn _get_resources(callback: &Function);
+
+    #[derive(Debug, Clone)]
+    pub type Resource;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn url(this: &Resource) -> String;
+
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(method, js_name = getContent)]
+    fn _get_content(this: &Resource, callback: &Function);
+
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(method, js_name = setContent)]
+    fn _set_content(this: &Resource, content: &str, commit: bool, callback: &Function);
+}
+
+// Safari has no callback form, and none of the resource APIs.
+#[cfg(not(feature = "safari"))]
+impl Resource {
+    pub async fn get_content(&self) -> Result<ResourceContent, Error> {
+        get_content_with(|callback| self._get_content(callback)).await
+    }
+
+    pub async fn set_content(&self, content: &str, commit: bool) -> Result<(), Error> {
+        let error = promise_from_callback(|callback| self._set_content(content, commit, &callback));
+        let error = JsFuture::from(error).await?;
+
+        if error.is_undefined() || error.is_null() {
+            Ok(())
+        } else {
+            Err(error.into())
+        }
+    }
+}
+
+#[cfg(not(feature = "safari"))]
+pub(crate) async fn get_content_with<F: FnOnce(&Function)>(get_content: F) -> Result<ResourceContent, Error> {
+    let promise = promise_from_callback(|resolve| {
+        let callback = Closure::once_into_js(move |content: Option<String>, encoding: String| {
+            let _ = resolve.call1(&JsValue::NULL, &Array::of2(&content.into(), &encoding.into()));
+        });
+
+        get_content(callback.unchecked_ref());
+    });
+
+    let result: Array = JsFuture::from(promise).await?.unchecked_into();
+
+    Ok(ResourceContent {
+        content: result.get(0).as_string(),
+        encoding: result.get(1).as_string().unwrap_or_default(),
+    })
+}
+
+/// The inspected tab, or `None` outside a devtools page.
+pub fn tab_id() -> Option<i32> {
+    crate::namespace::get("devtools.inspectedWindow.tabId")?
+        .as_f64()
+        .map(|tab_id| tab_id as i32)
+}
+
+pub async fn eval<T: DeserializeOwned>(
+    expression: &str,
+    options: &EvalOptions,
+) -> Result<Result<T, ExceptionInfo>, Error> {
+    let options = serde_wasm_bindgen::to_value(options)?;
+
+    // Safari rejects the callback form, and resolves with the same pair instead.
+    #[cfg(feature = "safari")]
+    let result = crate::namespace::call("devtools.inspectedWindow", "eval", &[expression.into(), options]).await?;
+
+    #[cfg(not(feature = "safari"))]
+    let result = JsFuture::from(promise_from_callback(|resolve| {
+        let callback = Closure::once_into_js(move |result: JsValue, exception_info: JsValue| {
+            let _ = resolve.call1(&JsValue::NULL, &Array::of2(&result, &exception_info));
+        });
+
+        _eval(expression, options, callback.unchecked_ref());
+    })).await?;
+
+    let result: Array = result.unchecked_into();
+    let exception_info = result.get(1);
+
+    if exception_info.is_undefined() || exception_info.is_null() {
+        Ok(Ok(serde_wasm_bindgen::from_value(result.get(0))?))
+    } else {
+        Ok(Err(serde_wasm_bindgen::from_value(exception_info)?))
+    }
+}
+
+pub fn reload(reload_options: &ReloadOptions) -> Result<(), Error> {
+    _reload(serde_wasm_bindgen::to_value(reload_options)?);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "safari"))]
+pub async fn get_resources() -> Result<Vec<Resource>, Error> {
+    let resources: Array = JsFuture::from(promise_from_callback(|callback| _get_resources(&callback)))
+        .await?
+        .unchecked_into();
+
+    Ok(resources.iter().map(JsCast::unchecked_into).collect())
+}
+
+pub mod on_resource_added {
+    use wasm_bindgen::prelude::*;
+    use super::Resource;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "devtools", "inspectedWindow", "onResourceAdded"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(Resource)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "devtools", "inspectedWindow"], js_name = onResourceAdded)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(Resource)>
+        where T: FnMut(Resource) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(Resource) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<Resource> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_resource_content_committed {
+    use wasm_bindgen::prelude::*;
+    use super::Resource;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "devtools", "inspectedWindow", "onResourceContentCommitted"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(Resource, String)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "devtools", "inspectedWindow"], js_name = onResourceContentCommitted)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(Resource, String)>
+        where T: FnMut(Resource, String) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(Resource, String) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(Resource, String)> {
+        stream_of(|mut callback| listen(move |resource, content| callback((resource, content))))
+    }
+}
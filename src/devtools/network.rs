@@ -0,0 +1,280 @@
+use wasm_bindgen::prelude::*;
+#[cfg(not(feature = "safari"))]
+use js_sys::Function;
+#[cfg(not(feature = "safari"))]
+use crate::devtools::inspected_window::{get_content_with, ResourceContent};
+#[cfg(not(feature = "safari"))]
+use crate::utils::{promise_from_callback, resolve};
+use crate::error::Error;
+
+pub mod har {
+    use serde::{Serialize, Deserialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Log {
+        pub version: String,
+        pub creator: Creator,
+        pub browser: Option<Creator>,
+        #[serde(default)]
+        pub pages: Vec<Page>,
+        pub entries: Vec<Entry>,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Creator {
+        pub name: String,
+        pub version: String,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Page {
+        pub started_date_time: String,
+        pub id: String,
+        pub title: String,
+        pub page_timings: PageTimings,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PageTimings {
+        pub on_content_load: Option<f64>,
+        pub on_load: Option<f64>,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Entry {
+        pub pageref: Option<String>,
+        pub started_date_time: String,
+        pub time: f64,
+        pub request: Request,
+        pub response: Response,
+        pub cache: Cache,
+        pub timings: Timings,
+        #[serde(rename = "serverIPAddress")]
+        pub server_ip_address: Option<String>,
+        pub connection: Option<String>,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Request {
+        pub method: String,
+        pub url: String,
+        pub http_version: String,
+        pub cookies: Vec<Cookie>,
+        pub headers: Vec<Header>,
+        pub query_string: Vec<QueryParam>,
+        pub post_data: Option<PostData>,
+        pub headers_size: i64,
+        pub body_size: i64,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Response {
+        pub status: i32,
+        pub status_text: String,
+        pub http_version: String,
+        pub cookies: Vec<Cookie>,
+        pub headers: Vec<Header>,
+        pub content: Content,
+        #[serde(rename = "redirectURL")]
+        pub redirect_url: String,
+        pub headers_size: i64,
+        pub body_size: i64,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Cookie {
+        pub name: String,
+        pub value: String,
+        pub path: Option<String>,
+        pub domain: Option<String>,
+        pub expires: Option<String>,
+        pub http_only: Option<bool>,
+        pub secure: Option<bool>,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Header {
+        pub name: String,
+        pub value: String,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct QueryParam {
+        pub name: String,
+        pub value: String,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PostData {
+        pub mime_type: String,
+        #[serde(default)]
+        pub params: Vec<PostParam>,
+        pub text: Option<String>,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PostParam {
+        pub name: String,
+        pub value: Option<String>,
+        pub file_name: Option<String>,
+        pub content_type: Option<String>,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Content {
+        pub size: i64,
+        pub compression: Option<i64>,
+        pub mime_type: String,
+        pub text: Option<String>,
+        pub encoding: Option<String>,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Cache {
+        pub before_request: Option<CacheEntry>,
+        pub after_request: Option<CacheEntry>,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct CacheEntry {
+        pub expires: Option<String>,
+        pub last_access: String,
+        pub e_tag: String,
+        pub hit_count: i64,
+        pub comment: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Timings {
+        pub blocked: Option<f64>,
+        pub dns: Option<f64>,
+        pub connect: Option<f64>,
+        pub send: f64,
+        pub wait: f64,
+        pub receive: f64,
+        pub ssl: Option<f64>,
+        pub comment: Option<String>,
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone)]
+    pub type Request;
+
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(method, js_name = getContent)]
+    fn _get_content(this: &Request, callback: &Function);
+
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(js_namespace = ["chrome", "devtools", "network"], js_name = getHAR)]
+    fn _get_har(callback: &Function);
+}
+
+impl Request {
+    pub fn entry(&self) -> Result<har::Entry, Error> {
+        Ok(serde_wasm_bindgen::from_value(self.into())?)
+    }
+
+    // Safari has no callback form, and no request bodies.
+    #[cfg(not(feature = "safari"))]
+    pub async fn get_content(&self) -> Result<ResourceContent, Error> {
+        get_content_with(|callback| self._get_content(callback)).await
+    }
+}
+
+#[cfg(not(feature = "safari"))]
+pub async fn get_har() -> Result<har::Log, Error> {
+    resolve(promise_from_callback(|callback| _get_har(&callback))).await
+}
+
+pub mod on_request_finished {
+    use wasm_bindgen::prelude::*;
+    use super::Request;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "devtools", "network", "onRequestFinished"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(Request)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "devtools", "network"], js_name = onRequestFinished)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(Request)>
+        where T: FnMut(Request) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(Request) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<Request> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_navigated {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "devtools", "network", "onNavigated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "devtools", "network"], js_name = onNavigated)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(String)>
+        where T: FnMut(String) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<String> {
+        stream_of(listen)
+    }
+}
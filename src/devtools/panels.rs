@@ -0,0 +1,134 @@
+use wasm_bindgen::prelude::*;
+use crate::events::Event;
+use crate::error::Error;
+#[cfg(not(feature = "safari"))]
+use js_sys::Function;
+#[cfg(not(feature = "safari"))]
+use wasm_bindgen_futures::JsFuture;
+#[cfg(not(feature = "safari"))]
+use serde::Serialize;
+#[cfg(not(feature = "safari"))]
+use crate::utils::{promise_from_callback, resolve};
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone)]
+    pub type ExtensionPanel;
+
+    #[wasm_bindgen(method, js_name = createStatusBarButton)]
+    pub fn create_status_bar_button(
+        this: &ExtensionPanel,
+        icon_path: &str,
+        tooltip_text: &str,
+        disabled: bool,
+    ) -> Button;
+
+    #[wasm_bindgen(method, getter, js_name = onShown)]
+    pub fn on_shown(this: &ExtensionPanel) -> Event;
+
+    #[wasm_bindgen(method, getter, js_name = onHidden)]
+    pub fn on_hidden(this: &ExtensionPanel) -> Event;
+
+    #[wasm_bindgen(method, getter, js_name = onSearch)]
+    pub fn on_search(this: &ExtensionPanel) -> Event;
+
+    #[derive(Debug, Clone)]
+    pub type ExtensionSidebarPane;
+
+    #[wasm_bindgen(method, js_name = setHeight)]
+    pub fn set_height(this: &ExtensionSidebarPane, height: &str);
+
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(method, js_name = setExpression)]
+    fn _set_expression(this: &ExtensionSidebarPane, expression: &str, root_title: Option<&str>, callback: &Function);
+
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(method, js_name = setObject)]
+    fn _set_object(this: &ExtensionSidebarPane, json_object: &str, root_title: Option<&str>, callback: &Function);
+
+    #[wasm_bindgen(method, js_name = setPage)]
+    pub fn set_page(this: &ExtensionSidebarPane, path: &str);
+
+    #[wasm_bindgen(method, getter, js_name = onShown)]
+    pub fn on_shown(this: &ExtensionSidebarPane) -> Event;
+
+    #[wasm_bindgen(method, getter, js_name = onHidden)]
+    pub fn on_hidden(this: &ExtensionSidebarPane) -> Event;
+
+    #[derive(Debug, Clone)]
+    pub type Button;
+
+    #[wasm_bindgen(method, js_name = update)]
+    pub fn update(this: &Button, icon_path: Option<&str>, tooltip_text: Option<&str>, disabled: Option<bool>);
+
+    #[wasm_bindgen(method, getter, js_name = onClicked)]
+    pub fn on_clicked(this: &Button) -> Event;
+
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(js_namespace = ["chrome", "devtools", "panels"], js_name = create)]
+    fn _create(title: &str, icon_path: &str, page_path: &str, callback: &Function);
+
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(js_namespace = ["chrome", "devtools", "panels", "elements"], js_name = createSidebarPane)]
+    fn _elements_create_sidebar_pane(title: &str, callback: &Function);
+
+    #[cfg(not(feature = "safari"))]
+    #[wasm_bindgen(js_namespace = ["chrome", "devtools", "panels", "sources"], js_name = createSidebarPane)]
+    fn _sources_create_sidebar_pane(title: &str, callback: &Function);
+}
+
+// Safari has no callback form, and no sidebar panes.
+#[cfg(not(feature = "safari"))]
+impl ExtensionSidebarPane {
+    pub async fn set_expression(&self, expression: &str, root_title: Option<&str>) -> Result<(), Error> {
+        resolve(promise_from_callback(|callback| self._set_expression(expression, root_title, &callback))).await
+    }
+
+    pub async fn set_object<T: Serialize>(&self, object: &T, root_title: Option<&str>) -> Result<(), Error> {
+        let json = js_sys::JSON::stringify(&serde_wasm_bindgen::to_value(object)?)?;
+        let json = String::from(json);
+
+        resolve(promise_from_callback(|callback| self._set_object(&json, root_title, &callback))).await
+    }
+}
+
+pub async fn create(title: &str, icon_path: &str, page_path: &str) -> Result<ExtensionPanel, Error> {
+    #[cfg(feature = "safari")]
+    let panel = crate::namespace::call("devtools.panels", "create", &[title.into(), icon_path.into(), page_path.into()]).await?;
+    #[cfg(not(feature = "safari"))]
+    let panel = JsFuture::from(promise_from_callback(|callback| _create(title, icon_path, page_path, &callback))).await?;
+
+    Ok(panel.unchecked_into())
+}
+
+#[cfg(not(feature = "safari"))]
+pub mod elements {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+    use crate::utils::promise_from_callback;
+    use crate::error::Error;
+    use super::ExtensionSidebarPane;
+
+    pub async fn create_sidebar_pane(title: &str) -> Result<ExtensionSidebarPane, Error> {
+        let pane = promise_from_callback(|callback| super::_elements_create_sidebar_pane(title, &callback));
+        let pane = JsFuture::from(pane).await?;
+
+        Ok(pane.unchecked_into())
+    }
+}
+
+#[cfg(not(feature = "safari"))]
+pub mod sources {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+    use crate::utils::promise_from_callback;
+    use crate::error::Error;
+    use super::ExtensionSidebarPane;
+
+    pub async fn create_sidebar_pane(title: &str) -> Result<ExtensionSidebarPane, Error> {
+        let pane = promise_from_callback(|callback| super::_sources_create_sidebar_pane(title, &callback));
+        let pane = JsFuture::from(pane).await?;
+
+        Ok(pane.unchecked_into())
+    }
+}
@@ -0,0 +1,35 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils;
+use crate::error::Error;
+
+/// A Chromium net error code, where anything other than zero is a failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ResolveCallbackResultCode(pub i32);
+
+impl ResolveCallbackResultCode {
+    pub const OK: Self = Self(0);
+
+    pub fn is_ok(self) -> bool {
+        self == Self::OK
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveCallbackResult {
+    pub result_code: ResolveCallbackResultCode,
+    pub address: Option<String>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "dns"], js_name = resolve)]
+    fn _resolve(hostname: &str) -> Promise;
+}
+
+pub async fn resolve(hostname: &str) -> Result<ResolveCallbackResult, Error> {
+    utils::resolve(_resolve(hostname)).await
+}
@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::{deserialize_optional_bytes, resolve};
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OperationResult {
+    Unknown,
+    Success,
+    Unsupported,
+    Cancelled,
+    DeviceBusy,
+    Invalid,
+    WrongType,
+    Eof,
+    AdfJammed,
+    AdfEmpty,
+    CoverOpen,
+    IoError,
+    AccessDenied,
+    NoMemory,
+    Unreachable,
+    Missing,
+    InternalError,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConnectionType {
+    Unspecified,
+    Usb,
+    Network,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionType {
+    Unknown,
+    Bool,
+    Int,
+    Fixed,
+    String,
+    Button,
+    Group,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionUnit {
+    Unitless,
+    Pixel,
+    Bit,
+    Mm,
+    Dpi,
+    Percent,
+    Microsecond,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Configurability {
+    NotConfigurable,
+    SoftwareConfigurable,
+    HardwareConfigurable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConstraintType {
+    IntRange,
+    FixedRange,
+    IntList,
+    FixedList,
+    StringList,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OptionValue {
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Numbers(Vec<f64>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConstraintList {
+    Numbers(Vec<f64>),
+    Strings(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionConstraint {
+    #[serde(rename = "type")]
+    pub constraint_type: ConstraintType,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub quant: Option<f64>,
+    pub list: Option<ConstraintList>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScannerOption {
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub option_type: OptionType,
+    pub unit: OptionUnit,
+    pub value: Option<OptionValue>,
+    pub constraint: Option<OptionConstraint>,
+    pub is_detectable: bool,
+    pub configurability: Configurability,
+    pub is_auto_settable: bool,
+    pub is_emulated: bool,
+    pub is_active: bool,
+    pub is_advanced: bool,
+    pub is_internal: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanOptions {
+    pub mime_types: Option<Vec<String>>,
+    pub max_images: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanResults {
+    /// Each scanned image as a `data:` URL.
+    pub data_urls: Vec<String>,
+    pub mime_type: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceFilter {
+    pub local: Option<bool>,
+    pub secure: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScannerInfo {
+    pub scanner_id: String,
+    pub name: String,
+    pub manufacturer: String,
+    pub model: String,
+    pub device_uuid: String,
+    pub connection_type: ConnectionType,
+    pub secure: bool,
+    pub image_formats: Vec<String>,
+    pub protocol_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetScannerListResponse {
+    pub result: OperationResult,
+    pub scanners: Vec<ScannerInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenScannerResponse {
+    pub scanner_id: String,
+    pub result: OperationResult,
+    pub scanner_handle: Option<String>,
+    pub options: Option<BTreeMap<String, ScannerOption>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseScannerResponse {
+    pub scanner_handle: String,
+    pub result: OperationResult,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSetting {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub option_type: OptionType,
+    pub value: Option<OptionValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetOptionResult {
+    pub name: String,
+    pub result: OperationResult,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetOptionsResponse {
+    pub scanner_handle: String,
+    pub results: Vec<SetOptionResult>,
+    pub options: Option<BTreeMap<String, ScannerOption>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartScanOptions {
+    /// The MIME type of the scanned image, for example `image/png`.
+    pub format: String,
+    pub max_read_size: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartScanResponse {
+    pub scanner_handle: String,
+    pub result: OperationResult,
+    pub job: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelScanResponse {
+    pub job: String,
+    pub result: OperationResult,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadScanDataResponse {
+    pub job: String,
+    pub result: OperationResult,
+    #[serde(default, deserialize_with = "deserialize_optional_bytes")]
+    pub data: Option<Vec<u8>>,
+    pub estimated_completion: Option<u32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "documentScan"], js_name = scan)]
+    fn _scan(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "documentScan"], js_name = getScannerList)]
+    fn _get_scanner_list(filter: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "documentScan"], js_name = openScanner)]
+    fn _open_scanner(scanner_id: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "documentScan"], js_name = closeScanner)]
+    fn _close_scanner(scanner_handle: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "documentScan"], js_name = setOptions)]
+    fn _set_options(scanner_handle: &str, options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "documentScan"], js_name = startScan)]
+    fn _start_scan(scanner_handle: &str, options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "documentScan"], js_name = cancelScan)]
+    fn _cancel_scan(job: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "documentScan"], js_name = readScanData)]
+    fn _read_scan_data(job: &str) -> Promise;
+}
+
+pub async fn scan(options: &ScanOptions) -> Result<ScanResults, Error> {
+    resolve(_scan(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn get_scanner_list(filter: &DeviceFilter) -> Result<GetScannerListResponse, Error> {
+    resolve(_get_scanner_list(serde_wasm_bindgen::to_value(filter)?)).await
+}
+
+pub async fn open_scanner(scanner_id: &str) -> Result<OpenScannerResponse, Error> {
+    resolve(_open_scanner(scanner_id)).await
+}
+
+pub async fn close_scanner(scanner_handle: &str) -> Result<CloseScannerResponse, Error> {
+    resolve(_close_scanner(scanner_handle)).await
+}
+
+pub async fn set_options(scanner_handle: &str, options: &[OptionSetting]) -> Result<SetOptionsResponse, Error> {
+    resolve(_set_options(scanner_handle, serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn start_scan(scanner_handle: &str, options: &StartScanOptions) -> Result<StartScanResponse, Error> {
+    resolve(_start_scan(scanner_handle, serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn cancel_scan(job: &str) -> Result<CancelScanResponse, Error> {
+    resolve(_cancel_scan(job)).await
+}
+
+/// Call repeatedly until the result is `Eof` to collect the whole image.
+pub async fn read_scan_data(job: &str) -> Result<ReadScanDataResponse, Error> {
+    resolve(_read_scan_data(job)).await
+}
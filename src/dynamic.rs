@@ -0,0 +1,109 @@
+//! Untyped access to APIs this crate doesn't bind yet, looked up by path at
+//! call time, e.g. `dynamic::call(&["chrome", "tabs", "query"], &[query])`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use js_sys::{Array, Function, Promise, Reflect};
+use crate::events::{Event, EventHandle};
+use crate::error::Error;
+
+/// The value at `path` under the global object, or `Error::Unsupported` if any part is missing.
+pub fn get(path: &[&str]) -> Result<JsValue, Error> {
+    let mut value: JsValue = js_sys::global().into();
+
+    for key in path {
+        if value.is_object() || value.is_function() {
+            value = Reflect::get(&value, &(*key).into())?;
+        } else {
+            value = JsValue::UNDEFINED;
+        }
+
+        if value.is_undefined() || value.is_null() {
+            return Err(Error::Unsupported(path.join(".")));
+        }
+    }
+
+    Ok(value)
+}
+
+fn split<'a>(path: &[&'a str]) -> Result<(JsValue, &'a str), Error> {
+    match path.split_last() {
+        Some((method, parent)) => Ok((get(parent)?, method)),
+        None => Err(Error::Unsupported(String::new())),
+    }
+}
+
+/// Calls the function at `path` in promise form and waits for the promise.
+/// Synchronous APIs resolve straight away with what they return.
+///
+/// Chrome's Manifest V2 APIs don't return promises, so use `call_with_callback` there.
+pub async fn call(path: &[&str], args: &[JsValue]) -> Result<JsValue, Error> {
+    let (parent, method) = split(path)?;
+
+    call_method(&parent, method, args).await
+}
+
+/// Calls the function at `path` with a trailing callback, and resolves with the
+/// callback's first argument, or fails with `chrome.runtime.lastError`.
+pub async fn call_with_callback(path: &[&str], args: &[JsValue]) -> Result<JsValue, Error> {
+    let (parent, method) = split(path)?;
+
+    call_method_with_callback(&parent, method, args).await
+}
+
+/// Attaches `callback` to the event at `path`, e.g. `&["chrome", "tabs", "onUpdated"]`.
+pub fn add_listener<T: ?Sized + 'static>(path: &[&str], callback: Closure<T>) -> Result<EventHandle, Error> {
+    let event = get(path)?;
+
+    if !Reflect::get(&event, &"addListener".into())?.is_function() {
+        return Err(Error::Unsupported(path.join(".")));
+    }
+
+    Ok(EventHandle::new(event.unchecked_into::<Event>(), callback))
+}
+
+fn method(target: &JsValue, name: &str) -> Result<Function, Error> {
+    Reflect::get(target, &name.into())?
+        .dyn_into()
+        .map_err(|_| Error::Unsupported(name.to_owned()))
+}
+
+pub(crate) async fn call_method(target: &JsValue, name: &str, args: &[JsValue]) -> Result<JsValue, Error> {
+    let args: Array = args.iter().collect();
+    let result = method(target, name)?.apply(target, &args)?;
+
+    match result.dyn_into::<Promise>() {
+        Ok(promise) => Ok(JsFuture::from(promise).await?),
+        Err(value) => Ok(value),
+    }
+}
+
+pub(crate) async fn call_method_with_callback(target: &JsValue, name: &str, args: &[JsValue]) -> Result<JsValue, Error> {
+    let function = method(target, name)?;
+    let args: Array = args.iter().collect();
+    let last_error = Rc::new(RefCell::new(None));
+    let mut function = Some(function);
+
+    let promise = Promise::new(&mut |resolve, reject| {
+        let last_error = last_error.clone();
+        let callback = Closure::once_into_js(move |value: JsValue| {
+            *last_error.borrow_mut() = Error::last_error();
+            resolve.call1(&JsValue::NULL, &value).ok();
+        });
+        args.push(&callback);
+
+        if let Some(Err(error)) = function.take().map(|function| function.apply(target, &args)) {
+            reject.call1(&JsValue::NULL, &error).ok();
+        }
+    });
+
+    let value = JsFuture::from(promise).await?;
+
+    match last_error.take() {
+        Some(error) => Err(error),
+        None => Ok(value),
+    }
+}
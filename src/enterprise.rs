@@ -0,0 +1,2 @@
+pub mod platform_keys;
+pub mod hardware_platform;
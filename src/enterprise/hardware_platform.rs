@@ -0,0 +1,22 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HardwarePlatformInfo {
+    pub manufacturer: String,
+    pub model: String,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "enterprise", "hardwarePlatform"], js_name = getHardwarePlatformInfo)]
+    fn _get_hardware_platform_info() -> Promise;
+}
+
+pub async fn get_hardware_platform_info() -> Result<HardwarePlatformInfo, Error> {
+    resolve(_get_hardware_platform_info()).await
+}
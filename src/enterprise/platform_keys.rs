@@ -0,0 +1,115 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use js_sys::{Array, Function, Reflect};
+use serde::{Serialize, Deserialize};
+use web_sys::SubtleCrypto;
+use crate::utils::{from_array_buffer, promise_from_callback, to_array_buffer};
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Scope {
+    User,
+    Machine,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Algorithm {
+    Rsa,
+    Ecdsa,
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub id: String,
+    pub subtle_crypto: SubtleCrypto,
+    pub software_backed_subtle_crypto: SubtleCrypto,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeKeyOptions {
+    pub challenge: Vec<u8>,
+    pub register_key: Option<Algorithm>,
+    pub scope: Scope,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RegisterKeyOptions {
+    algorithm: Algorithm,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChallengeKeyFields {
+    register_key: Option<RegisterKeyOptions>,
+    scope: Scope,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "enterprise", "platformKeys"], js_name = getTokens)]
+    fn _get_tokens(callback: &Function);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "enterprise", "platformKeys"], js_name = getCertificates)]
+    fn _get_certificates(token_id: &str, callback: &Function);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "enterprise", "platformKeys"], js_name = importCertificate)]
+    fn _import_certificate(token_id: &str, certificate: &JsValue, callback: &Function);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "enterprise", "platformKeys"], js_name = removeCertificate)]
+    fn _remove_certificate(token_id: &str, certificate: &JsValue, callback: &Function);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "enterprise", "platformKeys"], js_name = challengeKey)]
+    fn _challenge_key(options: JsValue, callback: &Function);
+}
+
+pub async fn get_tokens() -> Result<Vec<Token>, Error> {
+    let tokens: Array = JsFuture::from(promise_from_callback(|callback| _get_tokens(&callback))).await?.dyn_into()?;
+
+    tokens
+        .iter()
+        .map(|token| Ok(Token {
+            id: Reflect::get(&token, &"id".into())?.as_string().unwrap_or_default(),
+            subtle_crypto: Reflect::get(&token, &"subtleCrypto".into())?.unchecked_into(),
+            software_backed_subtle_crypto: Reflect::get(&token, &"softwareBackedSubtleCrypto".into())?.unchecked_into(),
+        }))
+        .collect()
+}
+
+/// Resolves to the DER encoding of each certificate on the token.
+pub async fn get_certificates(token_id: &str) -> Result<Vec<Vec<u8>>, Error> {
+    let certificates: Array = JsFuture::from(promise_from_callback(|callback| _get_certificates(token_id, &callback)))
+        .await?
+        .dyn_into()?;
+
+    Ok(certificates.iter().map(|certificate| from_array_buffer(&certificate)).collect())
+}
+
+pub async fn import_certificate(token_id: &str, certificate: &[u8]) -> Result<(), Error> {
+    let certificate = to_array_buffer(certificate).into();
+    JsFuture::from(promise_from_callback(|callback| _import_certificate(token_id, &certificate, &callback))).await?;
+
+    Ok(())
+}
+
+pub async fn remove_certificate(token_id: &str, certificate: &[u8]) -> Result<(), Error> {
+    let certificate = to_array_buffer(certificate).into();
+    JsFuture::from(promise_from_callback(|callback| _remove_certificate(token_id, &certificate, &callback))).await?;
+
+    Ok(())
+}
+
+/// Resolves to the challenge response.
+pub async fn challenge_key(options: &ChallengeKeyOptions) -> Result<Vec<u8>, Error> {
+    let js_options = serde_wasm_bindgen::to_value(&ChallengeKeyFields {
+        register_key: options.register_key.map(|algorithm| RegisterKeyOptions { algorithm }),
+        scope: options.scope,
+    })?;
+    Reflect::set(&js_options, &"challenge".into(), &to_array_buffer(&options.challenge))?;
+
+    let response = JsFuture::from(promise_from_callback(|callback| _challenge_key(js_options, &callback))).await?;
+
+    Ok(from_array_buffer(&response))
+}
@@ -0,0 +1,207 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use wasm_bindgen::prelude::*;
+use futures_core::Stream;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use crate::error::Error;
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone)]
+    pub type Event;
+
+    #[wasm_bindgen(method, js_name = addListener)]
+    fn _add_listener(this: &Event, callback: &JsValue);
+
+    #[wasm_bindgen(method, js_name = removeListener)]
+    fn _remove_listener(this: &Event, callback: &JsValue);
+
+    #[wasm_bindgen(method, js_name = hasListener)]
+    fn _has_listener(this: &Event, callback: &JsValue) -> bool;
+}
+
+impl Event {
+    pub fn add_listener<T: ?Sized>(&self, callback: &Closure<T>) {
+        self._add_listener(callback.as_ref());
+    }
+
+    pub fn remove_listener<T: ?Sized>(&self, callback: &Closure<T>) {
+        self._remove_listener(callback.as_ref());
+    }
+
+    pub fn has_listener<T: ?Sized>(&self, callback: &Closure<T>) -> bool {
+        self._has_listener(callback.as_ref())
+    }
+}
+
+/// Owns a listener attached to an event, and removes it when dropped.
+#[must_use = "the listener is removed as soon as the handle is dropped"]
+pub struct EventHandle {
+    event: Event,
+    callback: Option<Box<dyn AsRef<JsValue>>>,
+}
+
+impl EventHandle {
+    pub fn new<T: ?Sized + 'static>(event: Event, callback: Closure<T>) -> Self {
+        event.add_listener(&callback);
+
+        Self {
+            event,
+            callback: Some(Box::new(callback)),
+        }
+    }
+
+    /// Leaves the listener attached for the rest of the program.
+    pub fn forget(mut self) {
+        std::mem::forget(self.callback.take());
+    }
+
+    pub fn remove(self) {
+        drop(self);
+    }
+}
+
+impl Drop for EventHandle {
+    fn drop(&mut self) {
+        if let Some(callback) = &self.callback {
+            self.event._remove_listener((**callback).as_ref());
+        }
+    }
+}
+
+/// Deserializes an argument passed to an event listener, or logs it and
+/// returns `None` if it doesn't parse, e.g. because the browser sent an enum
+/// value added after this crate was written.
+pub(crate) fn deserialize_arg<T: DeserializeOwned>(value: JsValue) -> Option<T> {
+    convert_arg(&value, |value| Ok(serde_wasm_bindgen::from_value(value.clone())?))
+}
+
+/// `deserialize_arg` for arguments read with a hand-written conversion.
+pub(crate) fn convert_arg<T, F>(value: &JsValue, convert: F) -> Option<T>
+    where F: FnOnce(&JsValue) -> Result<T, Error>,
+{
+    match convert(value) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            web_sys::console::warn_3(&"Skipped an event whose argument didn't deserialize:".into(), &error.to_string().into(), value);
+            None
+        }
+    }
+}
+
+/// `deserialize_arg` for listener closures, leaving the closure with its
+/// return type's default if the argument doesn't parse. Pass a conversion as
+/// the second argument to use `convert_arg` instead.
+macro_rules! event_arg {
+    ($value:expr) => {
+        match $crate::events::deserialize_arg($value) {
+            Some(value) => value,
+            None => return Default::default(),
+        }
+    };
+    ($value:expr, $convert:expr) => {
+        match $crate::events::convert_arg(&$value, $convert) {
+            Some(value) => value,
+            None => return Default::default(),
+        }
+    };
+}
+
+pub(crate) use event_arg;
+
+/// How many undelivered events an `EventStream` holds before dropping the oldest.
+pub const STREAM_BUFFER_SIZE: usize = 128;
+
+struct StreamBuffer<T> {
+    items: VecDeque<T>,
+    waker: Option<Waker>,
+}
+
+/// A `Stream` of event occurrences. The listener is removed when the stream is dropped.
+pub struct EventStream<T> {
+    buffer: Rc<RefCell<StreamBuffer<T>>>,
+    _handle: EventHandle,
+}
+
+impl<T> Stream for EventStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut buffer = self.buffer.borrow_mut();
+
+        match buffer.items.pop_front() {
+            Some(item) => Poll::Ready(Some(item)),
+            None => {
+                buffer.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Turns an event's `listen` function into a `Stream`, e.g. `stream_of(tabs::on_created::listen)`.
+pub fn stream_of<T, L>(listen: L) -> EventStream<T>
+    where T: 'static,
+          L: FnOnce(Box<dyn FnMut(T)>) -> EventHandle,
+{
+    let buffer = Rc::new(RefCell::new(StreamBuffer {
+        items: VecDeque::new(),
+        waker: None,
+    }));
+
+    let sender = Rc::clone(&buffer);
+    let handle = listen(Box::new(move |item| {
+        let mut buffer = sender.borrow_mut();
+
+        if buffer.items.len() == STREAM_BUFFER_SIZE {
+            buffer.items.pop_front();
+        }
+        buffer.items.push_back(item);
+
+        if let Some(waker) = buffer.waker.take() {
+            waker.wake();
+        }
+    }));
+
+    EventStream {
+        buffer,
+        _handle: handle,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Port {
+    Single(u16),
+    Range(u16, u16),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlFilter {
+    pub schemes: Option<Vec<String>>,
+    pub ports: Option<Vec<Port>>,
+    pub url_contains: Option<String>,
+    pub url_equals: Option<String>,
+    pub url_prefix: Option<String>,
+    pub url_suffix: Option<String>,
+    pub url_matches: Option<String>,
+    pub original_url_matches: Option<String>,
+    pub origin_and_path_matches: Option<String>,
+    pub host_contains: Option<String>,
+    pub host_equals: Option<String>,
+    pub host_prefix: Option<String>,
+    pub host_suffix: Option<String>,
+    pub path_contains: Option<String>,
+    pub path_equals: Option<String>,
+    pub path_prefix: Option<String>,
+    pub path_suffix: Option<String>,
+    pub query_contains: Option<String>,
+    pub query_equals: Option<String>,
+    pub query_prefix: Option<String>,
+    pub query_suffix: Option<String>,
+}
@@ -0,0 +1,55 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Object, Promise};
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ViewType {
+    Tab,
+    Popup,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchProperties {
+    #[serde(rename = "type")]
+    pub view_type: Option<ViewType>,
+    pub window_id: Option<i32>,
+    pub tab_id: Option<i32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "extension"], js_name = getViews)]
+    fn _get_views(fetch_properties: JsValue) -> Vec<Object>;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "extension"], js_name = getBackgroundPage)]
+    pub fn get_background_page() -> Option<Object>;
+
+    #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "extension"], js_name = inIncognitoContext)]
+    static IN_INCOGNITO_CONTEXT: JsValue;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "extension"], js_name = isAllowedIncognitoAccess)]
+    fn _is_allowed_incognito_access() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "extension"], js_name = isAllowedFileSchemeAccess)]
+    fn _is_allowed_file_scheme_access() -> Promise;
+}
+
+pub fn get_views(fetch_properties: &FetchProperties) -> Result<Vec<Object>, Error> {
+    Ok(_get_views(serde_wasm_bindgen::to_value(fetch_properties)?))
+}
+
+pub fn in_incognito_context() -> bool {
+    IN_INCOGNITO_CONTEXT.with(JsValue::is_truthy)
+}
+
+pub async fn is_allowed_incognito_access() -> Result<bool, Error> {
+    resolve(_is_allowed_incognito_access()).await
+}
+
+pub async fn is_allowed_file_scheme_access() -> Result<bool, Error> {
+    resolve(_is_allowed_file_scheme_access()).await
+}
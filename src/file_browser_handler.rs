@@ -0,0 +1,55 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Reflect};
+use web_sys::FileSystemEntry;
+use crate::error::Error;
+
+#[derive(Debug, Clone)]
+pub struct FileHandlerExecuteEventDetails {
+    pub entries: Vec<FileSystemEntry>,
+    pub tab_id: Option<i32>,
+}
+
+impl FileHandlerExecuteEventDetails {
+    fn from_js(details: &JsValue) -> Result<Self, Error> {
+        let entries: Array = Reflect::get(details, &"entries".into())?.dyn_into()?;
+        let tab_id = Reflect::get(details, &"tab_id".into())?.as_f64();
+
+        Ok(Self {
+            entries: entries.iter().map(JsCast::unchecked_into).collect(),
+            tab_id: tab_id.map(|tab_id| tab_id as i32),
+        })
+    }
+}
+
+pub mod on_execute {
+    use wasm_bindgen::prelude::*;
+    use super::FileHandlerExecuteEventDetails;
+    use crate::events::{Event, EventHandle, EventStream, event_arg, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileBrowserHandler", "onExecute"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileBrowserHandler"], js_name = onExecute)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, JsValue)>
+        where T: FnMut(String, FileHandlerExecuteEventDetails) + 'static,
+    {
+        Closure::wrap(Box::new(move |id, details: JsValue| {
+            callback(id, event_arg!(details, FileHandlerExecuteEventDetails::from_js));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, FileHandlerExecuteEventDetails) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, FileHandlerExecuteEventDetails)> {
+        stream_of(|mut callback| listen(move |id, details| callback((id, details))))
+    }
+}
@@ -0,0 +1,1494 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Date, Function, Promise, Reflect};
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use crate::events::deserialize_arg;
+use crate::utils::{deserialize_bytes, resolve, to_array_buffer};
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProviderError {
+    Ok,
+    Failed,
+    InUse,
+    Exists,
+    NotFound,
+    AccessDenied,
+    TooManyOpened,
+    NoMemory,
+    NoSpace,
+    NotADirectory,
+    InvalidOperation,
+    Security,
+    Abort,
+    NotAFile,
+    NotEmpty,
+    InvalidUrl,
+    Io,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OpenFileMode {
+    Read,
+    Write,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChangeType {
+    Changed,
+    Deleted,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudIdentifier {
+    pub provider_name: String,
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudFileInfo {
+    pub version_tag: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EntryMetadata {
+    pub is_directory: Option<bool>,
+    pub name: Option<String>,
+    pub size: Option<f64>,
+    /// Milliseconds since the Unix epoch.
+    pub modification_time: Option<f64>,
+    pub mime_type: Option<String>,
+    /// A data URI, no larger than 32 KB.
+    pub thumbnail: Option<String>,
+    pub cloud_identifier: Option<CloudIdentifier>,
+    pub cloud_file_info: Option<CloudFileInfo>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EntryMetadataFields<'a> {
+    is_directory: Option<bool>,
+    name: &'a Option<String>,
+    size: Option<f64>,
+    mime_type: &'a Option<String>,
+    thumbnail: &'a Option<String>,
+    cloud_identifier: &'a Option<CloudIdentifier>,
+    cloud_file_info: &'a Option<CloudFileInfo>,
+}
+
+impl EntryMetadata {
+    fn to_js(&self) -> Result<JsValue, Error> {
+        let metadata = serde_wasm_bindgen::to_value(&EntryMetadataFields {
+            is_directory: self.is_directory,
+            name: &self.name,
+            size: self.size,
+            mime_type: &self.mime_type,
+            thumbnail: &self.thumbnail,
+            cloud_identifier: &self.cloud_identifier,
+            cloud_file_info: &self.cloud_file_info,
+        })?;
+
+        // Chrome expects a Date object here.
+        if let Some(modification_time) = self.modification_time {
+            Reflect::set(&metadata, &"modificationTime".into(), &Date::new(&modification_time.into()))?;
+        }
+
+        Ok(metadata)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Action {
+    pub id: String,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenedFile {
+    pub open_request_id: i32,
+    pub file_path: String,
+    pub mode: OpenFileMode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Watcher {
+    pub entry_path: String,
+    pub recursive: bool,
+    pub last_tag: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileSystemInfo {
+    pub file_system_id: String,
+    pub display_name: String,
+    pub writable: bool,
+    pub opened_files_limit: u32,
+    pub opened_files: Vec<OpenedFile>,
+    pub supports_notify_tag: Option<bool>,
+    pub watchers: Vec<Watcher>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MountOptions {
+    pub file_system_id: String,
+    pub display_name: String,
+    pub writable: Option<bool>,
+    pub opened_files_limit: Option<u32>,
+    pub supports_notify_tag: Option<bool>,
+    pub persistent: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmountOptions {
+    pub file_system_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    pub entry_path: String,
+    pub change_type: ChangeType,
+    pub cloud_file_info: Option<CloudFileInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyOptions {
+    pub file_system_id: String,
+    pub observed_path: String,
+    pub recursive: bool,
+    pub change_type: ChangeType,
+    pub changes: Option<Vec<Change>>,
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmountRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMetadataRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub entry_path: String,
+    pub is_directory: bool,
+    pub name: bool,
+    pub size: bool,
+    pub modification_time: bool,
+    pub thumbnail: bool,
+    pub mime_type: bool,
+    #[serde(default)]
+    pub cloud_identifier: bool,
+    #[serde(default)]
+    pub cloud_file_info: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetActionsRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub entry_paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadDirectoryRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub directory_path: String,
+    pub is_directory: bool,
+    pub name: bool,
+    pub size: bool,
+    pub modification_time: bool,
+    pub thumbnail: bool,
+    pub mime_type: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenFileRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub file_path: String,
+    pub mode: OpenFileMode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseFileRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub open_request_id: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadFileRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub open_request_id: i32,
+    pub offset: f64,
+    pub length: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDirectoryRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub directory_path: String,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteEntryRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub entry_path: String,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateFileRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub file_path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyEntryRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub source_path: String,
+    pub target_path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveEntryRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub source_path: String,
+    pub target_path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TruncateRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub file_path: String,
+    pub length: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteFileRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub open_request_id: i32,
+    pub offset: f64,
+    #[serde(deserialize_with = "deserialize_bytes")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AbortRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub operation_request_id: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigureRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddWatcherRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub entry_path: String,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveWatcherRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub entry_path: String,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecuteActionRequestedOptions {
+    pub file_system_id: String,
+    pub request_id: i32,
+    pub entry_paths: Vec<String>,
+    pub action_id: String,
+}
+
+#[derive(Debug, Clone)]
+struct Callbacks {
+    success: Function,
+    error: Function,
+}
+
+impl Callbacks {
+    fn success(&self, args: &Array) -> Result<(), Error> {
+        self.success.apply(&JsValue::NULL, args)?;
+
+        Ok(())
+    }
+
+    fn error(&self, error: ProviderError) -> Result<(), Error> {
+        self.error.call1(&JsValue::NULL, &serde_wasm_bindgen::to_value(&error)?)?;
+
+        Ok(())
+    }
+}
+
+/// Reads the options of a request, failing the request if they don't parse so
+/// the browser isn't left waiting for an answer.
+fn request_options<T: DeserializeOwned>(options: JsValue, error: &Function) -> Option<T> {
+    let options = deserialize_arg(options);
+
+    if options.is_none() {
+        let _ = error.call1(&JsValue::NULL, &"FAILED".into());
+    }
+
+    options
+}
+
+#[derive(Debug, Clone)]
+pub struct Response(Callbacks);
+
+impl Response {
+    fn new(success: Function, error: Function) -> Self {
+        Self(Callbacks { success, error })
+    }
+
+    pub fn success(&self) -> Result<(), Error> {
+        self.0.success(&Array::new())
+    }
+
+    pub fn error(&self, error: ProviderError) -> Result<(), Error> {
+        self.0.error(error)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MetadataResponse(Callbacks);
+
+impl MetadataResponse {
+    fn new(success: Function, error: Function) -> Self {
+        Self(Callbacks { success, error })
+    }
+
+    pub fn success(&self, metadata: &EntryMetadata) -> Result<(), Error> {
+        self.0.success(&Array::of1(&metadata.to_js()?))
+    }
+
+    pub fn error(&self, error: ProviderError) -> Result<(), Error> {
+        self.0.error(error)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ActionsResponse(Callbacks);
+
+impl ActionsResponse {
+    fn new(success: Function, error: Function) -> Self {
+        Self(Callbacks { success, error })
+    }
+
+    pub fn success(&self, actions: &[Action]) -> Result<(), Error> {
+        self.0.success(&Array::of1(&serde_wasm_bindgen::to_value(actions)?))
+    }
+
+    pub fn error(&self, error: ProviderError) -> Result<(), Error> {
+        self.0.error(error)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EntriesResponse(Callbacks);
+
+impl EntriesResponse {
+    fn new(success: Function, error: Function) -> Self {
+        Self(Callbacks { success, error })
+    }
+
+    /// Set `has_more` to send the entries in several batches.
+    pub fn success(&self, entries: &[EntryMetadata], has_more: bool) -> Result<(), Error> {
+        let js_entries = Array::new();
+        for entry in entries {
+            js_entries.push(&entry.to_js()?);
+        }
+
+        self.0.success(&Array::of2(&js_entries, &has_more.into()))
+    }
+
+    pub fn error(&self, error: ProviderError) -> Result<(), Error> {
+        self.0.error(error)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenFileResponse(Callbacks);
+
+impl OpenFileResponse {
+    fn new(success: Function, error: Function) -> Self {
+        Self(Callbacks { success, error })
+    }
+
+    pub fn success(&self, metadata: Option<&EntryMetadata>) -> Result<(), Error> {
+        match metadata {
+            Some(metadata) => self.0.success(&Array::of1(&metadata.to_js()?)),
+            None => self.0.success(&Array::new()),
+        }
+    }
+
+    pub fn error(&self, error: ProviderError) -> Result<(), Error> {
+        self.0.error(error)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReadFileResponse(Callbacks);
+
+impl ReadFileResponse {
+    fn new(success: Function, error: Function) -> Self {
+        Self(Callbacks { success, error })
+    }
+
+    /// Set `has_more` to send the data in several chunks.
+    pub fn success(&self, data: &[u8], has_more: bool) -> Result<(), Error> {
+        self.0.success(&Array::of2(&to_array_buffer(data), &has_more.into()))
+    }
+
+    pub fn error(&self, error: ProviderError) -> Result<(), Error> {
+        self.0.error(error)
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider"], js_name = mount)]
+    fn _mount(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider"], js_name = unmount)]
+    fn _unmount(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider"], js_name = getAll)]
+    fn _get_all() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider"], js_name = get)]
+    fn _get(file_system_id: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider"], js_name = notify)]
+    fn _notify(options: JsValue) -> Promise;
+}
+
+pub async fn mount(options: &MountOptions) -> Result<(), Error> {
+    resolve(_mount(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn unmount(options: &UnmountOptions) -> Result<(), Error> {
+    resolve(_unmount(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn get_all() -> Result<Vec<FileSystemInfo>, Error> {
+    resolve(_get_all()).await
+}
+
+pub async fn get(file_system_id: &str) -> Result<FileSystemInfo, Error> {
+    resolve(_get(file_system_id)).await
+}
+
+pub async fn notify(options: &NotifyOptions) -> Result<(), Error> {
+    resolve(_notify(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+/// A file system implementation. The required methods are the minimum for a
+/// read-only file system; the rest fail with `InvalidOperation` by default.
+///
+/// Each request must be answered exactly once through its response, either
+/// immediately or after some asynchronous work.
+pub trait Provider: 'static {
+    fn on_unmount_requested(&mut self, options: UnmountRequestedOptions, response: Response);
+
+    fn on_get_metadata_requested(&mut self, options: GetMetadataRequestedOptions, response: MetadataResponse);
+
+    fn on_get_actions_requested(&mut self, _options: GetActionsRequestedOptions, response: ActionsResponse) {
+        let _ = response.success(&[]);
+    }
+
+    fn on_read_directory_requested(&mut self, options: ReadDirectoryRequestedOptions, response: EntriesResponse);
+
+    fn on_open_file_requested(&mut self, options: OpenFileRequestedOptions, response: OpenFileResponse);
+
+    fn on_close_file_requested(&mut self, options: CloseFileRequestedOptions, response: Response);
+
+    fn on_read_file_requested(&mut self, options: ReadFileRequestedOptions, response: ReadFileResponse);
+
+    fn on_create_directory_requested(&mut self, _options: CreateDirectoryRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_delete_entry_requested(&mut self, _options: DeleteEntryRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_create_file_requested(&mut self, _options: CreateFileRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_copy_entry_requested(&mut self, _options: CopyEntryRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_move_entry_requested(&mut self, _options: MoveEntryRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_truncate_requested(&mut self, _options: TruncateRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_write_file_requested(&mut self, _options: WriteFileRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_abort_requested(&mut self, _options: AbortRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_configure_requested(&mut self, _options: ConfigureRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_mount_requested(&mut self, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_add_watcher_requested(&mut self, _options: AddWatcherRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_remove_watcher_requested(&mut self, _options: RemoveWatcherRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+
+    fn on_execute_action_requested(&mut self, _options: ExecuteActionRequestedOptions, response: Response) {
+        let _ = response.error(ProviderError::InvalidOperation);
+    }
+}
+
+/// Wires every `on*Requested` event to `provider`. The listeners live for the
+/// rest of the extension's lifetime.
+pub fn register<P: Provider>(provider: P) {
+    let provider = Rc::new(RefCell::new(provider));
+
+    let p = provider.clone();
+    let listener = on_unmount_requested::create_listener(move |options, response| p.borrow_mut().on_unmount_requested(options, response));
+    on_unmount_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_get_metadata_requested::create_listener(move |options, response| p.borrow_mut().on_get_metadata_requested(options, response));
+    on_get_metadata_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_get_actions_requested::create_listener(move |options, response| p.borrow_mut().on_get_actions_requested(options, response));
+    on_get_actions_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_read_directory_requested::create_listener(move |options, response| p.borrow_mut().on_read_directory_requested(options, response));
+    on_read_directory_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_open_file_requested::create_listener(move |options, response| p.borrow_mut().on_open_file_requested(options, response));
+    on_open_file_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_close_file_requested::create_listener(move |options, response| p.borrow_mut().on_close_file_requested(options, response));
+    on_close_file_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_read_file_requested::create_listener(move |options, response| p.borrow_mut().on_read_file_requested(options, response));
+    on_read_file_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_create_directory_requested::create_listener(move |options, response| p.borrow_mut().on_create_directory_requested(options, response));
+    on_create_directory_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_delete_entry_requested::create_listener(move |options, response| p.borrow_mut().on_delete_entry_requested(options, response));
+    on_delete_entry_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_create_file_requested::create_listener(move |options, response| p.borrow_mut().on_create_file_requested(options, response));
+    on_create_file_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_copy_entry_requested::create_listener(move |options, response| p.borrow_mut().on_copy_entry_requested(options, response));
+    on_copy_entry_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_move_entry_requested::create_listener(move |options, response| p.borrow_mut().on_move_entry_requested(options, response));
+    on_move_entry_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_truncate_requested::create_listener(move |options, response| p.borrow_mut().on_truncate_requested(options, response));
+    on_truncate_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_write_file_requested::create_listener(move |options, response| p.borrow_mut().on_write_file_requested(options, response));
+    on_write_file_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_abort_requested::create_listener(move |options, response| p.borrow_mut().on_abort_requested(options, response));
+    on_abort_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_configure_requested::create_listener(move |options, response| p.borrow_mut().on_configure_requested(options, response));
+    on_configure_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_mount_requested::create_listener(move |response| p.borrow_mut().on_mount_requested(response));
+    on_mount_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_add_watcher_requested::create_listener(move |options, response| p.borrow_mut().on_add_watcher_requested(options, response));
+    on_add_watcher_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_remove_watcher_requested::create_listener(move |options, response| p.borrow_mut().on_remove_watcher_requested(options, response));
+    on_remove_watcher_requested::add_listener(&listener);
+    listener.forget();
+
+    let p = provider.clone();
+    let listener = on_execute_action_requested::create_listener(move |options, response| p.borrow_mut().on_execute_action_requested(options, response));
+    on_execute_action_requested::add_listener(&listener);
+    listener.forget();
+}
+
+pub mod on_unmount_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{UnmountRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onUnmountRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onUnmountRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(UnmountRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(UnmountRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(UnmountRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_get_metadata_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{GetMetadataRequestedOptions, MetadataResponse, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onGetMetadataRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onGetMetadataRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(GetMetadataRequestedOptions, MetadataResponse) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, MetadataResponse::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(GetMetadataRequestedOptions, MetadataResponse) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(GetMetadataRequestedOptions, MetadataResponse)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_get_actions_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{GetActionsRequestedOptions, ActionsResponse, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onGetActionsRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onGetActionsRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(GetActionsRequestedOptions, ActionsResponse) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, ActionsResponse::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(GetActionsRequestedOptions, ActionsResponse) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(GetActionsRequestedOptions, ActionsResponse)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_read_directory_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{ReadDirectoryRequestedOptions, EntriesResponse, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onReadDirectoryRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onReadDirectoryRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(ReadDirectoryRequestedOptions, EntriesResponse) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, EntriesResponse::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ReadDirectoryRequestedOptions, EntriesResponse) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(ReadDirectoryRequestedOptions, EntriesResponse)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_open_file_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{OpenFileRequestedOptions, OpenFileResponse, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onOpenFileRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onOpenFileRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(OpenFileRequestedOptions, OpenFileResponse) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, OpenFileResponse::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(OpenFileRequestedOptions, OpenFileResponse) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(OpenFileRequestedOptions, OpenFileResponse)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_close_file_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{CloseFileRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onCloseFileRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onCloseFileRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(CloseFileRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(CloseFileRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(CloseFileRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_read_file_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{ReadFileRequestedOptions, ReadFileResponse, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onReadFileRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onReadFileRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(ReadFileRequestedOptions, ReadFileResponse) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, ReadFileResponse::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ReadFileRequestedOptions, ReadFileResponse) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(ReadFileRequestedOptions, ReadFileResponse)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_create_directory_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{CreateDirectoryRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onCreateDirectoryRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onCreateDirectoryRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(CreateDirectoryRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(CreateDirectoryRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(CreateDirectoryRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_delete_entry_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{DeleteEntryRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onDeleteEntryRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onDeleteEntryRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(DeleteEntryRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(DeleteEntryRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(DeleteEntryRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_create_file_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{CreateFileRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onCreateFileRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onCreateFileRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(CreateFileRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(CreateFileRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(CreateFileRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_copy_entry_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{CopyEntryRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onCopyEntryRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onCopyEntryRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(CopyEntryRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(CopyEntryRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(CopyEntryRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_move_entry_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{MoveEntryRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onMoveEntryRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onMoveEntryRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(MoveEntryRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(MoveEntryRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(MoveEntryRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_truncate_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{TruncateRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onTruncateRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onTruncateRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(TruncateRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(TruncateRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(TruncateRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_write_file_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{WriteFileRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onWriteFileRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onWriteFileRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(WriteFileRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(WriteFileRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(WriteFileRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_abort_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{AbortRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onAbortRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onAbortRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(AbortRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(AbortRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(AbortRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_configure_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{ConfigureRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onConfigureRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onConfigureRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(ConfigureRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ConfigureRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(ConfigureRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_mount_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::Response;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onMountRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onMountRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(Function, Function)>
+        where T: FnMut(Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |success, error| {
+            callback(Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<Response> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_add_watcher_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{AddWatcherRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onAddWatcherRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onAddWatcherRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(AddWatcherRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(AddWatcherRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(AddWatcherRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_remove_watcher_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{RemoveWatcherRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onRemoveWatcherRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onRemoveWatcherRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(RemoveWatcherRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(RemoveWatcherRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(RemoveWatcherRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
+
+pub mod on_execute_action_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{ExecuteActionRequestedOptions, Response, request_options};
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fileSystemProvider", "onExecuteActionRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fileSystemProvider"], js_name = onExecuteActionRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function, Function)>
+        where T: FnMut(ExecuteActionRequestedOptions, Response) + 'static,
+    {
+        Closure::wrap(Box::new(move |options: JsValue, success, error| {
+            let options = match request_options(options, &error) {
+                Some(options) => options,
+                None => return,
+            };
+
+            callback(options, Response::new(success, error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ExecuteActionRequestedOptions, Response) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(ExecuteActionRequestedOptions, Response)> {
+        stream_of(|mut callback| listen(move |options, response| callback((options, response))))
+    }
+}
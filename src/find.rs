@@ -0,0 +1,92 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindOptions {
+    pub tab_id: Option<i32>,
+    pub case_sensitive: Option<bool>,
+    pub match_diacritics: Option<bool>,
+    pub entire_word: Option<bool>,
+    pub include_rect_data: Option<bool>,
+    pub include_range_data: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RangeData {
+    pub frame_pos: i32,
+    pub start_text_node_pos: i32,
+    pub end_text_node_pos: i32,
+    pub start_offset: i32,
+    pub end_offset: i32,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rect {
+    pub top: f64,
+    pub left: f64,
+    pub bottom: f64,
+    pub right: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RectsAndTexts {
+    pub rect_list: Vec<Rect>,
+    pub text_list: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RectData {
+    pub rects_and_texts: RectsAndTexts,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindResult {
+    pub count: u32,
+    /// Only present if `include_range_data` was set.
+    pub range_data: Option<Vec<RangeData>>,
+    /// Only present if `include_rect_data` was set.
+    pub rect_data: Option<Vec<RectData>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HighlightOptions {
+    pub range_index: Option<u32>,
+    pub tab_id: Option<i32>,
+    pub no_scroll: Option<bool>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "find"], js_name = find)]
+    fn _find(query_phrase: &str, options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "find"], js_name = highlightResults)]
+    fn _highlight_results(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "find"], js_name = removeHighlighting)]
+    fn _remove_highlighting(tab_id: Option<i32>) -> Promise;
+}
+
+pub async fn find(query_phrase: &str, options: &FindOptions) -> Result<FindResult, Error> {
+    resolve(_find(query_phrase, serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn highlight_results(options: &HighlightOptions) -> Result<(), Error> {
+    resolve(_highlight_results(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn remove_highlighting(tab_id: Option<i32>) -> Result<(), Error> {
+    resolve(_remove_highlighting(tab_id)).await
+}
@@ -0,0 +1,478 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::types::LevelOfControl;
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GenericFamily {
+    Standard,
+    #[serde(rename = "sansserif")]
+    SansSerif,
+    Serif,
+    Fixed,
+    Cursive,
+    Fantasy,
+    Math,
+}
+
+/// An ISO 15924 script code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScriptCode {
+    Afak,
+    Arab,
+    Armi,
+    Armn,
+    Avst,
+    Bali,
+    Bamu,
+    Bass,
+    Batk,
+    Beng,
+    Blis,
+    Bopo,
+    Brah,
+    Brai,
+    Bugi,
+    Buhd,
+    Cakm,
+    Cans,
+    Cari,
+    Cham,
+    Cher,
+    Cirt,
+    Copt,
+    Cprt,
+    Cyrl,
+    Cyrs,
+    Deva,
+    Dsrt,
+    Dupl,
+    Egyd,
+    Egyh,
+    Egyp,
+    Elba,
+    Ethi,
+    Geor,
+    Geok,
+    Glag,
+    Goth,
+    Gran,
+    Grek,
+    Gujr,
+    Guru,
+    Hang,
+    Hani,
+    Hano,
+    Hans,
+    Hant,
+    Hebr,
+    Hluw,
+    Hmng,
+    Hung,
+    Inds,
+    Ital,
+    Java,
+    Jpan,
+    Jurc,
+    Kali,
+    Khar,
+    Khmr,
+    Khoj,
+    Knda,
+    Kpel,
+    Kthi,
+    Lana,
+    Laoo,
+    Latf,
+    Latg,
+    Latn,
+    Lepc,
+    Limb,
+    Lina,
+    Linb,
+    Lisu,
+    Loma,
+    Lyci,
+    Lydi,
+    Mand,
+    Mani,
+    Maya,
+    Mend,
+    Merc,
+    Mero,
+    Mlym,
+    Moon,
+    Mong,
+    Mroo,
+    Mtei,
+    Mymr,
+    Narb,
+    Nbat,
+    Nkgb,
+    Nkoo,
+    Nshu,
+    Ogam,
+    Olck,
+    Orkh,
+    Orya,
+    Osma,
+    Palm,
+    Perm,
+    Phag,
+    Phli,
+    Phlp,
+    Phlv,
+    Phnx,
+    Plrd,
+    Prti,
+    Rjng,
+    Roro,
+    Runr,
+    Samr,
+    Sara,
+    Sarb,
+    Saur,
+    Sgnw,
+    Shaw,
+    Shrd,
+    Sind,
+    Sinh,
+    Sora,
+    Sund,
+    Sylo,
+    Syrc,
+    Syre,
+    Syrj,
+    Syrn,
+    Tagb,
+    Takr,
+    Tale,
+    Talu,
+    Taml,
+    Tang,
+    Tavt,
+    Telu,
+    Teng,
+    Tfng,
+    Tglg,
+    Thaa,
+    Thai,
+    Tibt,
+    Tirh,
+    Ugar,
+    Vaii,
+    Visp,
+    Wara,
+    Wole,
+    Xpeo,
+    Xsux,
+    Yiii,
+    Zmth,
+    Zsym,
+    Zyyy,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontName {
+    pub font_id: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontDetails {
+    pub generic_family: GenericFamily,
+    pub script: Option<ScriptCode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Font {
+    pub font_id: String,
+    pub level_of_control: LevelOfControl,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetFontDetails {
+    pub generic_family: GenericFamily,
+    pub script: Option<ScriptCode>,
+    pub font_id: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontSize {
+    pub pixel_size: u32,
+    pub level_of_control: LevelOfControl,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SetFontSizeDetails {
+    pixel_size: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontChange {
+    pub font_id: String,
+    pub generic_family: GenericFamily,
+    pub script: Option<ScriptCode>,
+    pub level_of_control: LevelOfControl,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = getFontList)]
+    fn _get_font_list() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = getFont)]
+    fn _get_font(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = setFont)]
+    fn _set_font(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = clearFont)]
+    fn _clear_font(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = getDefaultFontSize)]
+    fn _get_default_font_size() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = setDefaultFontSize)]
+    fn _set_default_font_size(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = clearDefaultFontSize)]
+    fn _clear_default_font_size() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = getDefaultFixedFontSize)]
+    fn _get_default_fixed_font_size() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = setDefaultFixedFontSize)]
+    fn _set_default_fixed_font_size(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = clearDefaultFixedFontSize)]
+    fn _clear_default_fixed_font_size() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = getMinimumFontSize)]
+    fn _get_minimum_font_size() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = setMinimumFontSize)]
+    fn _set_minimum_font_size(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "fontSettings"], js_name = clearMinimumFontSize)]
+    fn _clear_minimum_font_size() -> Promise;
+}
+
+pub async fn get_font_list() -> Result<Vec<FontName>, Error> {
+    resolve(_get_font_list()).await
+}
+
+pub async fn get_font(details: &FontDetails) -> Result<Font, Error> {
+    resolve(_get_font(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn set_font(details: &SetFontDetails) -> Result<(), Error> {
+    resolve(_set_font(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn clear_font(details: &FontDetails) -> Result<(), Error> {
+    resolve(_clear_font(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn get_default_font_size() -> Result<FontSize, Error> {
+    resolve(_get_default_font_size()).await
+}
+
+pub async fn set_default_font_size(pixel_size: u32) -> Result<(), Error> {
+    resolve(_set_default_font_size(serde_wasm_bindgen::to_value(&SetFontSizeDetails { pixel_size })?)).await
+}
+
+pub async fn clear_default_font_size() -> Result<(), Error> {
+    resolve(_clear_default_font_size()).await
+}
+
+pub async fn get_default_fixed_font_size() -> Result<FontSize, Error> {
+    resolve(_get_default_fixed_font_size()).await
+}
+
+pub async fn set_default_fixed_font_size(pixel_size: u32) -> Result<(), Error> {
+    resolve(_set_default_fixed_font_size(serde_wasm_bindgen::to_value(&SetFontSizeDetails { pixel_size })?)).await
+}
+
+pub async fn clear_default_fixed_font_size() -> Result<(), Error> {
+    resolve(_clear_default_fixed_font_size()).await
+}
+
+pub async fn get_minimum_font_size() -> Result<FontSize, Error> {
+    resolve(_get_minimum_font_size()).await
+}
+
+pub async fn set_minimum_font_size(pixel_size: u32) -> Result<(), Error> {
+    resolve(_set_minimum_font_size(serde_wasm_bindgen::to_value(&SetFontSizeDetails { pixel_size })?)).await
+}
+
+pub async fn clear_minimum_font_size() -> Result<(), Error> {
+    resolve(_clear_minimum_font_size()).await
+}
+
+pub mod on_font_changed {
+    use wasm_bindgen::prelude::*;
+    use super::FontChange;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fontSettings", "onFontChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fontSettings"], js_name = onFontChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(FontChange) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(FontChange) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<FontChange> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_default_font_size_changed {
+    use wasm_bindgen::prelude::*;
+    use super::FontSize;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fontSettings", "onDefaultFontSizeChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fontSettings"], js_name = onDefaultFontSizeChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(FontSize) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(FontSize) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<FontSize> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_default_fixed_font_size_changed {
+    use wasm_bindgen::prelude::*;
+    use super::FontSize;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fontSettings", "onDefaultFixedFontSizeChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fontSettings"], js_name = onDefaultFixedFontSizeChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(FontSize) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(FontSize) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<FontSize> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_minimum_font_size_changed {
+    use wasm_bindgen::prelude::*;
+    use super::FontSize;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "fontSettings", "onMinimumFontSizeChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "fontSettings"], js_name = onMinimumFontSizeChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(FontSize) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(FontSize) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<FontSize> {
+        stream_of(listen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GenericFamily;
+
+    #[test]
+    fn generic_family_wire_strings() {
+        let families = [
+            (GenericFamily::Standard, "standard"),
+            (GenericFamily::SansSerif, "sansserif"),
+            (GenericFamily::Serif, "serif"),
+            (GenericFamily::Fixed, "fixed"),
+            (GenericFamily::Cursive, "cursive"),
+            (GenericFamily::Fantasy, "fantasy"),
+            (GenericFamily::Math, "math"),
+        ];
+
+        for (family, wire) in families {
+            let json = format!("\"{}\"", wire);
+
+            assert_eq!(serde_json::to_string(&family).unwrap(), json);
+            assert_eq!(serde_json::from_str::<GenericFamily>(&json).unwrap(), family);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Timeouts and cancellation for the crate's async functions.
+//!
+//! Some callbacks never fire, e.g. messaging a tab whose content script is gone. Both combinators stop
+//! waiting when they give up, and drop the Rust future. A callback already handed to the browser stays
+//! with it until the browser calls it, and is freed then, so one that is never called is never freed.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use js_sys::{Function, Promise};
+use crate::error::Error;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &Function, timeout: f64) -> JsValue;
+
+    #[wasm_bindgen(js_name = clearTimeout)]
+    fn clear_timeout(handle: &JsValue);
+}
+
+struct Timer {
+    fired: JsFuture,
+    handle: JsValue,
+}
+
+impl Timer {
+    fn new(duration: Duration) -> Self {
+        let mut handle = JsValue::UNDEFINED;
+        let promise = Promise::new(&mut |resolve, _reject| {
+            handle = set_timeout(&resolve, duration.as_millis() as f64);
+        });
+
+        Self {
+            fired: JsFuture::from(promise),
+            handle,
+        }
+    }
+}
+
+impl Future for Timer {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        Pin::new(&mut self.fired).poll(cx).map(|_| ())
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        clear_timeout(&self.handle);
+    }
+}
+
+#[derive(Default)]
+struct CancellationState {
+    cancelled: bool,
+    next_id: usize,
+    wakers: HashMap<usize, Waker>,
+}
+
+/// Cancels every call wrapped with it, from anywhere that holds a clone.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    state: Rc<RefCell<CancellationState>>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        let wakers = {
+            let mut state = self.state.borrow_mut();
+            state.cancelled = true;
+            std::mem::take(&mut state.wakers)
+        };
+
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.state.borrow().cancelled
+    }
+
+    fn register(&self) -> Registration<'_> {
+        let mut state = self.state.borrow_mut();
+        let id = state.next_id;
+        state.next_id += 1;
+
+        Registration { token: self, id }
+    }
+}
+
+/// A waiting call's single slot in its token's wakers, freed when the call is dropped.
+struct Registration<'a> {
+    token: &'a CancellationToken,
+    id: usize,
+}
+
+impl Registration<'_> {
+    fn poll_cancelled(&self, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.token.state.borrow_mut();
+
+        if state.cancelled {
+            return Poll::Ready(());
+        }
+
+        match state.wakers.entry(self.id) {
+            Entry::Occupied(mut entry) => {
+                if !entry.get().will_wake(cx.waker()) {
+                    entry.insert(cx.waker().clone());
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(cx.waker().clone());
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for Registration<'_> {
+    fn drop(&mut self) {
+        self.token.state.borrow_mut().wakers.remove(&self.id);
+    }
+}
+
+/// Resolves once `duration` has passed.
+pub async fn sleep(duration: Duration) {
+    Timer::new(duration).await
+}
+
+/// Resolves to `Err(Error::TimedOut)` if `future` takes longer than `duration`.
+pub async fn with_timeout<T, F>(duration: Duration, future: F) -> Result<T, Error>
+    where F: Future<Output = Result<T, Error>>,
+{
+    let mut future = Box::pin(future);
+    let mut timer = Timer::new(duration);
+
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(result) = future.as_mut().poll(cx) {
+            return Poll::Ready(result);
+        }
+
+        Pin::new(&mut timer).poll(cx).map(|_| Err(Error::TimedOut))
+    }).await
+}
+
+/// Resolves to `Err(Error::Cancelled)` as soon as `token` is cancelled.
+pub async fn with_cancellation<T, F>(token: &CancellationToken, future: F) -> Result<T, Error>
+    where F: Future<Output = Result<T, Error>>,
+{
+    let mut future = Box::pin(future);
+    let registration = token.register();
+
+    std::future::poll_fn(|cx| {
+        if token.is_cancelled() {
+            return Poll::Ready(Err(Error::Cancelled));
+        }
+
+        if let Poll::Ready(result) = future.as_mut().poll(cx) {
+            return Poll::Ready(result);
+        }
+
+        registration.poll_cancelled(cx).map(|_| Err(Error::Cancelled))
+    }).await
+}
+
+/// Method forms of [`with_timeout`] and [`with_cancellation`].
+pub trait TimeoutExt<T>: Future<Output = Result<T, Error>> + Sized {
+    fn with_timeout(self, duration: Duration) -> impl Future<Output = Result<T, Error>> {
+        with_timeout(duration, self)
+    }
+
+    fn with_cancellation(self, token: &CancellationToken) -> impl Future<Output = Result<T, Error>> {
+        with_cancellation(token, self)
+    }
+}
+
+impl<T, F: Future<Output = Result<T, Error>>> TimeoutExt<T> for F {}
+
+#[cfg(test)]
+mod tests {
+    use std::task::{Context, Poll, Waker};
+    use super::CancellationToken;
+
+    #[test]
+    fn registration_keeps_one_waker_until_dropped() {
+        let token = CancellationToken::new();
+        let mut cx = Context::from_waker(Waker::noop());
+        let registration = token.register();
+
+        for _ in 0..3 {
+            assert_eq!(registration.poll_cancelled(&mut cx), Poll::Pending);
+        }
+        assert_eq!(token.state.borrow().wakers.len(), 1);
+
+        drop(registration);
+        assert!(token.state.borrow().wakers.is_empty());
+    }
+
+    #[test]
+    fn cancel_wakes_and_readies_registrations() {
+        let token = CancellationToken::new();
+        let mut cx = Context::from_waker(Waker::noop());
+        let registration = token.register();
+
+        assert_eq!(registration.poll_cancelled(&mut cx), Poll::Pending);
+        token.cancel();
+
+        assert!(token.state.borrow().wakers.is_empty());
+        assert_eq!(registration.poll_cancelled(&mut cx), Poll::Ready(()));
+    }
+}
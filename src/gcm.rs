@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use serde_wasm_bindgen::Serializer;
+use crate::utils::{map_to_js_value, resolve};
+use crate::error::Error;
+
+pub const MAX_MESSAGE_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutgoingMessage {
+    pub destination_id: String,
+    pub message_id: String,
+    pub time_to_live: Option<u32>,
+    pub data: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomingMessage {
+    pub data: HashMap<String, String>,
+    pub from: Option<String>,
+    pub collapse_key: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendError {
+    pub error_message: String,
+    pub message_id: Option<String>,
+    #[serde(default)]
+    pub details: HashMap<String, String>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "gcm"], js_name = register)]
+    fn _register(sender_ids: Vec<JsValue>) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "gcm"], js_name = unregister)]
+    fn _unregister() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "gcm"], js_name = send)]
+    fn _send(message: JsValue) -> Promise;
+}
+
+pub async fn register(sender_ids: Vec<String>) -> Result<String, Error> {
+    resolve(_register(map_to_js_value(sender_ids))).await
+}
+
+pub async fn unregister() -> Result<(), Error> {
+    resolve(_unregister()).await
+}
+
+pub async fn send(message: &OutgoingMessage) -> Result<String, Error> {
+    let message = message.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+
+    resolve(_send(message)).await
+}
+
+pub mod on_message {
+    use wasm_bindgen::prelude::*;
+    use super::IncomingMessage;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "gcm", "onMessage"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "gcm"], js_name = onMessage)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(IncomingMessage) + 'static,
+    {
+        Closure::wrap(Box::new(move |message: JsValue| {
+            callback(event_arg!(message));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(IncomingMessage) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<IncomingMessage> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_messages_deleted {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "gcm", "onMessagesDeleted"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "gcm"], js_name = onMessagesDeleted)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
+
+pub mod on_send_error {
+    use wasm_bindgen::prelude::*;
+    use super::SendError;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "gcm", "onSendError"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "gcm"], js_name = onSendError)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(SendError) + 'static,
+    {
+        Closure::wrap(Box::new(move |error: JsValue| {
+            callback(event_arg!(error));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(SendError) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<SendError> {
+        stream_of(listen)
+    }
+}
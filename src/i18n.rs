@@ -0,0 +1,48 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::{map_to_js_value, resolve};
+use crate::error::Error;
+
+pub use web_extension_sys_macros::define_messages;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectedLanguage {
+    pub language: String,
+    pub percentage: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageDetectionResult {
+    pub is_reliable: bool,
+    pub languages: Vec<DetectedLanguage>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "i18n"], js_name = getMessage)]
+    fn _get_message(message_name: &str, substitutions: Vec<JsValue>) -> String;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "i18n"], js_name = getUILanguage)]
+    pub fn get_ui_language() -> String;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "i18n"], js_name = getAcceptLanguages)]
+    fn _get_accept_languages() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "i18n"], js_name = detectLanguage)]
+    fn _detect_language(text: &str) -> Promise;
+}
+
+pub fn get_message(message_name: &str, substitutions: &[&str]) -> String {
+    _get_message(message_name, map_to_js_value(substitutions.to_vec()))
+}
+
+pub async fn get_accept_languages() -> Result<Vec<String>, Error> {
+    resolve(_get_accept_languages()).await
+}
+
+pub async fn detect_language(text: &str) -> Result<LanguageDetectionResult, Error> {
+    resolve(_detect_language(text)).await
+}
@@ -0,0 +1,128 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenDetails {
+    pub interactive: Option<bool>,
+    pub scopes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAuthTokenResult {
+    pub token: Option<String>,
+    pub granted_scopes: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+struct InvalidTokenDetails<'a> {
+    token: &'a str,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebAuthFlowDetails {
+    pub url: String,
+    pub interactive: Option<bool>,
+    pub abort_on_load_for_non_interactive: Option<bool>,
+    pub timeout_ms_for_non_interactive: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountStatus {
+    Sync,
+    Any,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileDetails {
+    pub account_status: Option<AccountStatus>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileUserInfo {
+    pub email: String,
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    pub id: String,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "identity"], js_name = getAuthToken)]
+    fn _get_auth_token(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "identity"], js_name = removeCachedAuthToken)]
+    fn _remove_cached_auth_token(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "identity"], js_name = getRedirectURL)]
+    pub fn get_redirect_url(path: Option<&str>) -> String;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "identity"], js_name = launchWebAuthFlow)]
+    fn _launch_web_auth_flow(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "identity"], js_name = getProfileUserInfo)]
+    fn _get_profile_user_info(details: JsValue) -> Promise;
+}
+
+pub async fn get_auth_token(details: &TokenDetails) -> Result<GetAuthTokenResult, Error> {
+    resolve(_get_auth_token(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn remove_cached_auth_token(token: &str) -> Result<(), Error> {
+    let details = InvalidTokenDetails { token };
+
+    resolve(_remove_cached_auth_token(serde_wasm_bindgen::to_value(&details)?)).await
+}
+
+pub async fn launch_web_auth_flow(details: &WebAuthFlowDetails) -> Result<Option<String>, Error> {
+    resolve(_launch_web_auth_flow(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn get_profile_user_info(details: &ProfileDetails) -> Result<ProfileUserInfo, Error> {
+    resolve(_get_profile_user_info(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub mod on_sign_in_changed {
+    use wasm_bindgen::prelude::*;
+    use super::AccountInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "identity", "onSignInChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, bool)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "identity"], js_name = onSignInChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, bool)>
+        where T: FnMut(AccountInfo, bool) + 'static,
+    {
+        Closure::wrap(Box::new(move |account: JsValue, signed_in| {
+            callback(event_arg!(account), signed_in);
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(AccountInfo, bool) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(AccountInfo, bool)> {
+        stream_of(|mut callback| listen(move |account, signed_in| callback((account, signed_in))))
+    }
+}
@@ -0,0 +1,59 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IdleState {
+    Active,
+    Idle,
+    Locked,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "idle"], js_name = queryState)]
+    fn _query_state(detection_interval_in_seconds: u32) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "idle"], js_name = setDetectionInterval)]
+    pub fn set_detection_interval(interval_in_seconds: u32);
+}
+
+pub async fn query_state(detection_interval_in_seconds: u32) -> Result<IdleState, Error> {
+    resolve(_query_state(detection_interval_in_seconds)).await
+}
+
+pub mod on_state_changed {
+    use wasm_bindgen::prelude::*;
+    use super::IdleState;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "idle", "onStateChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "idle"], js_name = onStateChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(IdleState) + 'static,
+    {
+        Closure::wrap(Box::new(move |new_state: JsValue| {
+            callback(event_arg!(new_state));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(IdleState) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<IdleState> {
+        stream_of(listen)
+    }
+}
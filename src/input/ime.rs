@@ -0,0 +1,657 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyboardEventType {
+    Keyup,
+    Keydown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InputContextType {
+    Text,
+    Search,
+    Tel,
+    Url,
+    Email,
+    Number,
+    Password,
+    Null,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoCapitalizeType {
+    Characters,
+    Words,
+    Sentences,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ScreenType {
+    Normal,
+    Login,
+    Lock,
+    SecondaryLogin,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnderlineStyle {
+    Underline,
+    DoubleUnderline,
+    NoUnderline,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WindowPosition {
+    Cursor,
+    Composition,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MouseButton {
+    Left,
+    Middle,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MenuItemStyle {
+    Check,
+    Radio,
+    Separator,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyboardEvent {
+    #[serde(rename = "type")]
+    pub event_type: KeyboardEventType,
+    pub request_id: Option<String>,
+    pub extension_id: Option<String>,
+    pub key: String,
+    pub code: String,
+    pub key_code: Option<u32>,
+    pub alt_key: Option<bool>,
+    pub altgr_key: Option<bool>,
+    pub ctrl_key: Option<bool>,
+    pub shift_key: Option<bool>,
+    pub caps_lock: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputContext {
+    #[serde(rename = "contextID")]
+    pub context_id: i32,
+    #[serde(rename = "type")]
+    pub context_type: InputContextType,
+    pub auto_correct: bool,
+    pub auto_complete: bool,
+    pub auto_capitalize: AutoCapitalizeType,
+    pub spell_check: bool,
+    pub should_do_learning: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MenuItem {
+    pub id: String,
+    pub label: Option<String>,
+    pub style: Option<MenuItemStyle>,
+    pub visible: Option<bool>,
+    pub checked: Option<bool>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Segment {
+    pub start: u32,
+    pub end: u32,
+    pub style: UnderlineStyle,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateUsage {
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateTemplate {
+    pub candidate: String,
+    pub id: i32,
+    pub parent_id: Option<i32>,
+    pub label: Option<String>,
+    pub annotation: Option<String>,
+    pub usage: Option<CandidateUsage>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateWindowProperties {
+    pub visible: Option<bool>,
+    pub cursor_visible: Option<bool>,
+    pub vertical: Option<bool>,
+    pub page_size: Option<u32>,
+    pub auxiliary_text: Option<String>,
+    pub auxiliary_text_visible: Option<bool>,
+    pub window_position: Option<WindowPosition>,
+    pub current_candidate_index: Option<i32>,
+    pub total_candidates: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositionParameters {
+    #[serde(rename = "contextID")]
+    pub context_id: i32,
+    pub text: String,
+    pub selection_start: Option<u32>,
+    pub selection_end: Option<u32>,
+    pub cursor: u32,
+    pub segments: Option<Vec<Segment>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteSurroundingTextParameters {
+    #[serde(rename = "engineID")]
+    pub engine_id: String,
+    #[serde(rename = "contextID")]
+    pub context_id: i32,
+    pub offset: i32,
+    pub length: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SurroundingInfo {
+    pub text: String,
+    pub focus: u32,
+    pub anchor: u32,
+    pub offset: u32,
+}
+
+#[derive(Serialize)]
+struct ContextParameters<'a> {
+    #[serde(rename = "contextID")]
+    context_id: i32,
+    text: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KeyEventsParameters<'a> {
+    #[serde(rename = "contextID")]
+    context_id: i32,
+    key_data: &'a [KeyboardEvent],
+}
+
+#[derive(Serialize)]
+struct CandidatesParameters<'a> {
+    #[serde(rename = "contextID")]
+    context_id: i32,
+    candidates: &'a [CandidateTemplate],
+}
+
+#[derive(Serialize)]
+struct CursorPositionParameters {
+    #[serde(rename = "contextID")]
+    context_id: i32,
+    #[serde(rename = "candidateID")]
+    candidate_id: i32,
+}
+
+#[derive(Serialize)]
+struct CandidateWindowParameters<'a> {
+    #[serde(rename = "engineID")]
+    engine_id: &'a str,
+    properties: &'a CandidateWindowProperties,
+}
+
+#[derive(Serialize)]
+struct MenuParameters<'a> {
+    #[serde(rename = "engineID")]
+    engine_id: &'a str,
+    items: &'a [MenuItem],
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = setComposition)]
+    fn _set_composition(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = clearComposition)]
+    fn _clear_composition(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = commitText)]
+    fn _commit_text(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = sendKeyEvents)]
+    fn _send_key_events(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = setCandidateWindowProperties)]
+    fn _set_candidate_window_properties(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = setCandidates)]
+    fn _set_candidates(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = setCursorPosition)]
+    fn _set_cursor_position(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = setMenuItems)]
+    fn _set_menu_items(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = updateMenuItems)]
+    fn _update_menu_items(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = deleteSurroundingText)]
+    fn _delete_surrounding_text(parameters: JsValue) -> Promise;
+
+    /// Reports whether a key event received with `on_key_event` was handled,
+    /// when the listener chose to answer asynchronously.
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = keyEventHandled)]
+    pub fn key_event_handled(request_id: &str, response: bool);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "input", "ime"], js_name = hideInputView)]
+    pub fn hide_input_view();
+}
+
+pub async fn set_composition(parameters: &CompositionParameters) -> Result<bool, Error> {
+    resolve(_set_composition(serde_wasm_bindgen::to_value(parameters)?)).await
+}
+
+pub async fn clear_composition(context_id: i32) -> Result<bool, Error> {
+    let parameters = ContextParameters { context_id, text: None };
+
+    resolve(_clear_composition(serde_wasm_bindgen::to_value(&parameters)?)).await
+}
+
+pub async fn commit_text(context_id: i32, text: &str) -> Result<bool, Error> {
+    let parameters = ContextParameters { context_id, text: Some(text) };
+
+    resolve(_commit_text(serde_wasm_bindgen::to_value(&parameters)?)).await
+}
+
+pub async fn send_key_events(context_id: i32, key_data: &[KeyboardEvent]) -> Result<(), Error> {
+    let parameters = KeyEventsParameters { context_id, key_data };
+
+    resolve(_send_key_events(serde_wasm_bindgen::to_value(&parameters)?)).await
+}
+
+pub async fn set_candidate_window_properties(
+    engine_id: &str,
+    properties: &CandidateWindowProperties,
+) -> Result<bool, Error> {
+    let parameters = CandidateWindowParameters { engine_id, properties };
+
+    resolve(_set_candidate_window_properties(serde_wasm_bindgen::to_value(&parameters)?)).await
+}
+
+pub async fn set_candidates(context_id: i32, candidates: &[CandidateTemplate]) -> Result<bool, Error> {
+    let parameters = CandidatesParameters { context_id, candidates };
+
+    resolve(_set_candidates(serde_wasm_bindgen::to_value(&parameters)?)).await
+}
+
+pub async fn set_cursor_position(context_id: i32, candidate_id: i32) -> Result<bool, Error> {
+    let parameters = CursorPositionParameters { context_id, candidate_id };
+
+    resolve(_set_cursor_position(serde_wasm_bindgen::to_value(&parameters)?)).await
+}
+
+pub async fn set_menu_items(engine_id: &str, items: &[MenuItem]) -> Result<(), Error> {
+    let parameters = MenuParameters { engine_id, items };
+
+    resolve(_set_menu_items(serde_wasm_bindgen::to_value(&parameters)?)).await
+}
+
+pub async fn update_menu_items(engine_id: &str, items: &[MenuItem]) -> Result<(), Error> {
+    let parameters = MenuParameters { engine_id, items };
+
+    resolve(_update_menu_items(serde_wasm_bindgen::to_value(&parameters)?)).await
+}
+
+pub async fn delete_surrounding_text(parameters: &DeleteSurroundingTextParameters) -> Result<(), Error> {
+    resolve(_delete_surrounding_text(serde_wasm_bindgen::to_value(parameters)?)).await
+}
+
+pub mod on_activate {
+    use wasm_bindgen::prelude::*;
+    use super::ScreenType;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "input", "ime", "onActivate"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "input", "ime"], js_name = onActivate)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, JsValue)>
+        where T: FnMut(String, ScreenType) + 'static,
+    {
+        Closure::wrap(Box::new(move |engine_id, screen: JsValue| {
+            callback(engine_id, event_arg!(screen));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, ScreenType) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, ScreenType)> {
+        stream_of(|mut callback| listen(move |engine_id, screen| callback((engine_id, screen))))
+    }
+}
+
+pub mod on_deactivated {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "input", "ime", "onDeactivated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "input", "ime"], js_name = onDeactivated)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(String)>
+        where T: FnMut(String) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<String> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_focus {
+    use wasm_bindgen::prelude::*;
+    use super::InputContext;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "input", "ime", "onFocus"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "input", "ime"], js_name = onFocus)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(InputContext) + 'static,
+    {
+        Closure::wrap(Box::new(move |context: JsValue| {
+            callback(event_arg!(context));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(InputContext) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<InputContext> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_blur {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "input", "ime", "onBlur"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(i32)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "input", "ime"], js_name = onBlur)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(i32)>
+        where T: FnMut(i32) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(i32) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<i32> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_input_context_update {
+    use wasm_bindgen::prelude::*;
+    use super::InputContext;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "input", "ime", "onInputContextUpdate"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "input", "ime"], js_name = onInputContextUpdate)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(InputContext) + 'static,
+    {
+        Closure::wrap(Box::new(move |context: JsValue| {
+            callback(event_arg!(context));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(InputContext) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<InputContext> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_key_event {
+    use wasm_bindgen::prelude::*;
+    use super::KeyboardEvent;
+    use crate::events::{Event, EventHandle, deserialize_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "input", "ime", "onKeyEvent"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, JsValue, String) -> JsValue>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "input", "ime"], js_name = onKeyEvent)]
+        static EVENT: Event;
+    }
+
+    /// The callback returns whether it handled the key, or `None` to answer
+    /// later through `key_event_handled` with the request ID.
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, JsValue, String) -> JsValue>
+        where T: FnMut(String, KeyboardEvent, String) -> Option<bool> + 'static,
+    {
+        Closure::wrap(Box::new(move |engine_id, key_data: JsValue, request_id| {
+            // Undefined would tell the browser to wait for `key_event_handled`,
+            // so a key this crate can't read is passed through instead.
+            let key_data = match deserialize_arg(key_data) {
+                Some(key_data) => key_data,
+                None => return false.into(),
+            };
+
+            match callback(engine_id, key_data, request_id) {
+                Some(handled) => handled.into(),
+                None => JsValue::UNDEFINED,
+            }
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, KeyboardEvent, String) -> Option<bool> + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+}
+
+pub mod on_candidate_clicked {
+    use wasm_bindgen::prelude::*;
+    use super::MouseButton;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "input", "ime", "onCandidateClicked"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, i32, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "input", "ime"], js_name = onCandidateClicked)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, i32, JsValue)>
+        where T: FnMut(String, i32, MouseButton) + 'static,
+    {
+        Closure::wrap(Box::new(move |engine_id, candidate_id, button: JsValue| {
+            callback(engine_id, candidate_id, event_arg!(button));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, i32, MouseButton) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, i32, MouseButton)> {
+        stream_of(|mut callback| listen(move |engine_id, candidate_id, button| callback((engine_id, candidate_id, button))))
+    }
+}
+
+pub mod on_menu_item_activated {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "input", "ime", "onMenuItemActivated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, String)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "input", "ime"], js_name = onMenuItemActivated)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(String, String)>
+        where T: FnMut(String, String) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, String) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, String)> {
+        stream_of(|mut callback| listen(move |engine_id, surrounding_info| callback((engine_id, surrounding_info))))
+    }
+}
+
+pub mod on_surrounding_text_changed {
+    use wasm_bindgen::prelude::*;
+    use super::SurroundingInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "input", "ime", "onSurroundingTextChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "input", "ime"], js_name = onSurroundingTextChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, JsValue)>
+        where T: FnMut(String, SurroundingInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |engine_id, surrounding_info: JsValue| {
+            callback(engine_id, event_arg!(surrounding_info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, SurroundingInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, SurroundingInfo)> {
+        stream_of(|mut callback| listen(move |engine_id, surrounding_info| callback((engine_id, surrounding_info))))
+    }
+}
+
+pub mod on_reset {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "input", "ime", "onReset"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "input", "ime"], js_name = onReset)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(String)>
+        where T: FnMut(String) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<String> {
+        stream_of(listen)
+    }
+}
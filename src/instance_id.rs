@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::Serialize;
+use serde_wasm_bindgen::Serializer;
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTokenParams {
+    pub authorized_entity: String,
+    pub scope: String,
+    pub options: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteTokenParams {
+    pub authorized_entity: String,
+    pub scope: String,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "instanceID"], js_name = getID)]
+    fn _get_id() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "instanceID"], js_name = getCreationTime)]
+    fn _get_creation_time() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "instanceID"], js_name = getToken)]
+    fn _get_token(get_token_params: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "instanceID"], js_name = deleteToken)]
+    fn _delete_token(delete_token_params: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "instanceID"], js_name = deleteID)]
+    fn _delete_id() -> Promise;
+}
+
+pub async fn get_id() -> Result<String, Error> {
+    resolve(_get_id()).await
+}
+
+pub async fn get_creation_time() -> Result<f64, Error> {
+    resolve(_get_creation_time()).await
+}
+
+pub async fn get_token(params: &GetTokenParams) -> Result<String, Error> {
+    let params = params.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+
+    resolve(_get_token(params)).await
+}
+
+pub async fn delete_token(params: &DeleteTokenParams) -> Result<(), Error> {
+    resolve(_delete_token(serde_wasm_bindgen::to_value(params)?)).await
+}
+
+pub async fn delete_id() -> Result<(), Error> {
+    resolve(_delete_id()).await
+}
+
+pub mod on_token_refresh {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "instanceID", "onTokenRefresh"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "instanceID"], js_name = onTokenRefresh)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
@@ -0,0 +1,155 @@
+//! Keeps a Manifest V3 service worker running past its 30 second idle timeout.
+//!
+//! Hold a `KeepAliveGuard` for as long as the work needs the worker, or wrap
+//! the work in `scoped`. The worker can still be stopped after five minutes of
+//! a single event handler, or by the browser under memory pressure.
+
+use std::cell::Cell;
+use std::future::Future;
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use js_sys::{Function, Object, Promise, Reflect};
+use serde::de::IgnoredAny;
+use crate::events::{Event, EventHandle};
+use crate::namespace::get;
+use crate::runtime::{connect_native, Port};
+use crate::utils::resolve;
+use crate::error::Error;
+
+/// How often `Strategy::Ping` calls an extension API. Each call resets the idle timer.
+pub const PING_INTERVAL: Duration = Duration::from_secs(20);
+
+/// The name of the alarm `Strategy::Alarms` creates.
+pub const ALARM_NAME: &str = "web_extension_sys.keep_alive";
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setInterval)]
+    fn set_interval(handler: &Function, timeout: f64) -> JsValue;
+
+    #[wasm_bindgen(js_name = clearInterval)]
+    fn clear_interval(handle: &JsValue);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "runtime"], js_name = getPlatformInfo)]
+    fn _get_platform_info() -> Promise;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    /// Calls `runtime.getPlatformInfo` every `PING_INTERVAL`. Needs Chrome 110 or later.
+    Ping,
+    /// Holds a `runtime.connectNative` port to the named application open.
+    /// Needs the `nativeMessaging` permission and Chrome 105 or later.
+    NativePort(String),
+    /// A 30 second `chrome.alarms` alarm. This doesn't stop the worker from
+    /// being killed, but wakes it again shortly after. Needs the `alarms` permission.
+    Alarms,
+}
+
+thread_local! {
+    // Guards using `Strategy::Alarms` share one alarm, cleared when the last is dropped.
+    static ALARM_GUARDS: Cell<usize> = const { Cell::new(0) };
+}
+
+enum Mechanism {
+    Ping {
+        handle: JsValue,
+        _callback: Closure<dyn FnMut()>,
+    },
+    NativePort(Port),
+    Alarms {
+        _listener: EventHandle,
+    },
+}
+
+/// Keeps the worker alive until dropped.
+#[must_use = "the worker is only kept alive while the guard is held"]
+pub struct KeepAliveGuard {
+    mechanism: Mechanism,
+}
+
+impl KeepAliveGuard {
+    /// Uses `Strategy::Ping` in a service worker, and `Strategy::Alarms` in
+    /// an event page, whose idle timer API calls don't reset.
+    pub fn new() -> Result<Self, Error> {
+        if is_service_worker() {
+            Self::with_strategy(Strategy::Ping)
+        } else {
+            Self::with_strategy(Strategy::Alarms)
+        }
+    }
+
+    pub fn with_strategy(strategy: Strategy) -> Result<Self, Error> {
+        let mechanism = match strategy {
+            Strategy::Ping => {
+                let callback = Closure::wrap(Box::new(|| {
+                    // Awaited so that a rejection, e.g. once the context is invalidated, is handled.
+                    spawn_local(async {
+                        let _ = resolve::<IgnoredAny>(_get_platform_info()).await;
+                    });
+                }) as Box<dyn FnMut()>);
+                let handle = set_interval(callback.as_ref().unchecked_ref(), PING_INTERVAL.as_millis() as f64);
+
+                Mechanism::Ping { handle, _callback: callback }
+            }
+            Strategy::NativePort(application) => Mechanism::NativePort(connect_native(&application)),
+            Strategy::Alarms => {
+                let alarms = get("alarms").ok_or_else(|| Error::Unsupported("alarms".to_owned()))?;
+
+                let alarm_info = Object::new();
+                Reflect::set(&alarm_info, &"periodInMinutes".into(), &0.5.into())?;
+                let create: Function = Reflect::get(&alarms, &"create".into())?.dyn_into()?;
+                // The alarm only wakes the worker if something listens for it.
+                let on_alarm: Event = Reflect::get(&alarms, &"onAlarm".into())?.unchecked_into();
+                let listener = Closure::wrap(Box::new(|_alarm: JsValue| {}) as Box<dyn FnMut(JsValue)>);
+
+                // Counted only once the alarm exists, and nothing after this can
+                // fail, so every count is matched by a guard that clears it.
+                create.call2(&alarms, &ALARM_NAME.into(), &alarm_info)?;
+                ALARM_GUARDS.with(|guards| guards.set(guards.get() + 1));
+
+                Mechanism::Alarms { _listener: EventHandle::new(on_alarm, listener) }
+            }
+        };
+
+        Ok(Self { mechanism })
+    }
+}
+
+impl Drop for KeepAliveGuard {
+    fn drop(&mut self) {
+        match &self.mechanism {
+            Mechanism::Ping { handle, .. } => clear_interval(handle),
+            Mechanism::NativePort(port) => port.disconnect(),
+            Mechanism::Alarms { .. } => {
+                let remaining = ALARM_GUARDS.with(|guards| {
+                    guards.set(guards.get() - 1);
+                    guards.get()
+                });
+
+                if remaining > 0 {
+                    return;
+                }
+
+                if let Some(alarms) = get("alarms") {
+                    if let Ok(clear) = Reflect::get(&alarms, &"clear".into()).and_then(|clear| clear.dyn_into::<Function>()) {
+                        let _ = clear.call1(&alarms, &ALARM_NAME.into());
+                    }
+                }
+            }
+        }
+    }
+}
+
+// `ServiceWorkerGlobalScope` is only exposed inside service workers.
+fn is_service_worker() -> bool {
+    Reflect::has(&js_sys::global(), &"ServiceWorkerGlobalScope".into()).unwrap_or(false)
+}
+
+/// Keeps the worker alive until `future` completes.
+pub async fn scoped<F: Future>(future: F) -> Result<F::Output, Error> {
+    let _guard = KeepAliveGuard::new()?;
+
+    Ok(future.await)
+}
@@ -1,8 +1,13 @@
 mod utils {
     use wasm_bindgen::prelude::*;
-    use js_sys::{Object, Reflect};
+    use wasm_bindgen_futures::JsFuture;
+    use js_sys::Promise;
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+    use serde_wasm_bindgen::Serializer;
     use crate::error::Error;
 
+    #[cfg(any(feature = "storage", feature = "i18n", feature = "gcm"))]
     pub fn map_to_js_value<T: Into<JsValue>>(vec: Vec<T>) -> Vec<JsValue> {
         vec
             .into_iter()
@@ -10,141 +15,264 @@ mod utils {
             .collect()
     }
 
+    #[cfg(any(all(feature = "storage", not(feature = "safari")), feature = "printing"))]
     pub fn create_object_with_property<T: Into<JsValue>>(
         key: String,
         value: T,
-    ) -> Result<Object, Error> {
-        let data = Object::new();
-        Reflect::set(&data, &key.into(), &value.into())?;
+    ) -> Result<js_sys::Object, Error> {
+        let data = js_sys::Object::new();
+        js_sys::Reflect::set(&data, &key.into(), &value.into())?;
 
         Ok(data)
     }
+
+    /// For values the browser JSON-serializes, such as messages, where a JS `Map` would arrive as `{}`.
+    pub fn to_json_value<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, Error> {
+        Ok(value.serialize(&Serializer::new().serialize_maps_as_objects(true))?)
+    }
+
+    #[cfg(any(feature = "certificate_provider", feature = "file_system_provider", feature = "enterprise", feature = "platform_keys", feature = "vpn_provider"))]
+    pub fn to_array_buffer(bytes: &[u8]) -> js_sys::ArrayBuffer {
+        js_sys::Uint8Array::from(bytes).buffer()
+    }
+
+    #[cfg(any(feature = "enterprise", feature = "platform_keys", feature = "vpn_provider"))]
+    pub fn from_array_buffer(buffer: &JsValue) -> Vec<u8> {
+        js_sys::Uint8Array::new(buffer).to_vec()
+    }
+
+    #[cfg(any(feature = "certificate_provider", feature = "file_system_provider", feature = "document_scan"))]
+    /// For `deserialize_with` on fields Chrome hands over as an `ArrayBuffer`.
+    pub fn deserialize_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        use std::fmt;
+        use serde::de::{self, Visitor};
+
+        struct BytesVisitor;
+
+        impl<'de> Visitor<'de> for BytesVisitor {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an ArrayBuffer")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(v.to_vec())
+            }
+
+            fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(v)
+            }
+        }
+
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+
+    #[cfg(feature = "document_scan")]
+    pub fn deserialize_optional_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error> {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        struct Bytes(#[serde(deserialize_with = "deserialize_bytes")] Vec<u8>);
+
+        Ok(Option::<Bytes>::deserialize(deserializer)?.map(|bytes| bytes.0))
+    }
+
+    #[cfg(any(feature = "enterprise", feature = "platform_keys", feature = "desktop_capture", feature = "tab_capture", feature = "declarative_content", all(feature = "devtools", not(feature = "safari"))))]
+    pub fn promise_from_callback<F: FnOnce(js_sys::Function)>(call: F) -> Promise {
+        let mut call = Some(call);
+
+        Promise::new(&mut |resolve, _reject| {
+            if let Some(call) = call.take() {
+                call(resolve);
+            }
+        })
+    }
+
+    pub async fn resolve<T: DeserializeOwned>(promise: Promise) -> Result<T, Error> {
+        let value = JsFuture::from(promise).await?;
+
+        Ok(serde_wasm_bindgen::from_value(value)?)
+    }
 }
 
+#[cfg(feature = "storage")]
 pub mod storage {
     use wasm_bindgen::closure::Closure;
     use wasm_bindgen::JsValue;
     use js_sys::Reflect;
 
-    pub mod local {
-        use wasm_bindgen::prelude::*;
-        use crate::utils::{map_to_js_value, create_object_with_property};
-        use serde_wasm_bindgen;
-        use crate::error::Error;
-        use serde::Serialize;
+    macro_rules! define_storage_area {
+        ($name:ident, [$($namespace:tt),*]) => {
+            pub mod $name {
+                use wasm_bindgen::prelude::*;
+                use js_sys::Promise;
+                use crate::utils::{map_to_js_value, resolve};
+                #[cfg(not(feature = "safari"))]
+                use crate::utils::create_object_with_property;
+                use serde_wasm_bindgen;
+                use serde_wasm_bindgen::Serializer;
+                use crate::error::Error;
+                use serde::Serialize;
+                use serde::de::DeserializeOwned;
+                use std::collections::HashMap;
+
+                // Safari rejects the callback form, so only the promise form is offered there.
+                #[cfg(not(feature = "safari"))]
+                #[wasm_bindgen]
+                extern "C" {
+                    #[wasm_bindgen(js_namespace = [$($namespace),*], js_name = get)]
+                    pub fn get_one(key: &str, callback: &Closure<dyn FnMut(JsValue)>);
+
+                    #[wasm_bindgen(js_namespace = [$($namespace),*], js_name = get)]
+                    fn _get_multiple(keys: Vec<JsValue>, callback: &Closure<dyn FnMut(JsValue)>);
+
+                    #[wasm_bindgen(js_namespace = [$($namespace),*], js_name = set)]
+                    fn _set(data: JsValue);
+
+                    #[wasm_bindgen(js_namespace = [$($namespace),*], js_name = set)]
+                    fn _set_and_then(data: JsValue, callback: &Closure<dyn FnMut()>);
+                }
 
-        #[wasm_bindgen]
-        extern "C" {
-            #[wasm_bindgen(js_namespace = ["chrome", "storage", "local"], js_name = get)]
-            pub fn get_one(key: &str, callback: &Closure<dyn FnMut(JsValue)>);
+                #[wasm_bindgen]
+                extern "C" {
+                    #[wasm_bindgen(js_namespace = [$($namespace),*], js_name = get)]
+                    fn _get(keys: Vec<JsValue>) -> Promise;
 
-            #[wasm_bindgen(js_namespace = ["chrome", "storage", "local"], js_name = get)]
-            fn _get_multiple(keys: Vec<JsValue>, callback: &Closure<dyn FnMut(JsValue)>);
+                    #[wasm_bindgen(js_namespace = [$($namespace),*], js_name = get)]
+                    fn _get_all() -> Promise;
 
-            #[wasm_bindgen(js_namespace = ["chrome", "storage", "local"], js_name = set)]
-            fn _set(data: JsValue);
+                    #[wasm_bindgen(js_namespace = [$($namespace),*], js_name = set)]
+                    fn _set_promise(data: JsValue) -> Promise;
 
-            #[wasm_bindgen(js_namespace = ["chrome", "storage", "local"], js_name = set)]
-            fn _set_and_then(data: JsValue, callback: &Closure<dyn FnMut()>);
-        }
+                    #[wasm_bindgen(js_namespace = [$($namespace),*], js_name = remove)]
+                    fn _remove(keys: Vec<JsValue>) -> Promise;
 
-        pub fn get_multiple(keys: Vec<String>, callback: &Closure<dyn FnMut(JsValue)>) {
-            let keys = map_to_js_value(keys);
+                    #[wasm_bindgen(js_namespace = [$($namespace),*], js_name = clear)]
+                    fn _clear() -> Promise;
 
-            _get_multiple(keys, callback)
-        }
+                    #[wasm_bindgen(js_namespace = [$($namespace),*], js_name = getBytesInUse)]
+                    fn _get_bytes_in_use(keys: Option<Vec<JsValue>>) -> Promise;
+                }
 
-        fn _set_optional_callback(data: JsValue, callback: Option<&Closure<dyn FnMut()>>) {
-            match callback {
-                None => {
-                    _set(data);
+                #[cfg(not(feature = "safari"))]
+                pub fn get_multiple(keys: Vec<String>, callback: &Closure<dyn FnMut(JsValue)>) {
+                    let keys = map_to_js_value(keys);
+
+                    _get_multiple(keys, callback)
                 }
-                Some(c) => {
-                    _set_and_then(data, c);
+
+                #[cfg(not(feature = "safari"))]
+                fn _set_optional_callback(data: JsValue, callback: Option<&Closure<dyn FnMut()>>) {
+                    match callback {
+                        None => {
+                            _set(data);
+                        }
+                        Some(c) => {
+                            _set_and_then(data, c);
+                        }
+                    }
                 }
-            }
-        }
 
-        pub fn set_one<T: Into<JsValue>>(
-            key: String,
-            value: T,
-            callback: Option<&Closure<dyn FnMut()>>
-        ) -> Result<(), Error> {
-            let data = create_object_with_property(key, value)?;
+                #[cfg(not(feature = "safari"))]
+                pub fn set_one<T: Into<JsValue>>(
+                    key: String,
+                    value: T,
+                    callback: Option<&Closure<dyn FnMut()>>
+                ) -> Result<(), Error> {
+                    let data = create_object_with_property(key, value)?;
 
-            _set_optional_callback(data.into(), callback);
+                    _set_optional_callback(data.into(), callback);
 
-            Ok(())
-        }
+                    Ok(())
+                }
 
-        pub fn set_multiple<T: Serialize>(
-            data: T,
-            callback: Option<&Closure<dyn FnMut()>>
-        ) -> Result<(), Error> {
-            _set_optional_callback(serde_wasm_bindgen::to_value(&data)?, callback);
+                #[cfg(not(feature = "safari"))]
+                pub fn set_multiple<T: Serialize>(
+                    data: T,
+                    callback: Option<&Closure<dyn FnMut()>>
+                ) -> Result<(), Error> {
+                    _set_optional_callback(serde_wasm_bindgen::to_value(&data)?, callback);
 
-            Ok(())
-        }
-    }
+                    Ok(())
+                }
 
-    pub mod sync {
-        use wasm_bindgen::prelude::*;
-        use crate::utils::{map_to_js_value, create_object_with_property};
-        use serde_wasm_bindgen;
-        use crate::error::Error;
-        use serde::Serialize;
+                /// Like `get`, but for a single key, with `Error::NoSuchKey` if it isn't set.
+                pub async fn get_key<T: DeserializeOwned>(key: &str) -> Result<T, Error> {
+                    let mut items: HashMap<String, T> = get(vec![key.to_owned()]).await?;
 
-        #[wasm_bindgen]
-        extern "C" {
-            #[wasm_bindgen(js_namespace = ["chrome", "storage", "sync"], js_name = get)]
-            pub fn get_one(key: &str, callback: &Closure<dyn FnMut(JsValue)>);
+                    items.remove(key).ok_or_else(|| Error::NoSuchKey(key.to_owned()))
+                }
 
-            #[wasm_bindgen(js_namespace = ["chrome", "storage", "sync"], js_name = get)]
-            fn _get_multiple(keys: Vec<JsValue>, callback: &Closure<dyn FnMut(JsValue)>);
+                /// Missing keys are absent from the result, so `T` should use `Option` fields or a map.
+                pub async fn get<T: DeserializeOwned>(keys: Vec<String>) -> Result<T, Error> {
+                    resolve(_get(map_to_js_value(keys))).await
+                }
 
-            #[wasm_bindgen(js_namespace = ["chrome", "storage", "sync"], js_name = set)]
-            fn _set(data: JsValue);
+                pub async fn get_all<T: DeserializeOwned>() -> Result<T, Error> {
+                    resolve(_get_all()).await
+                }
 
-            #[wasm_bindgen(js_namespace = ["chrome", "storage", "sync"], js_name = set)]
-            fn _set_and_then(data: JsValue, callback: &Closure<dyn FnMut()>);
-        }
+                pub async fn set<T: Serialize>(data: &T) -> Result<(), Error> {
+                    let data = data.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
 
-        pub fn get_multiple(keys: Vec<String>, callback: &Closure<dyn FnMut(JsValue)>) {
-            let keys = map_to_js_value(keys);
+                    resolve(_set_promise(data)).await
+                }
 
-            _get_multiple(keys, callback)
-        }
+                pub async fn remove(keys: Vec<String>) -> Result<(), Error> {
+                    resolve(_remove(map_to_js_value(keys))).await
+                }
 
-        fn _set_optional_callback(data: JsValue, callback: Option<&Closure<dyn FnMut()>>) {
-            match callback {
-                None => {
-                    _set(data);
+                pub async fn clear() -> Result<(), Error> {
+                    resolve(_clear()).await
                 }
-                Some(c) => {
-                    _set_and_then(data, c);
+
+                /// Pass `None` to get the total for the whole storage area.
+                pub async fn get_bytes_in_use(keys: Option<Vec<String>>) -> Result<f64, Error> {
+                    resolve(_get_bytes_in_use(keys.map(map_to_js_value))).await
                 }
             }
-        }
+        };
+    }
 
-        pub fn set_one<T: Into<JsValue>>(
-            key: String,
-            value: T,
-            callback: Option<&Closure<dyn FnMut()>>
-        ) -> Result<(), Error> {
-            let data = create_object_with_property(key, value)?;
+    define_storage_area!(local, ["chrome", "storage", "local"]);
 
-            _set_optional_callback(data.into(), callback);
+    define_storage_area!(sync, ["chrome", "storage", "sync"]);
 
-            Ok(())
-        }
+    define_storage_area!(session, ["chrome", "storage", "session"]);
 
-        pub fn set_multiple<T: Serialize>(
-            data: T,
-            callback: Option<&Closure<dyn FnMut()>>
-        ) -> Result<(), Error> {
-            _set_optional_callback(serde_wasm_bindgen::to_value(&data)?, callback);
+    /// The storage area a `storage.onChanged` event is about.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum AreaName {
+        Local,
+        Sync,
+        Session,
+        Managed,
+        /// An area added after this crate was written.
+        Unknown(String),
+    }
+
+    impl AreaName {
+        pub fn as_str(&self) -> &str {
+            match self {
+                AreaName::Local => "local",
+                AreaName::Sync => "sync",
+                AreaName::Session => "session",
+                AreaName::Managed => "managed",
+                AreaName::Unknown(name) => name,
+            }
+        }
+    }
 
-            Ok(())
+    impl From<&str> for AreaName {
+        fn from(name: &str) -> Self {
+            match name {
+                "local" => AreaName::Local,
+                "sync" => AreaName::Sync,
+                "session" => AreaName::Session,
+                "managed" => AreaName::Managed,
+                _ => AreaName::Unknown(name.to_owned()),
+            }
         }
     }
 
@@ -152,6 +280,8 @@ pub mod storage {
         use wasm_bindgen::prelude::*;
         use std::collections::HashMap;
         use js_sys::Object;
+        use crate::events::{Event, EventHandle, EventStream, stream_of};
+        use super::AreaName;
 
         #[wasm_bindgen]
         extern "C" {
@@ -166,20 +296,33 @@ pub mod storage {
 
             #[wasm_bindgen(js_namespace = ["chrome", "storage", "onChanged"], js_name = addListener)]
             pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, String)>);
+
+            #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "storage"], js_name = onChanged)]
+            static EVENT: Event;
         }
 
         pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, String)>
-            where T: FnMut(HashMap<String, StorageChange>, String) + 'static,
+            where T: FnMut(HashMap<String, StorageChange>, AreaName) + 'static,
         {
-            Closure::wrap(Box::new(move |changes, namespace| {
+            Closure::wrap(Box::new(move |changes: JsValue, area_name: String| {
                 let changes: Object = changes.into();
                 let keys = Object::keys(&changes).to_vec().into_iter().map(|v| v.as_string().unwrap());
-                let values = Object::values(&changes).to_vec().into_iter().map(|v| StorageChange::from(v));
+                let values = Object::values(&changes).to_vec().into_iter().map(StorageChange::from);
                 let changes: HashMap<String, StorageChange> = keys.zip(values).collect();
 
-                callback(changes, namespace);
+                callback(changes, AreaName::from(area_name.as_str()));
             }))
         }
+
+        pub fn listen<T>(callback: T) -> EventHandle
+            where T: FnMut(HashMap<String, StorageChange>, AreaName) + 'static,
+        {
+            EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+        }
+
+        pub fn stream() -> EventStream<(HashMap<String, StorageChange>, AreaName)> {
+            stream_of(|mut callback| listen(move |changes, area_name| callback((changes, area_name))))
+        }
     }
 
     pub fn create_get_one_closure<T>(mut callback: T, key: &str) -> Closure<dyn FnMut(JsValue)>
@@ -206,15 +349,347 @@ pub mod storage {
     }
 }
 
+#[cfg(feature = "permissions")]
+pub mod permissions;
+
+pub mod match_pattern;
+
+#[cfg(feature = "identity")]
+pub mod identity;
+
+#[cfg(feature = "tabs")]
+pub mod tabs;
+
+#[cfg(feature = "commands")]
+pub mod commands;
+
+#[cfg(feature = "omnibox")]
+pub mod omnibox;
+
+#[cfg(feature = "idle")]
+pub mod idle;
+
+#[cfg(feature = "i18n")]
+pub mod i18n;
+
+#[cfg(feature = "management")]
+pub mod management;
+
+#[cfg(feature = "windows")]
+pub mod windows;
+
+#[cfg(feature = "sessions")]
+pub mod sessions;
+
+#[cfg(feature = "top_sites")]
+pub mod top_sites;
+
+#[cfg(feature = "tts")]
+pub mod tts;
+
+#[cfg(feature = "tts_engine")]
+pub mod tts_engine;
+
+pub mod events;
+
+#[cfg(feature = "web_navigation")]
+pub mod web_navigation;
+
+pub mod types;
+
+#[cfg(feature = "proxy")]
+pub mod proxy;
+
+#[cfg(feature = "privacy")]
+pub mod privacy;
+
+#[cfg(feature = "browsing_data")]
+pub mod browsing_data;
+
+#[cfg(feature = "content_settings")]
+pub mod content_settings;
+
+#[cfg(feature = "devtools")]
+pub mod devtools;
+
+#[cfg(feature = "debugger")]
+pub mod debugger;
+
+#[cfg(feature = "extension")]
+pub mod extension;
+
+#[cfg(feature = "side_panel")]
+pub mod side_panel;
+
+#[cfg(feature = "offscreen")]
+pub mod offscreen;
+
+#[cfg(feature = "user_scripts")]
+pub mod user_scripts;
+
+#[cfg(feature = "runtime")]
+pub mod runtime;
+
+#[cfg(feature = "search")]
+pub mod search;
+
+#[cfg(feature = "desktop_capture")]
+pub mod desktop_capture;
+
+#[cfg(feature = "tab_capture")]
+pub mod tab_capture;
+
+#[cfg(feature = "dns")]
+pub mod dns;
+
+#[cfg(feature = "gcm")]
+pub mod gcm;
+
+#[cfg(feature = "instance_id")]
+pub mod instance_id;
+
+#[cfg(feature = "power")]
+pub mod power;
+
+#[cfg(feature = "printing")]
+pub mod printing;
+
+#[cfg(feature = "printing_metrics")]
+pub mod printing_metrics;
+
+#[cfg(feature = "printer_provider")]
+pub mod printer_provider;
+
+#[cfg(feature = "system")]
+pub mod system;
+
+#[cfg(feature = "page_capture")]
+pub mod page_capture;
+
+#[cfg(feature = "processes")]
+pub mod processes;
+
+#[cfg(feature = "reading_list")]
+pub mod reading_list;
+
+#[cfg(feature = "declarative_content")]
+pub mod declarative_content;
+
+#[cfg(feature = "font_settings")]
+pub mod font_settings;
+
+#[cfg(feature = "wallpaper")]
+pub mod wallpaper;
+
+#[cfg(feature = "accessibility_features")]
+pub mod accessibility_features;
+
+#[cfg(feature = "login_state")]
+pub mod login_state;
+
+#[cfg(feature = "enterprise")]
+pub mod enterprise;
+
+#[cfg(feature = "certificate_provider")]
+pub mod certificate_provider;
+
+#[cfg(feature = "platform_keys")]
+pub mod platform_keys;
+
+#[cfg(feature = "file_system_provider")]
+pub mod file_system_provider;
+
+#[cfg(feature = "file_browser_handler")]
+pub mod file_browser_handler;
+
+#[cfg(feature = "input")]
+pub mod input;
+
+#[cfg(feature = "vpn_provider")]
+pub mod vpn_provider;
+
+#[cfg(feature = "audio")]
+pub mod audio;
+
+#[cfg(feature = "document_scan")]
+pub mod document_scan;
+
+#[cfg(feature = "web_authentication_proxy")]
+pub mod web_authentication_proxy;
+
+pub mod namespace;
+
+#[cfg(feature = "contextual_identities")]
+pub mod contextual_identities;
+
+#[cfg(feature = "theme")]
+pub mod theme;
+
+#[cfg(feature = "sidebar_action")]
+pub mod sidebar_action;
+
+#[cfg(feature = "menus")]
+pub mod menus;
+
+#[cfg(feature = "pkcs11")]
+pub mod pkcs11;
+
+#[cfg(feature = "captive_portal")]
+pub mod captive_portal;
+
+#[cfg(feature = "browser_settings")]
+pub mod browser_settings;
+
+#[cfg(feature = "find")]
+pub mod find;
+
+#[cfg(feature = "compat")]
+pub mod compat;
+
+pub mod future;
+
+pub mod dynamic;
+
+#[cfg(feature = "keep_alive")]
+pub mod keep_alive;
+
+#[cfg(feature = "logger")]
+pub mod logger;
+
+#[cfg(feature = "rpc")]
+pub mod rpc;
+
+#[cfg(feature = "shared_state")]
+pub mod shared_state;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "test_harness")]
+pub mod test_harness;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+    pub use serde_wasm_bindgen;
+    pub use wasm_bindgen;
+    pub use wasm_bindgen_futures;
+    pub use crate::utils::to_json_value;
+}
+
 pub mod error {
     use std::fmt::{self, Debug};
     use serde_wasm_bindgen;
-    use wasm_bindgen::JsValue;
+    use wasm_bindgen::{JsCast, JsValue};
+    use js_sys::Reflect;
 
     #[derive(Debug)]
     pub enum Error {
         SerdeWasmBindgen(serde_wasm_bindgen::Error),
+        /// A thrown or rejected value that isn't an error or message.
         JsValue(JsValue),
+        InvalidMatchPattern(String),
+        /// An error reported by the browser that doesn't fit a more specific variant.
+        Runtime(Details),
+        QuotaExceeded(Details),
+        PermissionDenied(Details),
+        NoSuchKey(String),
+        /// An API the running browser doesn't provide, named by its path under `chrome`.
+        Unsupported(String),
+        /// The extension was reloaded, updated or removed while this context was still running.
+        ContextInvalidated(Details),
+        TimedOut,
+        Cancelled,
+        /// The port a call was made over closed before the call was answered.
+        Disconnected,
+    }
+
+    /// What went wrong, and the value the browser threw or rejected with.
+    #[derive(Debug, Clone)]
+    pub struct Details {
+        pub message: String,
+        /// `None` for errors made from a message alone, e.g. one relayed from another context.
+        pub source: Option<JsValue>,
+    }
+
+    impl fmt::Display for Details {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(&self.message)
+        }
+    }
+
+    const CONTEXT_INVALIDATED: &str = "Extension context invalidated.";
+
+    // Chrome names the storage limit that was hit. Firefox has one message for every limit.
+    const QUOTA_EXCEEDED: &[&str] = &[
+        "QUOTA_BYTES quota exceeded",
+        "QUOTA_BYTES_PER_ITEM quota exceeded",
+        "MAX_ITEMS quota exceeded",
+        "MAX_WRITE_OPERATIONS_PER_HOUR quota exceeded",
+        "MAX_WRITE_OPERATIONS_PER_MINUTE quota exceeded",
+        "Session storage quota bytes exceeded. Values were not stored.",
+        "QuotaExceededError: storage.sync API call exceeded its quota limitations.",
+    ];
+
+    // These messages go on to name the URL or host, so only their start is fixed.
+    const PERMISSION_DENIED: &[&str] = &[
+        "Cannot access contents of url ",
+        "Cannot access contents of the page. Extension manifest must request permission to access the respective host.",
+        "Cannot access a chrome:// URL",
+        "Cannot access a chrome-extension:// URL of different extension",
+        "Missing host permission for the tab",
+        "Extension manifest must request permission to access this host.",
+    ];
+
+    impl Error {
+        /// Sorts a browser error message into the matching variant.
+        pub fn from_message(message: &str) -> Self {
+            Self::classify(message, None)
+        }
+
+        /// A `Runtime` error with `message`, for failures that don't come from the browser.
+        pub fn runtime(message: impl Into<String>) -> Self {
+            Self::Runtime(Details { message: message.into(), source: None })
+        }
+
+        fn classify(message: &str, source: Option<JsValue>) -> Self {
+            let details = Details { message: message.to_owned(), source };
+
+            if message == CONTEXT_INVALIDATED {
+                Self::ContextInvalidated(details)
+            } else if QUOTA_EXCEEDED.contains(&message) {
+                Self::QuotaExceeded(details)
+            } else if PERMISSION_DENIED.iter().any(|prefix| message.starts_with(prefix)) {
+                Self::PermissionDenied(details)
+            } else {
+                Self::Runtime(details)
+            }
+        }
+
+        /// Reads `chrome.runtime.lastError`, which callback-based calls set instead of rejecting.
+        pub fn last_error() -> Option<Self> {
+            let chrome = Reflect::get(&js_sys::global(), &"chrome".into()).ok()?;
+            let runtime = Reflect::get(&chrome, &"runtime".into()).ok()?;
+            let last_error = Reflect::get(&runtime, &"lastError".into()).ok()?;
+
+            if last_error.is_undefined() || last_error.is_null() {
+                return None;
+            }
+
+            Some(last_error.into())
+        }
+
+        pub fn message(&self) -> Option<&str> {
+            self.details().map(|details| details.message.as_str())
+        }
+
+        /// The message and source value of an error the browser reported.
+        pub fn details(&self) -> Option<&Details> {
+            match self {
+                Error::Runtime(d) | Error::QuotaExceeded(d) | Error::PermissionDenied(d) | Error::ContextInvalidated(d) => Some(d),
+                _ => None,
+            }
+        }
     }
 
     impl fmt::Display for Error {
@@ -225,6 +700,16 @@ pub mod error {
                     write!(f, "JsValue error: ")?;
                     e.fmt(f)
                 },
+                Error::InvalidMatchPattern(p) => write!(f, "Invalid match pattern: {}", p),
+                Error::Runtime(d) => write!(f, "Runtime error: {}", d),
+                Error::QuotaExceeded(d) => write!(f, "Quota exceeded: {}", d),
+                Error::PermissionDenied(d) => write!(f, "Permission denied: {}", d),
+                Error::NoSuchKey(k) => write!(f, "No such key: {}", k),
+                Error::Unsupported(p) => write!(f, "Unsupported API: {}", p),
+                Error::ContextInvalidated(_) => write!(f, "Extension context invalidated"),
+                Error::TimedOut => write!(f, "Timed out"),
+                Error::Cancelled => write!(f, "Cancelled"),
+                Error::Disconnected => write!(f, "Disconnected"),
             }
         }
     }
@@ -239,7 +724,56 @@ pub mod error {
 
     impl From<JsValue> for Error {
         fn from(e: JsValue) -> Self {
-            Self::JsValue(e)
+            let message = match e.dyn_ref::<js_sys::Error>() {
+                Some(error) => error.message().as_string(),
+                None => e.as_string().or_else(|| {
+                    // runtime.lastError is a plain object with a message.
+                    Reflect::get(&e, &"message".into()).ok().and_then(|m| m.as_string())
+                }),
+            };
+
+            match message {
+                Some(message) => Self::classify(&message, Some(e)),
+                None => Self::JsValue(e),
+            }
+        }
+    }
+
+    // A runtime error rather than its own variant, so the set of variants
+    // doesn't depend on which features are enabled.
+    #[cfg(feature = "testing")]
+    impl From<serde_json::Error> for Error {
+        fn from(e: serde_json::Error) -> Self {
+            Self::runtime(e.to_string())
         }
     }
-}
\ No newline at end of file
+
+    #[cfg(test)]
+    mod tests {
+        use super::Error;
+
+        #[test]
+        fn from_message_matches_browser_messages_only() {
+            assert!(matches!(Error::from_message("Extension context invalidated."), Error::ContextInvalidated(_)));
+            assert!(matches!(Error::from_message("QUOTA_BYTES_PER_ITEM quota exceeded"), Error::QuotaExceeded(_)));
+            assert!(matches!(Error::from_message("MAX_WRITE_OPERATIONS_PER_MINUTE quota exceeded"), Error::QuotaExceeded(_)));
+            assert!(matches!(
+                Error::from_message("Cannot access contents of url \"https://example.com/\". Extension manifest must request permission to access this host."),
+                Error::PermissionDenied(_),
+            ));
+            assert!(matches!(Error::from_message("Missing host permission for the tab"), Error::PermissionDenied(_)));
+
+            assert!(matches!(Error::from_message("Only permissions specified in the manifest may be requested."), Error::Runtime(_)));
+            assert!(matches!(Error::from_message("The quota was fine"), Error::Runtime(_)));
+            assert!(matches!(Error::from_message("No tab with id: 3."), Error::Runtime(_)));
+        }
+
+        #[test]
+        fn from_message_keeps_the_message() {
+            let error = Error::from_message("No tab with id: 3.");
+
+            assert_eq!(error.message(), Some("No tab with id: 3."));
+            assert!(error.details().unwrap().source.is_none());
+        }
+    }
+}
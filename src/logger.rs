@@ -0,0 +1,256 @@
+//! A `log` backend that writes to the console of the current extension context.
+//!
+//! Every record is prefixed with the context it came from, so the background
+//! console and a page's console can be told apart at a glance:
+//!
+//! ```ignore
+//! logger::Logger::new().level(LevelFilter::Debug).mirror_errors(true).init()?;
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Function, Object, Reflect};
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+use serde::{Serialize, Deserialize};
+use crate::storage::session;
+use crate::error::Error;
+
+/// The `storage.session` key mirrored errors are kept under.
+pub const MIRROR_KEY: &str = "web_extension_sys.logger.errors";
+
+/// How many mirrored errors are kept before dropping the oldest.
+pub const MIRROR_LIMIT: usize = 100;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    fn console_error(message: &str);
+
+    #[wasm_bindgen(js_namespace = console, js_name = warn)]
+    fn console_warn(message: &str);
+
+    #[wasm_bindgen(js_namespace = console, js_name = info)]
+    fn console_info(message: &str);
+
+    #[wasm_bindgen(js_namespace = console, js_name = debug)]
+    fn console_debug(message: &str);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Context {
+    /// The background page or service worker.
+    Background,
+    Popup,
+    ContentScript,
+    /// Any other extension page, such as the options page or a tab.
+    Page,
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Context::Background => "background",
+            Context::Popup => "popup",
+            Context::ContentScript => "content",
+            Context::Page => "page",
+        })
+    }
+}
+
+impl Context {
+    /// Works out which context this code is running in.
+    pub fn detect() -> Self {
+        let global = js_sys::global();
+
+        let protocol = Reflect::get(&global, &"location".into())
+            .and_then(|location| Reflect::get(&location, &"protocol".into()))
+            .ok()
+            .and_then(|protocol| protocol.as_string())
+            .unwrap_or_default();
+
+        if !protocol.ends_with("-extension:") {
+            return Context::ContentScript;
+        }
+
+        if !Reflect::has(&global, &"document".into()).unwrap_or(false) {
+            return Context::Background;
+        }
+
+        let extension = match crate::namespace::get("extension") {
+            Some(extension) => extension,
+            None => return Context::Page,
+        };
+
+        if let Some(background) = call(&extension, "getBackgroundPage", &JsValue::UNDEFINED) {
+            if Object::is(&background, &global) {
+                return Context::Background;
+            }
+        }
+
+        let popup = Object::new();
+        let _ = Reflect::set(&popup, &"type".into(), &"popup".into());
+
+        match call(&extension, "getViews", &popup) {
+            Some(views) if Array::from(&views).includes(&global, 0) => Context::Popup,
+            _ => Context::Page,
+        }
+    }
+}
+
+fn call(target: &JsValue, method: &str, argument: &JsValue) -> Option<JsValue> {
+    let function: Function = Reflect::get(target, &method.into()).ok()?.dyn_into().ok()?;
+
+    function.call1(target, argument).ok()
+}
+
+/// An error-level record saved to `storage.session`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoggedError {
+    pub context: Context,
+    pub target: String,
+    pub message: String,
+    /// Milliseconds since the epoch.
+    pub timestamp: f64,
+}
+
+pub struct Logger {
+    level: LevelFilter,
+    context: Context,
+    mirror_errors: bool,
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self {
+            level: LevelFilter::Info,
+            context: Context::detect(),
+            mirror_errors: false,
+        }
+    }
+}
+
+impl Logger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Overrides the detected context.
+    pub fn context(mut self, context: Context) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Also saves error-level records to `storage.session`, where a debug page
+    /// can read them back with `mirrored_errors`.
+    pub fn mirror_errors(mut self, mirror_errors: bool) -> Self {
+        self.mirror_errors = mirror_errors;
+        self
+    }
+
+    /// Installs this as the global logger. Fails if a logger is already set.
+    pub fn init(self) -> Result<(), SetLoggerError> {
+        log::set_max_level(self.level);
+        log::set_logger(Box::leak(Box::new(self)))
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let message = format!("[{}] {}: {}", self.context, record.target(), record.args());
+
+        match record.level() {
+            Level::Error => console_error(&message),
+            Level::Warn => console_warn(&message),
+            Level::Info => console_info(&message),
+            Level::Debug | Level::Trace => console_debug(&message),
+        }
+
+        if self.mirror_errors && record.level() == Level::Error {
+            mirror(LoggedError {
+                context: self.context,
+                target: record.target().to_owned(),
+                message: record.args().to_string(),
+                timestamp: js_sys::Date::now(),
+            });
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs a `Logger` at `level` for the detected context.
+pub fn init(level: LevelFilter) -> Result<(), SetLoggerError> {
+    Logger::new().level(level).init()
+}
+
+thread_local! {
+    static PENDING: RefCell<Vec<LoggedError>> = const { RefCell::new(Vec::new()) };
+    static FLUSHING: Cell<bool> = const { Cell::new(false) };
+}
+
+// Writes are queued and flushed by a single task, so errors logged in quick
+// succession don't overwrite each other.
+fn mirror(error: LoggedError) {
+    PENDING.with(|pending| pending.borrow_mut().push(error));
+
+    if !FLUSHING.with(|flushing| flushing.replace(true)) {
+        wasm_bindgen_futures::spawn_local(async {
+            // Content scripts can't use session storage unless its access level was raised.
+            if let Err(error) = flush_mirror().await {
+                console_warn(&format!("Couldn't mirror logged errors: {}", error));
+            }
+
+            FLUSHING.with(|flushing| flushing.set(false));
+        });
+    }
+}
+
+// Gives up on the batch if the stored errors can't be read, rather than
+// replacing them with it.
+async fn flush_mirror() -> Result<(), Error> {
+    loop {
+        let pending = PENDING.with(|pending| pending.take());
+
+        if pending.is_empty() || !crate::namespace::is_supported("storage.session") {
+            return Ok(());
+        }
+
+        let mut errors = mirrored_errors().await?;
+        errors.extend(pending);
+
+        if errors.len() > MIRROR_LIMIT {
+            errors.drain(..errors.len() - MIRROR_LIMIT);
+        }
+
+        session::set(&HashMap::from([(MIRROR_KEY, errors)])).await?;
+    }
+}
+
+/// The errors mirrored so far, oldest first.
+pub async fn mirrored_errors() -> Result<Vec<LoggedError>, Error> {
+    match session::get_key(MIRROR_KEY).await {
+        Err(Error::NoSuchKey(_)) => Ok(Vec::new()),
+        result => result,
+    }
+}
+
+pub async fn clear_mirrored_errors() -> Result<(), Error> {
+    session::remove(vec![MIRROR_KEY.to_owned()]).await
+}
@@ -0,0 +1,74 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProfileType {
+    SigninProfile,
+    UserProfile,
+    LockProfile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SessionState {
+    Unknown,
+    InOobeScreen,
+    InLoginScreen,
+    InSession,
+    InLockScreen,
+    InRmaScreen,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "loginState"], js_name = getProfileType)]
+    fn _get_profile_type() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "loginState"], js_name = getSessionState)]
+    fn _get_session_state() -> Promise;
+}
+
+pub async fn get_profile_type() -> Result<ProfileType, Error> {
+    resolve(_get_profile_type()).await
+}
+
+pub async fn get_session_state() -> Result<SessionState, Error> {
+    resolve(_get_session_state()).await
+}
+
+pub mod on_session_state_changed {
+    use wasm_bindgen::prelude::*;
+    use super::SessionState;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "loginState", "onSessionStateChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "loginState"], js_name = onSessionStateChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(SessionState) + 'static,
+    {
+        Closure::wrap(Box::new(move |session_state: JsValue| {
+            callback(event_arg!(session_state));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(SessionState) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<SessionState> {
+        stream_of(listen)
+    }
+}
@@ -0,0 +1,248 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtensionType {
+    Extension,
+    HostedApp,
+    PackagedApp,
+    LegacyPackagedApp,
+    Theme,
+    LoginScreenExtension,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtensionInstallType {
+    Admin,
+    Development,
+    Normal,
+    Sideload,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtensionDisabledReason {
+    Unknown,
+    PermissionsIncrease,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IconInfo {
+    pub size: u32,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionInfo {
+    pub id: String,
+    pub name: String,
+    pub short_name: Option<String>,
+    pub description: String,
+    pub version: String,
+    pub version_name: Option<String>,
+    pub enabled: bool,
+    pub may_disable: bool,
+    pub may_enable: Option<bool>,
+    pub disabled_reason: Option<ExtensionDisabledReason>,
+    #[serde(rename = "type")]
+    pub extension_type: ExtensionType,
+    pub install_type: ExtensionInstallType,
+    pub homepage_url: Option<String>,
+    pub update_url: Option<String>,
+    pub options_url: Option<String>,
+    pub offline_enabled: Option<bool>,
+    #[serde(default)]
+    pub icons: Vec<IconInfo>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    #[serde(default)]
+    pub host_permissions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UninstallOptions {
+    pub show_confirm_dialog: Option<bool>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "management"], js_name = getAll)]
+    fn _get_all() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "management"], js_name = get)]
+    fn _get(id: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "management"], js_name = getSelf)]
+    fn _get_self() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "management"], js_name = setEnabled)]
+    fn _set_enabled(id: &str, enabled: bool) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "management"], js_name = uninstall)]
+    fn _uninstall(id: &str, options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "management"], js_name = uninstallSelf)]
+    fn _uninstall_self(options: JsValue) -> Promise;
+}
+
+pub async fn get_all() -> Result<Vec<ExtensionInfo>, Error> {
+    resolve(_get_all()).await
+}
+
+pub async fn get(id: &str) -> Result<ExtensionInfo, Error> {
+    resolve(_get(id)).await
+}
+
+pub async fn get_self() -> Result<ExtensionInfo, Error> {
+    resolve(_get_self()).await
+}
+
+pub async fn set_enabled(id: &str, enabled: bool) -> Result<(), Error> {
+    resolve(_set_enabled(id, enabled)).await
+}
+
+pub async fn uninstall(id: &str, options: &UninstallOptions) -> Result<(), Error> {
+    resolve(_uninstall(id, serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn uninstall_self(options: &UninstallOptions) -> Result<(), Error> {
+    resolve(_uninstall_self(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub mod on_installed {
+    use wasm_bindgen::prelude::*;
+    use super::ExtensionInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "management", "onInstalled"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "management"], js_name = onInstalled)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ExtensionInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |info: JsValue| {
+            callback(event_arg!(info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ExtensionInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ExtensionInfo> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_uninstalled {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "management", "onUninstalled"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "management"], js_name = onUninstalled)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(String)>
+        where T: FnMut(String) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<String> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_enabled {
+    use wasm_bindgen::prelude::*;
+    use super::ExtensionInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "management", "onEnabled"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "management"], js_name = onEnabled)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ExtensionInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |info: JsValue| {
+            callback(event_arg!(info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ExtensionInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ExtensionInfo> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_disabled {
+    use wasm_bindgen::prelude::*;
+    use super::ExtensionInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "management", "onDisabled"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "management"], js_name = onDisabled)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ExtensionInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |info: JsValue| {
+            callback(event_arg!(info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ExtensionInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ExtensionInfo> {
+        stream_of(listen)
+    }
+}
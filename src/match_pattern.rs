@@ -0,0 +1,182 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use serde::{Serialize, Deserialize};
+use crate::error::Error;
+
+const ALL_URLS: &str = "<all_urls>";
+
+const SCHEMES: &[&str] = &["*", "http", "https", "file", "ftp", "urn", "ws", "wss"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct MatchPattern(String);
+
+impl MatchPattern {
+    pub fn new(pattern: &str) -> Result<Self, Error> {
+        if is_valid(pattern) {
+            Ok(Self(pattern.to_owned()))
+        } else {
+            Err(Error::InvalidMatchPattern(pattern.to_owned()))
+        }
+    }
+
+    pub fn all_urls() -> Self {
+        Self(ALL_URLS.to_owned())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn is_valid(pattern: &str) -> bool {
+    if pattern == ALL_URLS {
+        return true;
+    }
+
+    let (scheme, rest) = match pattern.split_once("://") {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    if !SCHEMES.contains(&scheme) {
+        return false;
+    }
+
+    // The path is mandatory, even if it's just "/".
+    let host = match rest.find('/') {
+        Some(i) => &rest[..i],
+        None => return false,
+    };
+
+    if scheme == "file" {
+        return !host.contains('*');
+    }
+
+    match strip_port(host) {
+        Some(host) => is_valid_host(host),
+        None => false,
+    }
+}
+
+fn strip_port(host: &str) -> Option<&str> {
+    let port_start = if host.starts_with('[') {
+        // IPv6 literals contain colons, so only look after the closing bracket,
+        // which must end the host or be followed by the port.
+        let end = host.find(']')? + 1;
+
+        match host[end..].chars().next() {
+            None => None,
+            Some(':') => Some(end),
+            Some(_) => return None,
+        }
+    } else {
+        host.find(':')
+    };
+
+    let i = match port_start {
+        Some(i) => i,
+        None => return Some(host),
+    };
+
+    let port = &host[i + 1..];
+
+    if port == "*" || (!port.is_empty() && port.bytes().all(|b| b.is_ascii_digit())) {
+        Some(&host[..i])
+    } else {
+        None
+    }
+}
+
+fn is_valid_host(host: &str) -> bool {
+    if host == "*" {
+        return true;
+    }
+
+    let host = host.strip_prefix("*.").unwrap_or(host);
+
+    !host.is_empty() && !host.contains('*')
+}
+
+impl FromStr for MatchPattern {
+    type Err = Error;
+
+    fn from_str(pattern: &str) -> Result<Self, Error> {
+        Self::new(pattern)
+    }
+}
+
+impl TryFrom<String> for MatchPattern {
+    type Error = Error;
+
+    fn try_from(pattern: String) -> Result<Self, Error> {
+        Self::new(&pattern)
+    }
+}
+
+impl From<MatchPattern> for String {
+    fn from(pattern: MatchPattern) -> Self {
+        pattern.0
+    }
+}
+
+impl fmt::Display for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid, strip_port};
+
+    #[test]
+    fn accepts_valid_patterns() {
+        for pattern in [
+            "<all_urls>",
+            "*://*/*",
+            "https://example.com/",
+            "https://*.example.com/path/*",
+            "http://localhost:8080/*",
+            "http://localhost:*/*",
+            "http://[::1]/*",
+            "http://[::1]:80/*",
+            "file:///home/*",
+            "wss://*/socket",
+        ] {
+            assert!(is_valid(pattern), "{} should be valid", pattern);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        for pattern in [
+            "",
+            "example.com/*",
+            "chrome://extensions/",
+            "https://example.com",
+            "https://*example.com/",
+            "https://example.*/",
+            "https:///",
+            "http://localhost:/*",
+            "http://localhost:8o/*",
+            "http://[::1]x80/*",
+            "http://[::1/*",
+            "file://*/",
+        ] {
+            assert!(!is_valid(pattern), "{} should be invalid", pattern);
+        }
+    }
+
+    #[test]
+    fn strip_port_leaves_the_host() {
+        assert_eq!(strip_port("example.com"), Some("example.com"));
+        assert_eq!(strip_port("example.com:443"), Some("example.com"));
+        assert_eq!(strip_port("example.com:*"), Some("example.com"));
+        assert_eq!(strip_port("[::1]"), Some("[::1]"));
+        assert_eq!(strip_port("[::1]:443"), Some("[::1]"));
+        assert_eq!(strip_port("[::1]:"), None);
+        assert_eq!(strip_port("[::1]x80"), None);
+    }
+}
@@ -0,0 +1,305 @@
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use serde_wasm_bindgen::Serializer;
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextType {
+    All,
+    Action,
+    Audio,
+    Bookmark,
+    BrowserAction,
+    Editable,
+    Frame,
+    Image,
+    Link,
+    Page,
+    PageAction,
+    Password,
+    Selection,
+    Tab,
+    ToolsMenu,
+    Video,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ItemType {
+    Normal,
+    Checkbox,
+    Radio,
+    Separator,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ViewType {
+    Tab,
+    Popup,
+    Sidebar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OverrideContextType {
+    Bookmark,
+    Tab,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MenuItemId {
+    Integer(i32),
+    String(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProperties {
+    #[serde(rename = "type")]
+    pub item_type: Option<ItemType>,
+    pub id: Option<String>,
+    /// Icon paths keyed by size in pixels.
+    pub icons: Option<BTreeMap<u32, String>>,
+    pub title: Option<String>,
+    pub checked: Option<bool>,
+    pub contexts: Option<Vec<ContextType>>,
+    pub view_types: Option<Vec<ViewType>>,
+    pub visible: Option<bool>,
+    pub parent_id: Option<MenuItemId>,
+    pub document_url_patterns: Option<Vec<String>>,
+    pub target_url_patterns: Option<Vec<String>>,
+    pub enabled: Option<bool>,
+    pub command: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProperties {
+    #[serde(rename = "type")]
+    pub item_type: Option<ItemType>,
+    pub icons: Option<BTreeMap<u32, String>>,
+    pub title: Option<String>,
+    pub checked: Option<bool>,
+    pub contexts: Option<Vec<ContextType>>,
+    pub view_types: Option<Vec<ViewType>>,
+    pub visible: Option<bool>,
+    pub parent_id: Option<MenuItemId>,
+    pub document_url_patterns: Option<Vec<String>>,
+    pub target_url_patterns: Option<Vec<String>>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextOptions {
+    pub show_defaults: Option<bool>,
+    pub context: Option<OverrideContextType>,
+    pub bookmark_id: Option<String>,
+    pub tab_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnClickData {
+    pub menu_item_id: MenuItemId,
+    pub parent_menu_item_id: Option<MenuItemId>,
+    pub modifiers: Vec<String>,
+    pub button: Option<i32>,
+    pub editable: bool,
+    pub media_type: Option<String>,
+    pub view_type: Option<ViewType>,
+    pub link_url: Option<String>,
+    pub link_text: Option<String>,
+    pub src_url: Option<String>,
+    pub page_url: Option<String>,
+    pub frame_id: Option<i32>,
+    pub frame_url: Option<String>,
+    pub selection_text: Option<String>,
+    pub was_checked: Option<bool>,
+    pub checked: Option<bool>,
+    pub bookmark_id: Option<String>,
+    pub target_element_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnShownInfo {
+    pub menu_ids: Vec<MenuItemId>,
+    pub contexts: Vec<ContextType>,
+    pub editable: bool,
+    pub media_type: Option<String>,
+    pub view_type: Option<ViewType>,
+    pub link_url: Option<String>,
+    pub link_text: Option<String>,
+    pub src_url: Option<String>,
+    pub page_url: Option<String>,
+    pub frame_url: Option<String>,
+    pub selection_text: Option<String>,
+    pub bookmark_id: Option<String>,
+    pub target_element_id: Option<i32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "menus"], js_name = create)]
+    fn _create(create_properties: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "menus"], js_name = update)]
+    fn _update(id: JsValue, update_properties: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "menus"], js_name = remove)]
+    fn _remove(menu_item_id: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "menus"], js_name = removeAll)]
+    fn _remove_all() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "menus"], js_name = refresh)]
+    fn _refresh() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "menus"], js_name = overrideContext)]
+    fn _override_context(context_options: JsValue);
+}
+
+/// Returns the ID of the new item.
+pub fn create(create_properties: &CreateProperties) -> Result<MenuItemId, Error> {
+    let create_properties = create_properties.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+
+    Ok(serde_wasm_bindgen::from_value(_create(create_properties))?)
+}
+
+pub async fn update(id: &MenuItemId, update_properties: &UpdateProperties) -> Result<(), Error> {
+    let update_properties = update_properties.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+
+    resolve(_update(serde_wasm_bindgen::to_value(id)?, update_properties)).await
+}
+
+pub async fn remove(menu_item_id: &MenuItemId) -> Result<(), Error> {
+    resolve(_remove(serde_wasm_bindgen::to_value(menu_item_id)?)).await
+}
+
+pub async fn remove_all() -> Result<(), Error> {
+    resolve(_remove_all()).await
+}
+
+/// Redraws a menu that is currently shown, typically after updating it in
+/// an `on_shown` listener.
+pub async fn refresh() -> Result<(), Error> {
+    resolve(_refresh()).await
+}
+
+/// Replaces the default context menu for the current `contextmenu` event.
+pub fn override_context(context_options: &ContextOptions) -> Result<(), Error> {
+    _override_context(serde_wasm_bindgen::to_value(context_options)?);
+
+    Ok(())
+}
+
+pub mod on_clicked {
+    use wasm_bindgen::prelude::*;
+    use crate::tabs::Tab;
+    use super::OnClickData;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "menus", "onClicked"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "menus"], js_name = onClicked)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, JsValue)>
+        where T: FnMut(OnClickData, Option<Tab>) + 'static,
+    {
+        Closure::wrap(Box::new(move |info: JsValue, tab: JsValue| {
+            callback(
+                event_arg!(info),
+                event_arg!(tab),
+            );
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(OnClickData, Option<Tab>) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(OnClickData, Option<Tab>)> {
+        stream_of(|mut callback| listen(move |info, tab| callback((info, tab))))
+    }
+}
+
+pub mod on_shown {
+    use wasm_bindgen::prelude::*;
+    use crate::tabs::Tab;
+    use super::OnShownInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "menus", "onShown"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "menus"], js_name = onShown)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, JsValue)>
+        where T: FnMut(OnShownInfo, Option<Tab>) + 'static,
+    {
+        Closure::wrap(Box::new(move |info: JsValue, tab: JsValue| {
+            callback(
+                event_arg!(info),
+                event_arg!(tab),
+            );
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(OnShownInfo, Option<Tab>) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(OnShownInfo, Option<Tab>)> {
+        stream_of(|mut callback| listen(move |info, tab| callback((info, tab))))
+    }
+}
+
+pub mod on_hidden {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "menus", "onHidden"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "menus"], js_name = onHidden)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
@@ -0,0 +1,118 @@
+//! Picks the extension global, `chrome` or `browser`, for the lookups this
+//! crate makes at run time: `compat`, `logger`, the `ChromeSetting` and
+//! `ContentSetting` accessors, the `keep_alive` alarms, devtools' `tab_id` and
+//! Safari promise calls, and `is_supported`/`require`.
+//!
+//! The typed bindings are `js_namespace = ["chrome", ...]` externs and always
+//! bind `chrome.*`. They work in Firefox through its `chrome` alias, but not in
+//! a `browser`-only environment; use `compat`, or `dynamic` with a path
+//! starting at `browser`, there.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+use js_sys::Reflect;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Namespace {
+    Chrome,
+    Browser,
+}
+
+impl Namespace {
+    fn global_name(self) -> &'static str {
+        match self {
+            Namespace::Chrome => "chrome",
+            Namespace::Browser => "browser",
+        }
+    }
+}
+
+// Callers that pass callbacks need `chrome`, since Firefox's `browser` rejects
+// them, so it comes first wherever it exists. Safari only promises consistent
+// behaviour on `browser`.
+#[cfg(feature = "safari")]
+const PREFERENCE: [Namespace; 2] = [Namespace::Browser, Namespace::Chrome];
+#[cfg(not(feature = "safari"))]
+const PREFERENCE: [Namespace; 2] = [Namespace::Chrome, Namespace::Browser];
+
+thread_local! {
+    static RESOLVED: RefCell<Option<(Namespace, JsValue)>> = const { RefCell::new(None) };
+}
+
+// A miss isn't cached, so a global that shows up later, such as one injected
+// by a test harness, is still found.
+fn resolve() -> (Namespace, JsValue) {
+    if let Some(resolved) = RESOLVED.with(|resolved| resolved.borrow().clone()) {
+        return resolved;
+    }
+
+    let global = js_sys::global();
+    let found = PREFERENCE.iter().find_map(|&namespace| {
+        let object = Reflect::get(&global, &namespace.global_name().into()).ok()?;
+
+        if object.is_object() {
+            Some((namespace, object))
+        } else {
+            None
+        }
+    });
+
+    match found {
+        Some(resolved) => {
+            RESOLVED.with(|cell| *cell.borrow_mut() = Some(resolved.clone()));
+            resolved
+        }
+        None => (PREFERENCE[0], JsValue::UNDEFINED),
+    }
+}
+
+/// The global that calls looked up at run time go through, resolved on first
+/// use: `chrome` where it exists, and `browser` in environments without it.
+/// With the `safari` feature `browser` is preferred instead. The typed
+/// bindings don't consult this; they always call `chrome`.
+pub fn current() -> Namespace {
+    resolve().0
+}
+
+/// Whether the browser provides the API at a path under the extension global,
+/// such as `"storage.session"` or `"action.openPopup"`.
+pub fn is_supported(path: &str) -> bool {
+    get(path).is_some()
+}
+
+/// Fails with `Error::Unsupported` if the API at `path` is missing.
+pub fn require(path: &str) -> Result<(), Error> {
+    if is_supported(path) {
+        Ok(())
+    } else {
+        Err(Error::Unsupported(path.to_owned()))
+    }
+}
+
+/// Calls `method` on the object at `path` in promise form, for the bindings
+/// Safari only accepts that way.
+#[cfg(all(feature = "safari", feature = "devtools"))]
+pub(crate) async fn call(path: &str, method: &str, args: &[JsValue]) -> Result<JsValue, Error> {
+    let target = get(path).ok_or_else(|| Error::Unsupported(path.to_owned()))?;
+
+    crate::dynamic::call_method(&target, method, args).await
+}
+
+pub(crate) fn get(path: &str) -> Option<JsValue> {
+    let mut value = resolve().1;
+
+    for key in path.split('.') {
+        if !value.is_object() && !value.is_function() {
+            return None;
+        }
+
+        value = Reflect::get(&value, &key.into()).ok()?;
+    }
+
+    if value.is_undefined() || value.is_null() {
+        None
+    } else {
+        Some(value)
+    }
+}
@@ -0,0 +1,57 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Reason {
+    Testing,
+    AudioPlayback,
+    IframeScripting,
+    DomScraping,
+    Blobs,
+    DomParser,
+    UserMedia,
+    DisplayMedia,
+    WebRtc,
+    Clipboard,
+    LocalStorage,
+    Workers,
+    BatteryStatus,
+    MatchMedia,
+    Geolocation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateParameters {
+    pub url: String,
+    pub reasons: Vec<Reason>,
+    pub justification: String,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "offscreen"], js_name = createDocument)]
+    fn _create_document(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "offscreen"], js_name = closeDocument)]
+    fn _close_document() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "offscreen"], js_name = hasDocument)]
+    fn _has_document() -> Promise;
+}
+
+pub async fn create_document(parameters: &CreateParameters) -> Result<(), Error> {
+    resolve(_create_document(serde_wasm_bindgen::to_value(parameters)?)).await
+}
+
+pub async fn close_document() -> Result<(), Error> {
+    resolve(_close_document()).await
+}
+
+pub async fn has_document() -> Result<bool, Error> {
+    resolve(_has_document()).await
+}
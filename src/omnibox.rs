@@ -0,0 +1,223 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Function, Promise};
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestResult {
+    pub content: String,
+    pub description: String,
+    pub deletable: Option<bool>,
+}
+
+impl SuggestResult {
+    pub fn new(content: &str, description: &str) -> Self {
+        Self::with_markup(content, &escape_xml(description))
+    }
+
+    /// Takes a description that is already XML, so `<match>`, `<dim>` and `<url>` can be used.
+    pub fn with_markup(content: &str, description: &str) -> Self {
+        Self {
+            content: content.to_owned(),
+            description: description.to_owned(),
+            deletable: None,
+        }
+    }
+
+    pub fn deletable(mut self, deletable: bool) -> Self {
+        self.deletable = Some(deletable);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefaultSuggestResult {
+    pub description: String,
+}
+
+impl DefaultSuggestResult {
+    pub fn new(description: &str) -> Self {
+        Self::with_markup(&escape_xml(description))
+    }
+
+    pub fn with_markup(description: &str) -> Self {
+        Self {
+            description: description.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OnInputEnteredDisposition {
+    CurrentTab,
+    NewForegroundTab,
+    NewBackgroundTab,
+}
+
+pub fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "omnibox"], js_name = setDefaultSuggestion)]
+    fn _set_default_suggestion(suggestion: JsValue) -> Promise;
+}
+
+pub async fn set_default_suggestion(suggestion: &DefaultSuggestResult) -> Result<(), Error> {
+    resolve(_set_default_suggestion(serde_wasm_bindgen::to_value(suggestion)?)).await
+}
+
+pub mod on_input_started {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "omnibox", "onInputStarted"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "omnibox"], js_name = onInputStarted)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
+
+pub mod on_input_changed {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::Suggest;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "omnibox", "onInputChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "omnibox"], js_name = onInputChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, Function)>
+        where T: FnMut(String, Suggest) + 'static,
+    {
+        Closure::wrap(Box::new(move |text, suggest| {
+            callback(text, Suggest(suggest));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, Suggest) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, Suggest)> {
+        stream_of(|mut callback| listen(move |text, suggest| callback((text, suggest))))
+    }
+}
+
+pub struct Suggest(Function);
+
+impl Suggest {
+    pub fn suggest(&self, results: &[SuggestResult]) -> Result<(), Error> {
+        self.0.call1(&JsValue::NULL, &serde_wasm_bindgen::to_value(results)?)?;
+
+        Ok(())
+    }
+}
+
+pub mod on_input_entered {
+    use wasm_bindgen::prelude::*;
+    use super::OnInputEnteredDisposition;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "omnibox", "onInputEntered"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "omnibox"], js_name = onInputEntered)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, JsValue)>
+        where T: FnMut(String, OnInputEnteredDisposition) + 'static,
+    {
+        Closure::wrap(Box::new(move |text, disposition: JsValue| {
+            callback(text, event_arg!(disposition));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, OnInputEnteredDisposition) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, OnInputEnteredDisposition)> {
+        stream_of(|mut callback| listen(move |text, disposition| callback((text, disposition))))
+    }
+}
+
+pub mod on_input_cancelled {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "omnibox", "onInputCancelled"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "omnibox"], js_name = onInputCancelled)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
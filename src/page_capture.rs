@@ -0,0 +1,32 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use js_sys::{Promise, Uint8Array};
+use serde::{Serialize, Deserialize};
+use web_sys::Blob;
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveDetails {
+    pub tab_id: i32,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "pageCapture"], js_name = saveAsMHTML)]
+    fn _save_as_mhtml(details: JsValue) -> Promise;
+}
+
+pub async fn save_as_mhtml(details: &SaveDetails) -> Result<Blob, Error> {
+    let blob = JsFuture::from(_save_as_mhtml(serde_wasm_bindgen::to_value(details)?)).await?;
+
+    Ok(blob.unchecked_into())
+}
+
+/// Like `save_as_mhtml`, but reads the resulting blob into memory.
+pub async fn save_as_mhtml_bytes(details: &SaveDetails) -> Result<Vec<u8>, Error> {
+    let blob = save_as_mhtml(details).await?;
+    let buffer = JsFuture::from(blob.array_buffer()).await?;
+
+    Ok(Uint8Array::new(&buffer).to_vec())
+}
@@ -0,0 +1,380 @@
+use std::fmt;
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::match_pattern::MatchPattern;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Permissions {
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    #[serde(default)]
+    pub origins: Vec<String>,
+}
+
+impl Permissions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn permission(mut self, permission: Permission) -> Self {
+        self.permissions.push(permission.to_string());
+        self
+    }
+
+    pub fn origin(mut self, origin: MatchPattern) -> Self {
+        self.origins.push(origin.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Permission {
+    ActiveTab,
+    Alarms,
+    Background,
+    Bookmarks,
+    BrowsingData,
+    CertificateProvider,
+    ClipboardRead,
+    ClipboardWrite,
+    ContentSettings,
+    ContextMenus,
+    Cookies,
+    Debugger,
+    DeclarativeContent,
+    DeclarativeNetRequest,
+    DeclarativeNetRequestFeedback,
+    DeclarativeNetRequestWithHostAccess,
+    DesktopCapture,
+    Dns,
+    DocumentScan,
+    Downloads,
+    DownloadsOpen,
+    DownloadsUi,
+    EnterpriseDeviceAttributes,
+    EnterpriseHardwarePlatform,
+    EnterpriseNetworkingAttributes,
+    EnterprisePlatformKeys,
+    Favicon,
+    FileBrowserHandler,
+    FileSystemProvider,
+    FontSettings,
+    Gcm,
+    Geolocation,
+    History,
+    Identity,
+    IdentityEmail,
+    Idle,
+    LoginState,
+    Management,
+    NativeMessaging,
+    Notifications,
+    Offscreen,
+    PageCapture,
+    PlatformKeys,
+    Power,
+    PrinterProvider,
+    Printing,
+    PrintingMetrics,
+    Privacy,
+    Processes,
+    Proxy,
+    ReadingList,
+    Runtime,
+    Scripting,
+    Search,
+    Sessions,
+    SidePanel,
+    Storage,
+    SystemCpu,
+    SystemDisplay,
+    SystemMemory,
+    SystemStorage,
+    TabCapture,
+    TabGroups,
+    Tabs,
+    TopSites,
+    Tts,
+    TtsEngine,
+    UnlimitedStorage,
+    UserScripts,
+    VpnProvider,
+    Wallpaper,
+    WebAuthenticationProxy,
+    WebNavigation,
+    WebRequest,
+    WebRequestBlocking,
+    Other(String),
+}
+
+impl Permission {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Permission::ActiveTab => "activeTab",
+            Permission::Alarms => "alarms",
+            Permission::Background => "background",
+            Permission::Bookmarks => "bookmarks",
+            Permission::BrowsingData => "browsingData",
+            Permission::CertificateProvider => "certificateProvider",
+            Permission::ClipboardRead => "clipboardRead",
+            Permission::ClipboardWrite => "clipboardWrite",
+            Permission::ContentSettings => "contentSettings",
+            Permission::ContextMenus => "contextMenus",
+            Permission::Cookies => "cookies",
+            Permission::Debugger => "debugger",
+            Permission::DeclarativeContent => "declarativeContent",
+            Permission::DeclarativeNetRequest => "declarativeNetRequest",
+            Permission::DeclarativeNetRequestFeedback => "declarativeNetRequestFeedback",
+            Permission::DeclarativeNetRequestWithHostAccess => "declarativeNetRequestWithHostAccess",
+            Permission::DesktopCapture => "desktopCapture",
+            Permission::Dns => "dns",
+            Permission::DocumentScan => "documentScan",
+            Permission::Downloads => "downloads",
+            Permission::DownloadsOpen => "downloads.open",
+            Permission::DownloadsUi => "downloads.ui",
+            Permission::EnterpriseDeviceAttributes => "enterprise.deviceAttributes",
+            Permission::EnterpriseHardwarePlatform => "enterprise.hardwarePlatform",
+            Permission::EnterpriseNetworkingAttributes => "enterprise.networkingAttributes",
+            Permission::EnterprisePlatformKeys => "enterprise.platformKeys",
+            Permission::Favicon => "favicon",
+            Permission::FileBrowserHandler => "fileBrowserHandler",
+            Permission::FileSystemProvider => "fileSystemProvider",
+            Permission::FontSettings => "fontSettings",
+            Permission::Gcm => "gcm",
+            Permission::Geolocation => "geolocation",
+            Permission::History => "history",
+            Permission::Identity => "identity",
+            Permission::IdentityEmail => "identity.email",
+            Permission::Idle => "idle",
+            Permission::LoginState => "loginState",
+            Permission::Management => "management",
+            Permission::NativeMessaging => "nativeMessaging",
+            Permission::Notifications => "notifications",
+            Permission::Offscreen => "offscreen",
+            Permission::PageCapture => "pageCapture",
+            Permission::PlatformKeys => "platformKeys",
+            Permission::Power => "power",
+            Permission::PrinterProvider => "printerProvider",
+            Permission::Printing => "printing",
+            Permission::PrintingMetrics => "printingMetrics",
+            Permission::Privacy => "privacy",
+            Permission::Processes => "processes",
+            Permission::Proxy => "proxy",
+            Permission::ReadingList => "readingList",
+            Permission::Runtime => "runtime",
+            Permission::Scripting => "scripting",
+            Permission::Search => "search",
+            Permission::Sessions => "sessions",
+            Permission::SidePanel => "sidePanel",
+            Permission::Storage => "storage",
+            Permission::SystemCpu => "system.cpu",
+            Permission::SystemDisplay => "system.display",
+            Permission::SystemMemory => "system.memory",
+            Permission::SystemStorage => "system.storage",
+            Permission::TabCapture => "tabCapture",
+            Permission::TabGroups => "tabGroups",
+            Permission::Tabs => "tabs",
+            Permission::TopSites => "topSites",
+            Permission::Tts => "tts",
+            Permission::TtsEngine => "ttsEngine",
+            Permission::UnlimitedStorage => "unlimitedStorage",
+            Permission::UserScripts => "userScripts",
+            Permission::VpnProvider => "vpnProvider",
+            Permission::Wallpaper => "wallpaper",
+            Permission::WebAuthenticationProxy => "webAuthenticationProxy",
+            Permission::WebNavigation => "webNavigation",
+            Permission::WebRequest => "webRequest",
+            Permission::WebRequestBlocking => "webRequestBlocking",
+            Permission::Other(permission) => permission,
+        }
+    }
+}
+
+impl From<&str> for Permission {
+    fn from(permission: &str) -> Self {
+        match permission {
+            "activeTab" => Permission::ActiveTab,
+            "alarms" => Permission::Alarms,
+            "background" => Permission::Background,
+            "bookmarks" => Permission::Bookmarks,
+            "browsingData" => Permission::BrowsingData,
+            "certificateProvider" => Permission::CertificateProvider,
+            "clipboardRead" => Permission::ClipboardRead,
+            "clipboardWrite" => Permission::ClipboardWrite,
+            "contentSettings" => Permission::ContentSettings,
+            "contextMenus" => Permission::ContextMenus,
+            "cookies" => Permission::Cookies,
+            "debugger" => Permission::Debugger,
+            "declarativeContent" => Permission::DeclarativeContent,
+            "declarativeNetRequest" => Permission::DeclarativeNetRequest,
+            "declarativeNetRequestFeedback" => Permission::DeclarativeNetRequestFeedback,
+            "declarativeNetRequestWithHostAccess" => Permission::DeclarativeNetRequestWithHostAccess,
+            "desktopCapture" => Permission::DesktopCapture,
+            "dns" => Permission::Dns,
+            "documentScan" => Permission::DocumentScan,
+            "downloads" => Permission::Downloads,
+            "downloads.open" => Permission::DownloadsOpen,
+            "downloads.ui" => Permission::DownloadsUi,
+            "enterprise.deviceAttributes" => Permission::EnterpriseDeviceAttributes,
+            "enterprise.hardwarePlatform" => Permission::EnterpriseHardwarePlatform,
+            "enterprise.networkingAttributes" => Permission::EnterpriseNetworkingAttributes,
+            "enterprise.platformKeys" => Permission::EnterprisePlatformKeys,
+            "favicon" => Permission::Favicon,
+            "fileBrowserHandler" => Permission::FileBrowserHandler,
+            "fileSystemProvider" => Permission::FileSystemProvider,
+            "fontSettings" => Permission::FontSettings,
+            "gcm" => Permission::Gcm,
+            "geolocation" => Permission::Geolocation,
+            "history" => Permission::History,
+            "identity" => Permission::Identity,
+            "identity.email" => Permission::IdentityEmail,
+            "idle" => Permission::Idle,
+            "loginState" => Permission::LoginState,
+            "management" => Permission::Management,
+            "nativeMessaging" => Permission::NativeMessaging,
+            "notifications" => Permission::Notifications,
+            "offscreen" => Permission::Offscreen,
+            "pageCapture" => Permission::PageCapture,
+            "platformKeys" => Permission::PlatformKeys,
+            "power" => Permission::Power,
+            "printerProvider" => Permission::PrinterProvider,
+            "printing" => Permission::Printing,
+            "printingMetrics" => Permission::PrintingMetrics,
+            "privacy" => Permission::Privacy,
+            "processes" => Permission::Processes,
+            "proxy" => Permission::Proxy,
+            "readingList" => Permission::ReadingList,
+            "runtime" => Permission::Runtime,
+            "scripting" => Permission::Scripting,
+            "search" => Permission::Search,
+            "sessions" => Permission::Sessions,
+            "sidePanel" => Permission::SidePanel,
+            "storage" => Permission::Storage,
+            "system.cpu" => Permission::SystemCpu,
+            "system.display" => Permission::SystemDisplay,
+            "system.memory" => Permission::SystemMemory,
+            "system.storage" => Permission::SystemStorage,
+            "tabCapture" => Permission::TabCapture,
+            "tabGroups" => Permission::TabGroups,
+            "tabs" => Permission::Tabs,
+            "topSites" => Permission::TopSites,
+            "tts" => Permission::Tts,
+            "ttsEngine" => Permission::TtsEngine,
+            "unlimitedStorage" => Permission::UnlimitedStorage,
+            "userScripts" => Permission::UserScripts,
+            "vpnProvider" => Permission::VpnProvider,
+            "wallpaper" => Permission::Wallpaper,
+            "webAuthenticationProxy" => Permission::WebAuthenticationProxy,
+            "webNavigation" => Permission::WebNavigation,
+            "webRequest" => Permission::WebRequest,
+            "webRequestBlocking" => Permission::WebRequestBlocking,
+            other => Permission::Other(other.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "permissions"], js_name = request)]
+    fn _request(permissions: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "permissions"], js_name = contains)]
+    fn _contains(permissions: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "permissions"], js_name = remove)]
+    fn _remove(permissions: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "permissions"], js_name = getAll)]
+    fn _get_all() -> Promise;
+}
+
+pub async fn request(permissions: &Permissions) -> Result<bool, Error> {
+    resolve(_request(serde_wasm_bindgen::to_value(permissions)?)).await
+}
+
+pub async fn contains(permissions: &Permissions) -> Result<bool, Error> {
+    resolve(_contains(serde_wasm_bindgen::to_value(permissions)?)).await
+}
+
+pub async fn remove(permissions: &Permissions) -> Result<bool, Error> {
+    resolve(_remove(serde_wasm_bindgen::to_value(permissions)?)).await
+}
+
+pub async fn get_all() -> Result<Permissions, Error> {
+    resolve(_get_all()).await
+}
+
+pub mod on_added {
+    use wasm_bindgen::prelude::*;
+    use super::Permissions;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "permissions", "onAdded"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "permissions"], js_name = onAdded)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(Permissions) + 'static,
+    {
+        Closure::wrap(Box::new(move |permissions: JsValue| {
+            callback(event_arg!(permissions));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(Permissions) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<Permissions> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_removed {
+    use wasm_bindgen::prelude::*;
+    use super::Permissions;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "permissions", "onRemoved"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "permissions"], js_name = onRemoved)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(Permissions) + 'static,
+    {
+        Closure::wrap(Box::new(move |permissions: JsValue| {
+            callback(event_arg!(permissions));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(Permissions) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<Permissions> {
+        stream_of(listen)
+    }
+}
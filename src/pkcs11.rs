@@ -0,0 +1,57 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenInfo {
+    pub name: String,
+    pub manufacturer: String,
+    #[serde(rename = "HWVersion")]
+    pub hw_version: String,
+    #[serde(rename = "FWVersion")]
+    pub fw_version: String,
+    pub serial: String,
+    pub is_logged_in: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotInfo {
+    pub name: String,
+    /// `None` if the slot is empty.
+    pub token: Option<TokenInfo>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "pkcs11"], js_name = getModuleSlots)]
+    fn _get_module_slots(name: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "pkcs11"], js_name = installModule)]
+    fn _install_module(name: &str, flags: Option<i32>) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "pkcs11"], js_name = uninstallModule)]
+    fn _uninstall_module(name: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "pkcs11"], js_name = isModuleInstalled)]
+    fn _is_module_installed(name: &str) -> Promise;
+}
+
+pub async fn get_module_slots(name: &str) -> Result<Vec<SlotInfo>, Error> {
+    resolve(_get_module_slots(name)).await
+}
+
+pub async fn install_module(name: &str, flags: Option<i32>) -> Result<(), Error> {
+    resolve(_install_module(name, flags)).await
+}
+
+pub async fn uninstall_module(name: &str) -> Result<(), Error> {
+    resolve(_uninstall_module(name)).await
+}
+
+pub async fn is_module_installed(name: &str) -> Result<bool, Error> {
+    resolve(_is_module_installed(name)).await
+}
@@ -0,0 +1,154 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use js_sys::{Array, Function, Object, Reflect};
+use serde::{Serialize, Deserialize};
+use serde_wasm_bindgen::Serializer;
+use web_sys::{CryptoKey, SubtleCrypto};
+use crate::utils::{from_array_buffer, promise_from_callback, to_array_buffer};
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ClientCertificateType {
+    RsaSign,
+    EcdsaSign,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientCertificateRequest {
+    pub certificate_types: Vec<ClientCertificateType>,
+    /// DER-encoded distinguished names of the accepted certificate authorities.
+    pub certificate_authorities: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelectDetails {
+    pub request: ClientCertificateRequest,
+    pub client_certs: Option<Vec<Vec<u8>>>,
+    pub interactive: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Match {
+    pub certificate: Vec<u8>,
+    pub key_algorithm: Object,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyPair {
+    pub public_key: CryptoKey,
+    pub private_key: Option<CryptoKey>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationDetails {
+    pub server_certificate_chain: Vec<Vec<u8>>,
+    pub hostname: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationResult {
+    pub trusted: bool,
+    pub debug_errors: Vec<String>,
+}
+
+fn buffers(items: &[Vec<u8>]) -> Array {
+    items.iter().map(|item| to_array_buffer(item)).collect()
+}
+
+impl SelectDetails {
+    fn to_js(&self) -> Result<JsValue, Error> {
+        let request = Object::new();
+        Reflect::set(&request, &"certificateTypes".into(), &serde_wasm_bindgen::to_value(&self.request.certificate_types)?)?;
+        Reflect::set(&request, &"certificateAuthorities".into(), &buffers(&self.request.certificate_authorities))?;
+
+        let details = Object::new();
+        Reflect::set(&details, &"request".into(), &request)?;
+        Reflect::set(&details, &"interactive".into(), &self.interactive.into())?;
+        if let Some(client_certs) = &self.client_certs {
+            Reflect::set(&details, &"clientCerts".into(), &buffers(client_certs))?;
+        }
+
+        Ok(details.into())
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "platformKeys"], js_name = selectClientCertificates)]
+    fn _select_client_certificates(details: JsValue, callback: &Function);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "platformKeys"], js_name = getKeyPair)]
+    fn _get_key_pair(certificate: &JsValue, parameters: JsValue, callback: &JsValue);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "platformKeys"], js_name = getKeyPairBySpki)]
+    fn _get_key_pair_by_spki(public_key_spki_der: &JsValue, parameters: JsValue, callback: &JsValue);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "platformKeys"], js_name = subtleCrypto)]
+    fn _subtle_crypto() -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "platformKeys"], js_name = verifyTLSServerCertificate)]
+    fn _verify_tls_server_certificate(details: &JsValue, callback: &Function);
+}
+
+pub async fn select_client_certificates(details: &SelectDetails) -> Result<Vec<Match>, Error> {
+    let details = details.to_js()?;
+    let matches: Array = JsFuture::from(promise_from_callback(|callback| _select_client_certificates(details, &callback)))
+        .await?
+        .dyn_into()?;
+
+    matches
+        .iter()
+        .map(|m| Ok(Match {
+            certificate: from_array_buffer(&Reflect::get(&m, &"certificate".into())?),
+            key_algorithm: Reflect::get(&m, &"keyAlgorithm".into())?.unchecked_into(),
+        }))
+        .collect()
+}
+
+async fn key_pair_from<F: FnOnce(&JsValue)>(call: F) -> Result<KeyPair, Error> {
+    let pair: Array = JsFuture::from(promise_from_callback(|resolve| {
+        let callback = Closure::once_into_js(move |public_key: JsValue, private_key: JsValue| {
+            let _ = resolve.call1(&JsValue::NULL, &Array::of2(&public_key, &private_key));
+        });
+
+        call(&callback);
+    })).await?.dyn_into()?;
+
+    Ok(KeyPair {
+        public_key: pair.get(0).unchecked_into(),
+        private_key: pair.get(1).dyn_into().ok(),
+    })
+}
+
+/// `parameters` is a WebCrypto algorithm dictionary, such as
+/// `{ name: "RSASSA-PKCS1-v1_5", hash: { name: "SHA-256" } }`.
+pub async fn get_key_pair<P: Serialize>(certificate: &[u8], parameters: &P) -> Result<KeyPair, Error> {
+    let certificate = to_array_buffer(certificate).into();
+    let parameters = parameters.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+
+    key_pair_from(|callback| _get_key_pair(&certificate, parameters, callback)).await
+}
+
+pub async fn get_key_pair_by_spki<P: Serialize>(public_key_spki_der: &[u8], parameters: &P) -> Result<KeyPair, Error> {
+    let public_key_spki_der = to_array_buffer(public_key_spki_der).into();
+    let parameters = parameters.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+
+    key_pair_from(|callback| _get_key_pair_by_spki(&public_key_spki_der, parameters, callback)).await
+}
+
+/// Returns `None` when the platform keys API is unavailable in this context.
+pub fn subtle_crypto() -> Option<SubtleCrypto> {
+    _subtle_crypto().dyn_into().ok()
+}
+
+pub async fn verify_tls_server_certificate(details: &VerificationDetails) -> Result<VerificationResult, Error> {
+    let js_details = Object::new();
+    Reflect::set(&js_details, &"serverCertificateChain".into(), &buffers(&details.server_certificate_chain))?;
+    Reflect::set(&js_details, &"hostname".into(), &details.hostname.as_str().into())?;
+    let js_details = js_details.into();
+
+    let result = JsFuture::from(promise_from_callback(|callback| _verify_tls_server_certificate(&js_details, &callback))).await?;
+
+    Ok(serde_wasm_bindgen::from_value(result)?)
+}
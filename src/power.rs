@@ -0,0 +1,41 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Level {
+    System,
+    Display,
+}
+
+impl Level {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Level::System => "system",
+            Level::Display => "display",
+        }
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "power"], js_name = requestKeepAwake)]
+    fn _request_keep_awake(level: &str);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "power"], js_name = releaseKeepAwake)]
+    pub fn release_keep_awake();
+
+    #[wasm_bindgen(js_namespace = ["chrome", "power"], js_name = reportActivity)]
+    fn _report_activity() -> Promise;
+}
+
+pub fn request_keep_awake(level: Level) {
+    _request_keep_awake(level.as_str());
+}
+
+pub async fn report_activity() -> Result<(), Error> {
+    resolve(_report_activity()).await
+}
@@ -0,0 +1,240 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Function, Reflect};
+use serde::{Serialize, Deserialize};
+use serde_wasm_bindgen::Serializer;
+use crate::printing::{PrintJob, Ticket};
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrinterInfo {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PrintError {
+    Ok,
+    Failed,
+    InvalidTicket,
+    InvalidData,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsbDevice {
+    pub device: i32,
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub version: u16,
+    pub product_name: String,
+    pub manufacturer_name: String,
+    pub serial_number: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrintJobFields {
+    printer_id: String,
+    title: String,
+    ticket: Ticket,
+    content_type: String,
+}
+
+fn print_job_from_js(value: &JsValue) -> Result<PrintJob, Error> {
+    let fields: PrintJobFields = serde_wasm_bindgen::from_value(value.clone())?;
+    let document = Reflect::get(value, &"document".into())?;
+
+    Ok(PrintJob {
+        printer_id: fields.printer_id,
+        title: fields.title,
+        ticket: fields.ticket,
+        content_type: fields.content_type,
+        document: document.unchecked_into(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SendPrinters(Function);
+
+impl SendPrinters {
+    pub fn send(&self, printers: &[PrinterInfo]) -> Result<(), Error> {
+        self.0.call1(&JsValue::NULL, &serde_wasm_bindgen::to_value(printers)?)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SendCapabilities(Function);
+
+impl SendCapabilities {
+    /// Sends the printer's capabilities, described in CDD format.
+    pub fn send<T: Serialize>(&self, capabilities: &T) -> Result<(), Error> {
+        let capabilities = capabilities.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+        self.0.call1(&JsValue::NULL, &capabilities)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SendPrintResult(Function);
+
+impl SendPrintResult {
+    pub fn send(&self, result: PrintError) -> Result<(), Error> {
+        self.0.call1(&JsValue::NULL, &serde_wasm_bindgen::to_value(&result)?)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SendUsbPrinterInfo(Function);
+
+impl SendUsbPrinterInfo {
+    pub fn send(&self, printer_info: Option<&PrinterInfo>) -> Result<(), Error> {
+        self.0.call1(&JsValue::NULL, &serde_wasm_bindgen::to_value(&printer_info)?)?;
+
+        Ok(())
+    }
+}
+
+pub mod on_get_printers_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::SendPrinters;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "printerProvider", "onGetPrintersRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "printerProvider"], js_name = onGetPrintersRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(Function)>
+        where T: FnMut(SendPrinters) + 'static,
+    {
+        Closure::wrap(Box::new(move |result_callback| {
+            callback(SendPrinters(result_callback));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(SendPrinters) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<SendPrinters> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_get_capability_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::SendCapabilities;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "printerProvider", "onGetCapabilityRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "printerProvider"], js_name = onGetCapabilityRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, Function)>
+        where T: FnMut(String, SendCapabilities) + 'static,
+    {
+        Closure::wrap(Box::new(move |printer_id, result_callback| {
+            callback(printer_id, SendCapabilities(result_callback));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, SendCapabilities) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, SendCapabilities)> {
+        stream_of(|mut callback| listen(move |printer_id, result_callback| callback((printer_id, result_callback))))
+    }
+}
+
+pub mod on_print_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use crate::printing::PrintJob;
+    use super::{print_job_from_js, SendPrintResult};
+    use crate::events::{Event, EventHandle, EventStream, event_arg, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "printerProvider", "onPrintRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "printerProvider"], js_name = onPrintRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function)>
+        where T: FnMut(PrintJob, SendPrintResult) + 'static,
+    {
+        Closure::wrap(Box::new(move |print_job: JsValue, result_callback| {
+            callback(event_arg!(print_job, print_job_from_js), SendPrintResult(result_callback));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(PrintJob, SendPrintResult) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(PrintJob, SendPrintResult)> {
+        stream_of(|mut callback| listen(move |print_job, result_callback| callback((print_job, result_callback))))
+    }
+}
+
+pub mod on_get_usb_printer_info_requested {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{SendUsbPrinterInfo, UsbDevice};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "printerProvider", "onGetUsbPrinterInfoRequested"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "printerProvider"], js_name = onGetUsbPrinterInfoRequested)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Function)>
+        where T: FnMut(UsbDevice, SendUsbPrinterInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |device: JsValue, result_callback| {
+            callback(event_arg!(device), SendUsbPrinterInfo(result_callback));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(UsbDevice, SendUsbPrinterInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(UsbDevice, SendUsbPrinterInfo)> {
+        stream_of(|mut callback| listen(move |device, result_callback| callback((device, result_callback))))
+    }
+}
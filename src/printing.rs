@@ -0,0 +1,364 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use js_sys::{Object, Promise, Reflect};
+use serde::{Serialize, Deserialize};
+use serde_wasm_bindgen::Serializer;
+use web_sys::Blob;
+use crate::utils::{create_object_with_property, resolve};
+use crate::error::Error;
+
+pub const MAX_SUBMIT_JOB_CALLS_PER_MINUTE: u32 = 40;
+pub const MAX_GET_PRINTER_INFO_CALLS_PER_MINUTE: u32 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PrinterSource {
+    User,
+    Policy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PrinterStatus {
+    DoorOpen,
+    TrayMissing,
+    OutOfInk,
+    OutOfPaper,
+    OutputFull,
+    PaperJam,
+    GenericIssue,
+    Stopped,
+    Unreachable,
+    ExpiredCertificate,
+    Available,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum JobStatus {
+    Pending,
+    InProgress,
+    Failed,
+    Canceled,
+    Printed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubmitJobStatus {
+    Ok,
+    UserRejected,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Printer {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub uri: String,
+    pub source: PrinterSource,
+    pub is_default: bool,
+    pub recently_used_rank: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrinterInfo {
+    /// The printer's capabilities in CDD format, if the printer is reachable.
+    pub capabilities: Option<Object>,
+    pub status: PrinterStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitJobResponse {
+    pub status: SubmitJobStatus,
+    pub job_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ColorType {
+    StandardColor,
+    StandardMonochrome,
+    CustomColor,
+    CustomMonochrome,
+    Auto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DuplexType {
+    NoDuplex,
+    LongEdge,
+    ShortEdge,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrientationType {
+    Portrait,
+    Landscape,
+    AutoOrientation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColorTicketItem {
+    #[serde(rename = "type")]
+    pub color_type: ColorType,
+    pub vendor_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DuplexTicketItem {
+    #[serde(rename = "type")]
+    pub duplex_type: DuplexType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageOrientationTicketItem {
+    #[serde(rename = "type")]
+    pub orientation_type: OrientationType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CopiesTicketItem {
+    pub copies: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DpiTicketItem {
+    pub horizontal_dpi: u32,
+    pub vertical_dpi: u32,
+    pub vendor_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaSizeTicketItem {
+    pub width_microns: u32,
+    pub height_microns: u32,
+    pub is_continuous_feed: Option<bool>,
+    pub vendor_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollateTicketItem {
+    pub collate: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VendorTicketItem {
+    pub id: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrintTicketSection {
+    pub vendor_ticket_item: Option<Vec<VendorTicketItem>>,
+    pub color: Option<ColorTicketItem>,
+    pub duplex: Option<DuplexTicketItem>,
+    pub page_orientation: Option<PageOrientationTicketItem>,
+    pub copies: Option<CopiesTicketItem>,
+    pub dpi: Option<DpiTicketItem>,
+    pub media_size: Option<MediaSizeTicketItem>,
+    pub collate: Option<CollateTicketItem>,
+}
+
+/// A Cloud Job Ticket describing how a job should be printed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ticket {
+    pub version: String,
+    pub print: PrintTicketSection,
+}
+
+impl Default for Ticket {
+    fn default() -> Self {
+        Self {
+            version: "1.0".to_owned(),
+            print: PrintTicketSection::default(),
+        }
+    }
+}
+
+impl Ticket {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn color(mut self, color_type: ColorType) -> Self {
+        self.print.color = Some(ColorTicketItem { color_type, vendor_id: None });
+        self
+    }
+
+    pub fn duplex(mut self, duplex_type: DuplexType) -> Self {
+        self.print.duplex = Some(DuplexTicketItem { duplex_type });
+        self
+    }
+
+    pub fn orientation(mut self, orientation_type: OrientationType) -> Self {
+        self.print.page_orientation = Some(PageOrientationTicketItem { orientation_type });
+        self
+    }
+
+    pub fn copies(mut self, copies: u32) -> Self {
+        self.print.copies = Some(CopiesTicketItem { copies });
+        self
+    }
+
+    pub fn dpi(mut self, horizontal_dpi: u32, vertical_dpi: u32) -> Self {
+        self.print.dpi = Some(DpiTicketItem { horizontal_dpi, vertical_dpi, vendor_id: None });
+        self
+    }
+
+    pub fn media_size(mut self, width_microns: u32, height_microns: u32, vendor_id: Option<&str>) -> Self {
+        self.print.media_size = Some(MediaSizeTicketItem {
+            width_microns,
+            height_microns,
+            is_continuous_feed: None,
+            vendor_id: vendor_id.map(str::to_owned),
+        });
+        self
+    }
+
+    pub fn collate(mut self, collate: bool) -> Self {
+        self.print.collate = Some(CollateTicketItem { collate });
+        self
+    }
+
+    pub fn vendor_item(mut self, id: &str, value: &str) -> Self {
+        self.print.vendor_ticket_item
+            .get_or_insert_with(Vec::new)
+            .push(VendorTicketItem { id: id.to_owned(), value: value.to_owned() });
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PrintJob {
+    pub printer_id: String,
+    pub title: String,
+    pub ticket: Ticket,
+    pub content_type: String,
+    pub document: Blob,
+}
+
+impl PrintJob {
+    pub fn new(printer_id: &str, title: &str, ticket: Ticket, content_type: &str, document: Blob) -> Self {
+        Self {
+            printer_id: printer_id.to_owned(),
+            title: title.to_owned(),
+            ticket,
+            content_type: content_type.to_owned(),
+            document,
+        }
+    }
+
+    /// Wraps a PDF document held in memory.
+    pub fn pdf(printer_id: &str, title: &str, ticket: Ticket, bytes: &[u8]) -> Result<Self, Error> {
+        let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type("application/pdf");
+        let document = Blob::new_with_u8_array_sequence_and_options(&parts, &options)?;
+
+        Ok(Self::new(printer_id, title, ticket, "application/pdf", document))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PrintJobFields<'a> {
+    printer_id: &'a str,
+    title: &'a str,
+    ticket: &'a Ticket,
+    content_type: &'a str,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "printing"], js_name = getPrinters)]
+    fn _get_printers() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "printing"], js_name = getPrinterInfo)]
+    fn _get_printer_info(printer_id: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "printing"], js_name = submitJob)]
+    fn _submit_job(request: &Object) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "printing"], js_name = cancelJob)]
+    fn _cancel_job(job_id: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "printing"], js_name = getJobStatus)]
+    fn _get_job_status(job_id: &str) -> Promise;
+}
+
+pub async fn get_printers() -> Result<Vec<Printer>, Error> {
+    resolve(_get_printers()).await
+}
+
+pub async fn get_printer_info(printer_id: &str) -> Result<PrinterInfo, Error> {
+    let response = JsFuture::from(_get_printer_info(printer_id)).await?;
+
+    let capabilities = Reflect::get(&response, &"capabilities".into())?;
+    let status = Reflect::get(&response, &"status".into())?;
+
+    Ok(PrinterInfo {
+        capabilities: capabilities.dyn_into().ok(),
+        status: serde_wasm_bindgen::from_value(status)?,
+    })
+}
+
+pub async fn submit_job(job: &PrintJob) -> Result<SubmitJobResponse, Error> {
+    let fields = PrintJobFields {
+        printer_id: &job.printer_id,
+        title: &job.title,
+        ticket: &job.ticket,
+        content_type: &job.content_type,
+    };
+    let js_job = fields.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+    Reflect::set(&js_job, &"document".into(), &job.document)?;
+
+    let request = create_object_with_property("job".to_owned(), js_job)?;
+
+    resolve(_submit_job(&request)).await
+}
+
+pub async fn cancel_job(job_id: &str) -> Result<(), Error> {
+    resolve(_cancel_job(job_id)).await
+}
+
+pub async fn get_job_status(job_id: &str) -> Result<JobStatus, Error> {
+    resolve(_get_job_status(job_id)).await
+}
+
+pub mod on_job_status_changed {
+    use wasm_bindgen::prelude::*;
+    use super::JobStatus;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "printing", "onJobStatusChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "printing"], js_name = onJobStatusChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, JsValue)>
+        where T: FnMut(String, JobStatus) + 'static,
+    {
+        Closure::wrap(Box::new(move |job_id, status: JsValue| {
+            callback(job_id, event_arg!(status));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, JobStatus) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, JobStatus)> {
+        stream_of(|mut callback| listen(move |job_id, status| callback((job_id, status))))
+    }
+}
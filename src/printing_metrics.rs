@@ -0,0 +1,123 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::printing::{PrinterSource, PrinterStatus};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ColorMode {
+    BlackAndWhite,
+    Color,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DuplexMode {
+    OneSided,
+    TwoSidedLongEdge,
+    TwoSidedShortEdge,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PrintJobSource {
+    PrintPreview,
+    AndroidApp,
+    Extension,
+    IsolatedWebApp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PrintJobStatus {
+    Failed,
+    Canceled,
+    Printed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaSize {
+    pub width: u32,
+    pub height: u32,
+    pub vendor_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintSettings {
+    pub color: ColorMode,
+    pub duplex: DuplexMode,
+    pub media_size: MediaSize,
+    pub copies: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Printer {
+    pub name: String,
+    pub uri: String,
+    pub source: PrinterSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintJobInfo {
+    pub id: String,
+    pub title: String,
+    pub source: PrintJobSource,
+    pub source_id: Option<String>,
+    pub status: PrintJobStatus,
+    pub creation_time: f64,
+    pub completion_time: f64,
+    pub printer: Printer,
+    #[serde(rename = "printer_status")]
+    pub printer_status: PrinterStatus,
+    pub settings: PrintSettings,
+    pub number_of_pages: u32,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "printingMetrics"], js_name = getPrintJobs)]
+    fn _get_print_jobs() -> Promise;
+}
+
+pub async fn get_print_jobs() -> Result<Vec<PrintJobInfo>, Error> {
+    resolve(_get_print_jobs()).await
+}
+
+pub mod on_print_job_finished {
+    use wasm_bindgen::prelude::*;
+    use super::PrintJobInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "printingMetrics", "onPrintJobFinished"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "printingMetrics"], js_name = onPrintJobFinished)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(PrintJobInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |job_info: JsValue| {
+            callback(event_arg!(job_info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(PrintJobInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<PrintJobInfo> {
+        stream_of(listen)
+    }
+}
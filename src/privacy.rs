@@ -0,0 +1,110 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IPHandlingPolicy {
+    Default,
+    DefaultPublicAndPrivateInterfaces,
+    DefaultPublicInterfaceOnly,
+    DisableNonProxiedUdp,
+}
+
+pub mod network {
+    use crate::types::ChromeSetting;
+    use super::IPHandlingPolicy;
+    use crate::error::Error;
+
+    pub fn network_prediction_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.network.networkPredictionEnabled")
+    }
+
+    pub fn web_rtc_ip_handling_policy() -> Result<ChromeSetting<IPHandlingPolicy>, Error> {
+        ChromeSetting::from_path("privacy.network.webRTCIPHandlingPolicy")
+    }
+}
+
+pub mod services {
+    use crate::types::ChromeSetting;
+    use crate::error::Error;
+
+    pub fn alternate_error_pages_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.services.alternateErrorPagesEnabled")
+    }
+
+    pub fn autofill_address_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.services.autofillAddressEnabled")
+    }
+
+    pub fn autofill_credit_card_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.services.autofillCreditCardEnabled")
+    }
+
+    pub fn autofill_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.services.autofillEnabled")
+    }
+
+    pub fn password_saving_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.services.passwordSavingEnabled")
+    }
+
+    pub fn safe_browsing_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.services.safeBrowsingEnabled")
+    }
+
+    pub fn safe_browsing_extended_reporting_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.services.safeBrowsingExtendedReportingEnabled")
+    }
+
+    pub fn search_suggest_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.services.searchSuggestEnabled")
+    }
+
+    pub fn spelling_service_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.services.spellingServiceEnabled")
+    }
+
+    pub fn translation_service_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.services.translationServiceEnabled")
+    }
+}
+
+pub mod websites {
+    use crate::types::ChromeSetting;
+    use crate::error::Error;
+
+    pub fn ad_measurement_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.websites.adMeasurementEnabled")
+    }
+
+    pub fn do_not_track_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.websites.doNotTrackEnabled")
+    }
+
+    pub fn fledge_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.websites.fledgeEnabled")
+    }
+
+    pub fn hyperlink_auditing_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.websites.hyperlinkAuditingEnabled")
+    }
+
+    pub fn protected_content_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.websites.protectedContentEnabled")
+    }
+
+    pub fn referrers_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.websites.referrersEnabled")
+    }
+
+    pub fn related_website_sets_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.websites.relatedWebsiteSetsEnabled")
+    }
+
+    pub fn third_party_cookies_allowed() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.websites.thirdPartyCookiesAllowed")
+    }
+
+    pub fn topics_enabled() -> Result<ChromeSetting<bool>, Error> {
+        ChromeSetting::from_path("privacy.websites.topicsEnabled")
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessType {
+    Browser,
+    Renderer,
+    Extension,
+    Notification,
+    Plugin,
+    Worker,
+    Nacl,
+    ServiceWorker,
+    Utility,
+    Gpu,
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskInfo {
+    pub title: String,
+    pub tab_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cache {
+    pub size: f64,
+    pub live_size: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Process {
+    pub id: i32,
+    pub os_process_id: i32,
+    #[serde(rename = "type")]
+    pub process_type: ProcessType,
+    pub profile: String,
+    pub nacl_debug_port: i32,
+    pub tasks: Vec<TaskInfo>,
+    pub cpu: Option<f64>,
+    pub network: Option<f64>,
+    pub private_memory: Option<f64>,
+    pub js_memory_allocated: Option<f64>,
+    pub js_memory_used: Option<f64>,
+    pub sqlite_memory: Option<f64>,
+    pub image_cache: Option<Cache>,
+    pub script_cache: Option<Cache>,
+    pub css_cache: Option<Cache>,
+}
+
+/// Process dictionaries are keyed by the stringified process ID.
+fn process_map(processes: BTreeMap<String, Process>) -> BTreeMap<i32, Process> {
+    processes
+        .into_iter()
+        .filter_map(|(id, process)| id.parse().ok().map(|id| (id, process)))
+        .collect()
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "processes"], js_name = getProcessIdForTab)]
+    fn _get_process_id_for_tab(tab_id: i32) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "processes"], js_name = terminate)]
+    fn _terminate(process_id: i32) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "processes"], js_name = getProcessInfo)]
+    fn _get_process_info(process_ids: JsValue, include_memory: bool) -> Promise;
+}
+
+pub async fn get_process_id_for_tab(tab_id: i32) -> Result<i32, Error> {
+    resolve(_get_process_id_for_tab(tab_id)).await
+}
+
+pub async fn terminate(process_id: i32) -> Result<bool, Error> {
+    resolve(_terminate(process_id)).await
+}
+
+/// Pass an empty slice to get information about every process.
+pub async fn get_process_info(process_ids: &[i32], include_memory: bool) -> Result<BTreeMap<i32, Process>, Error> {
+    let processes = resolve(_get_process_info(serde_wasm_bindgen::to_value(process_ids)?, include_memory)).await?;
+
+    Ok(process_map(processes))
+}
+
+pub mod on_updated {
+    use std::collections::BTreeMap;
+    use wasm_bindgen::prelude::*;
+    use super::{process_map, Process};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "processes", "onUpdated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "processes"], js_name = onUpdated)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(BTreeMap<i32, Process>) + 'static,
+    {
+        Closure::wrap(Box::new(move |processes: JsValue| {
+            callback(process_map(event_arg!(processes)));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(BTreeMap<i32, Process>) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<BTreeMap<i32, Process>> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_updated_with_memory {
+    use std::collections::BTreeMap;
+    use wasm_bindgen::prelude::*;
+    use super::{process_map, Process};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "processes", "onUpdatedWithMemory"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "processes"], js_name = onUpdatedWithMemory)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(BTreeMap<i32, Process>) + 'static,
+    {
+        Closure::wrap(Box::new(move |processes: JsValue| {
+            callback(process_map(event_arg!(processes)));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(BTreeMap<i32, Process>) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<BTreeMap<i32, Process>> {
+        stream_of(listen)
+    }
+}
@@ -0,0 +1,103 @@
+use serde::{Serialize, Deserialize};
+use crate::types::ChromeSetting;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+    Direct,
+    AutoDetect,
+    PacScript,
+    FixedServers,
+    System,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scheme {
+    Http,
+    Https,
+    Quic,
+    Socks4,
+    Socks5,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyServer {
+    pub scheme: Option<Scheme>,
+    pub host: String,
+    pub port: Option<u16>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyRules {
+    pub single_proxy: Option<ProxyServer>,
+    pub proxy_for_http: Option<ProxyServer>,
+    pub proxy_for_https: Option<ProxyServer>,
+    pub proxy_for_ftp: Option<ProxyServer>,
+    pub fallback_proxy: Option<ProxyServer>,
+    pub bypass_list: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PacScript {
+    pub url: Option<String>,
+    pub data: Option<String>,
+    pub mandatory: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyConfig {
+    pub mode: Mode,
+    pub rules: Option<ProxyRules>,
+    pub pac_script: Option<PacScript>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyErrorDetails {
+    pub fatal: bool,
+    pub error: String,
+    pub details: String,
+}
+
+pub fn settings() -> Result<ChromeSetting<ProxyConfig>, Error> {
+    ChromeSetting::from_path("proxy.settings")
+}
+
+pub mod on_proxy_error {
+    use wasm_bindgen::prelude::*;
+    use super::ProxyErrorDetails;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "proxy", "onProxyError"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "proxy"], js_name = onProxyError)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ProxyErrorDetails) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ProxyErrorDetails) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ProxyErrorDetails> {
+        stream_of(listen)
+    }
+}
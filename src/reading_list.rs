@@ -0,0 +1,175 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingListEntry {
+    pub url: String,
+    pub title: String,
+    pub has_been_read: bool,
+    pub creation_time: f64,
+    pub last_update_time: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddEntryOptions {
+    pub url: String,
+    pub title: String,
+    pub has_been_read: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveOptions {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEntryOptions {
+    pub url: String,
+    pub title: Option<String>,
+    pub has_been_read: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryInfo {
+    pub url: Option<String>,
+    pub title: Option<String>,
+    pub has_been_read: Option<bool>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "readingList"], js_name = addEntry)]
+    fn _add_entry(entry: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "readingList"], js_name = removeEntry)]
+    fn _remove_entry(info: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "readingList"], js_name = updateEntry)]
+    fn _update_entry(info: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "readingList"], js_name = query)]
+    fn _query(info: JsValue) -> Promise;
+}
+
+pub async fn add_entry(entry: &AddEntryOptions) -> Result<(), Error> {
+    resolve(_add_entry(serde_wasm_bindgen::to_value(entry)?)).await
+}
+
+pub async fn remove_entry(info: &RemoveOptions) -> Result<(), Error> {
+    resolve(_remove_entry(serde_wasm_bindgen::to_value(info)?)).await
+}
+
+pub async fn update_entry(info: &UpdateEntryOptions) -> Result<(), Error> {
+    resolve(_update_entry(serde_wasm_bindgen::to_value(info)?)).await
+}
+
+pub async fn query(info: &QueryInfo) -> Result<Vec<ReadingListEntry>, Error> {
+    resolve(_query(serde_wasm_bindgen::to_value(info)?)).await
+}
+
+pub mod on_entry_added {
+    use wasm_bindgen::prelude::*;
+    use super::ReadingListEntry;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "readingList", "onEntryAdded"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "readingList"], js_name = onEntryAdded)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ReadingListEntry) + 'static,
+    {
+        Closure::wrap(Box::new(move |entry: JsValue| {
+            callback(event_arg!(entry));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ReadingListEntry) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ReadingListEntry> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_entry_removed {
+    use wasm_bindgen::prelude::*;
+    use super::ReadingListEntry;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "readingList", "onEntryRemoved"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "readingList"], js_name = onEntryRemoved)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ReadingListEntry) + 'static,
+    {
+        Closure::wrap(Box::new(move |entry: JsValue| {
+            callback(event_arg!(entry));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ReadingListEntry) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ReadingListEntry> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_entry_updated {
+    use wasm_bindgen::prelude::*;
+    use super::ReadingListEntry;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "readingList", "onEntryUpdated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "readingList"], js_name = onEntryUpdated)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ReadingListEntry) + 'static,
+    {
+        Closure::wrap(Box::new(move |entry: JsValue| {
+            callback(event_arg!(entry));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ReadingListEntry) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ReadingListEntry> {
+        stream_of(listen)
+    }
+}
@@ -0,0 +1,638 @@
+//! Typed services over `runtime` ports.
+//!
+//! Declare a service as a trait with `#[rpc::service]`. The background
+//! implements it and calls `<Name>Server::listen`, and other contexts call it
+//! through the generated `<Name>Client`:
+//!
+//! ```ignore
+//! #[rpc::service]
+//! pub trait Counter {
+//!     async fn add(&self, amount: i32) -> i32;
+//!     fn ticks(&self, every_ms: u32) -> rpc::BoxStream<u32>;
+//! }
+//! ```
+//!
+//! Methods returning a `BoxStream` send their items back one frame at a time.
+//! A client whose port closed, e.g. because the service worker was stopped,
+//! reconnects on its next call. Calls in flight at that point fail with
+//! `Error::Disconnected` rather than being retried.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+use std::task::{Context, Poll, Waker};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use js_sys::Reflect;
+use futures_core::Stream;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use crate::events::EventHandle;
+use crate::future::{with_cancellation, CancellationToken};
+use crate::runtime::{connect, on_connect, ConnectInfo, Port};
+use crate::utils::to_json_value;
+use crate::error::Error;
+
+pub use web_extension_sys_macros::service;
+
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+
+pub type BoxStream<T> = Pin<Box<dyn Stream<Item = T>>>;
+
+/// What a service method produces, before it is sent back to the caller.
+pub enum Reply {
+    Unary(BoxFuture<Result<JsValue, Error>>),
+    Stream(BoxStream<Result<JsValue, Error>>),
+}
+
+/// Routes a request to a service method. Implemented by the generated `<Name>Server`.
+pub trait Dispatch {
+    fn dispatch(self: Rc<Self>, method: &str, payload: JsValue) -> Result<Reply, Error>;
+}
+
+/// Ports serialize messages as JSON, so maps have to be plain objects.
+pub fn encode<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, Error> {
+    to_json_value(value)
+}
+
+pub fn decode<T: DeserializeOwned>(value: JsValue) -> Result<T, Error> {
+    Ok(serde_wasm_bindgen::from_value(value)?)
+}
+
+pub fn encode_stream<T: Serialize + 'static>(stream: BoxStream<T>) -> BoxStream<Result<JsValue, Error>> {
+    struct Encode<T>(BoxStream<T>);
+
+    impl<T: Serialize> Stream for Encode<T> {
+        type Item = Result<JsValue, Error>;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.0.as_mut().poll_next(cx).map(|item| item.map(|item| encode(&item)))
+        }
+    }
+
+    Box::pin(Encode(stream))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum FrameKind {
+    Request,
+    Response,
+    Item,
+    End,
+    Error,
+    Cancel,
+}
+
+/// Every frame is this object, plus a `payload` property for requests, responses and items.
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    id: u32,
+    kind: FrameKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    method: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl Header {
+    fn new(id: u32, kind: FrameKind) -> Self {
+        Self { id, kind, method: None, error: None }
+    }
+
+    fn error(id: u32, error: &Error) -> Self {
+        let message = error.message().map(str::to_owned).unwrap_or_else(|| error.to_string());
+
+        Self { error: Some(message), ..Self::new(id, FrameKind::Error) }
+    }
+}
+
+fn post(port: &Port, header: &Header, payload: &JsValue) -> Result<(), Error> {
+    let frame = serde_wasm_bindgen::to_value(header)?;
+
+    if !payload.is_undefined() {
+        Reflect::set(&frame, &"payload".into(), payload)?;
+    }
+
+    port.send_value(&frame)
+}
+
+fn read(frame: JsValue) -> Option<(Header, JsValue)> {
+    let payload = Reflect::get(&frame, &"payload".into()).ok()?;
+
+    Some((serde_wasm_bindgen::from_value(frame).ok()?, payload))
+}
+
+/// Defers dropping listeners, since one of them may be the closure currently running.
+fn drop_later(listeners: Vec<EventHandle>) {
+    spawn_local(async move { drop(listeners) });
+}
+
+/// The service answering a name's ports.
+enum Slot {
+    Ready(Rc<dyn Dispatch>),
+    /// Frames that arrived before the service was ready, in the order they arrived.
+    Waiting(Vec<(Weak<RefCell<Connection>>, JsValue)>),
+    /// The service was given up on before it was ready.
+    Abandoned,
+}
+
+struct Connection {
+    port: Port,
+    slot: Rc<RefCell<Slot>>,
+    streams: HashMap<u32, CancellationToken>,
+    listeners: Vec<EventHandle>,
+}
+
+fn listen(name: &str, slot: Rc<RefCell<Slot>>) -> EventHandle {
+    let name = name.to_owned();
+
+    on_connect::listen(move |port| {
+        if port.name() == name {
+            open(port, slot.clone());
+        }
+    })
+}
+
+/// Answers requests on every port connected under `name`. Used by the generated `<Name>Server::listen`.
+pub fn serve<D: Dispatch + 'static>(name: &str, service: D) -> EventHandle {
+    listen(name, Rc::new(RefCell::new(Slot::Ready(Rc::new(service)))))
+}
+
+/// Like `serve`, for a service that isn't ready yet, e.g. because it is still
+/// loading its state. It listens as soon as it is created, so a context that
+/// connects in the meantime isn't missed, and holds its requests until `start`.
+///
+/// Requests held when it is dropped without being started fail, as do later ones.
+pub struct Deferred {
+    slot: Rc<RefCell<Slot>>,
+    _listener: EventHandle,
+}
+
+impl Deferred {
+    pub fn new(name: &str) -> Self {
+        let slot = Rc::new(RefCell::new(Slot::Waiting(Vec::new())));
+
+        Self {
+            _listener: listen(name, slot.clone()),
+            slot,
+        }
+    }
+
+    /// Answers the requests held so far, then every later one, with `service`.
+    pub fn start<D: Dispatch + 'static>(&self, service: D) {
+        self.settle(Slot::Ready(Rc::new(service)));
+    }
+
+    /// Replaces a waiting slot with `service`, then handles the held frames again.
+    fn settle(&self, service: Slot) {
+        let held = {
+            let mut slot = self.slot.borrow_mut();
+
+            match &*slot {
+                Slot::Waiting(_) => std::mem::replace(&mut *slot, service),
+                _ => return,
+            }
+        };
+
+        if let Slot::Waiting(frames) = held {
+            for (connection, frame) in frames {
+                if let Some(connection) = connection.upgrade() {
+                    handle_request(&connection, frame);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Deferred {
+    fn drop(&mut self) {
+        self.settle(Slot::Abandoned);
+    }
+}
+
+fn open(port: Port, slot: Rc<RefCell<Slot>>) {
+    let connection = Rc::new(RefCell::new(Connection {
+        port: port.clone(),
+        slot,
+        streams: HashMap::new(),
+        listeners: Vec::new(),
+    }));
+
+    let weak = Rc::downgrade(&connection);
+    let on_message = port.listen_message(move |frame| {
+        if let Some(connection) = weak.upgrade() {
+            handle_request(&connection, frame);
+        }
+    });
+
+    // Holds the connection alive until the other side goes away.
+    let strong = connection.clone();
+    let on_disconnect = port.listen_disconnect(move |_| {
+        let mut connection = strong.borrow_mut();
+
+        for cancelled in connection.streams.values() {
+            cancelled.cancel();
+        }
+
+        drop_later(std::mem::take(&mut connection.listeners));
+    });
+
+    connection.borrow_mut().listeners = vec![on_message, on_disconnect];
+}
+
+fn handle_request(connection: &Rc<RefCell<Connection>>, frame: JsValue) {
+    let (port, slot) = {
+        let connection = connection.borrow();
+        (connection.port.clone(), connection.slot.clone())
+    };
+    let service = match &mut *slot.borrow_mut() {
+        Slot::Ready(service) => Some(service.clone()),
+        Slot::Waiting(frames) => {
+            frames.push((Rc::downgrade(connection), frame));
+            return;
+        }
+        Slot::Abandoned => None,
+    };
+    let (header, payload) = match read(frame) {
+        Some(frame) => frame,
+        None => return,
+    };
+    let id = header.id;
+
+    match header.kind {
+        FrameKind::Request => {
+            let service = match service {
+                Some(service) => service,
+                None => {
+                    let error = Error::runtime("the service stopped before it was ready");
+                    let _ = post(&port, &Header::error(id, &error), &JsValue::UNDEFINED);
+                    return;
+                }
+            };
+
+            match service.dispatch(header.method.as_deref().unwrap_or_default(), payload) {
+                Ok(Reply::Unary(future)) => spawn_local(async move {
+                    let _ = match future.await {
+                        Ok(value) => post(&port, &Header::new(id, FrameKind::Response), &value),
+                        Err(error) => post(&port, &Header::error(id, &error), &JsValue::UNDEFINED),
+                    };
+                }),
+                Ok(Reply::Stream(stream)) => {
+                    let cancelled = CancellationToken::new();
+                    connection.borrow_mut().streams.insert(id, cancelled.clone());
+
+                    let connection = Rc::downgrade(connection);
+                    spawn_local(async move {
+                        pump(&port, id, stream, &cancelled).await;
+
+                        if let Some(connection) = connection.upgrade() {
+                            connection.borrow_mut().streams.remove(&id);
+                        }
+                    });
+                }
+                Err(error) => {
+                    let _ = post(&port, &Header::error(id, &error), &JsValue::UNDEFINED);
+                }
+            }
+        }
+        FrameKind::Cancel => {
+            if let Some(cancelled) = connection.borrow_mut().streams.remove(&id) {
+                cancelled.cancel();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Sends the stream's items until it ends or is cancelled. Cancelling drops
+/// the stream straight away, even while it is waiting for its next item.
+async fn pump(port: &Port, id: u32, mut stream: BoxStream<Result<JsValue, Error>>, cancelled: &CancellationToken) {
+    loop {
+        let next = std::future::poll_fn(|cx| stream.as_mut().poll_next(cx));
+
+        let item = match with_cancellation(cancelled, async { Ok(next.await) }).await {
+            Ok(Some(item)) => item,
+            Ok(None) => break,
+            Err(_) => return,
+        };
+
+        // An error ends the stream.
+        let (sent, done) = match item {
+            Ok(value) => (post(port, &Header::new(id, FrameKind::Item), &value), false),
+            Err(error) => (post(port, &Header::error(id, &error), &JsValue::UNDEFINED), true),
+        };
+
+        if sent.is_err() || done {
+            return;
+        }
+    }
+
+    let _ = post(port, &Header::new(id, FrameKind::End), &JsValue::UNDEFINED);
+}
+
+#[derive(Default)]
+struct CallState {
+    result: Option<Result<JsValue, Error>>,
+    waker: Option<Waker>,
+}
+
+#[derive(Default)]
+struct StreamState {
+    items: VecDeque<Result<JsValue, Error>>,
+    done: bool,
+    waker: Option<Waker>,
+}
+
+enum Pending {
+    Call(Rc<RefCell<CallState>>),
+    Stream(Rc<RefCell<StreamState>>),
+}
+
+impl Pending {
+    fn fail(self, error: Error) {
+        let waker = match self {
+            Pending::Call(call) => {
+                let mut call = call.borrow_mut();
+                call.result = Some(Err(error));
+                call.waker.take()
+            }
+            Pending::Stream(stream) => {
+                let mut stream = stream.borrow_mut();
+                stream.items.push_back(Err(error));
+                stream.done = true;
+                stream.waker.take()
+            }
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+struct ClientState {
+    name: String,
+    port: Option<Port>,
+    listeners: Vec<EventHandle>,
+    next_id: u32,
+    pending: HashMap<u32, Pending>,
+}
+
+impl Drop for ClientState {
+    fn drop(&mut self) {
+        if let Some(port) = &self.port {
+            port.disconnect();
+        }
+    }
+}
+
+/// The untyped side of a generated `<Name>Client`. Clones share one port.
+#[derive(Clone)]
+pub struct Client {
+    state: Rc<RefCell<ClientState>>,
+}
+
+impl Client {
+    /// Nothing connects until the first call.
+    pub fn new(name: &str) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(ClientState {
+                name: name.to_owned(),
+                port: None,
+                listeners: Vec::new(),
+                next_id: 0,
+                pending: HashMap::new(),
+            })),
+        }
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.state.borrow().port.is_some()
+    }
+
+    /// Closes the port, failing calls in flight. The next call opens a new one.
+    pub fn disconnect(&self) {
+        let port = self.state.borrow().port.clone();
+
+        if let Some(port) = port {
+            port.disconnect();
+            disconnected(&self.state);
+        }
+    }
+
+    fn port(&self) -> Result<Port, Error> {
+        if let Some(port) = &self.state.borrow().port {
+            return Ok(port.clone());
+        }
+
+        let name = self.state.borrow().name.clone();
+        let port = connect(None, &ConnectInfo { name: Some(name), ..ConnectInfo::default() })?;
+
+        let weak = Rc::downgrade(&self.state);
+        let on_message = port.listen_message(move |frame| {
+            if let Some(state) = weak.upgrade() {
+                handle_response(&state, frame);
+            }
+        });
+
+        let weak = Rc::downgrade(&self.state);
+        let on_disconnect = port.listen_disconnect(move |_| {
+            if let Some(state) = weak.upgrade() {
+                disconnected(&state);
+            }
+        });
+
+        let mut state = self.state.borrow_mut();
+        state.port = Some(port.clone());
+        state.listeners = vec![on_message, on_disconnect];
+
+        Ok(port)
+    }
+
+    fn request<A: Serialize + ?Sized>(&self, method: &str, args: &A, pending: Pending) -> Result<u32, Error> {
+        let port = self.port()?;
+        let payload = encode(args)?;
+
+        let id = {
+            let mut state = self.state.borrow_mut();
+            let id = state.next_id;
+            state.next_id = id.wrapping_add(1);
+            state.pending.insert(id, pending);
+            id
+        };
+
+        let header = Header { method: Some(method.to_owned()), ..Header::new(id, FrameKind::Request) };
+
+        if let Err(error) = post(&port, &header, &payload) {
+            self.state.borrow_mut().pending.remove(&id);
+            return Err(error);
+        }
+
+        Ok(id)
+    }
+
+    /// Calls a method with its arguments as a tuple.
+    pub async fn call<A, R>(&self, method: &str, args: &A) -> Result<R, Error>
+        where A: Serialize + ?Sized,
+              R: DeserializeOwned,
+    {
+        let call = Rc::new(RefCell::new(CallState::default()));
+        let id = self.request(method, args, Pending::Call(call.clone()))?;
+
+        let response = ResponseFuture { state: self.state.clone(), id, call }.await?;
+
+        decode(response)
+    }
+
+    /// Calls a streaming method. Dropping the stream early cancels it on the server.
+    pub fn call_stream<A, R>(&self, method: &str, args: &A) -> Result<ResponseStream<R>, Error>
+        where A: Serialize + ?Sized,
+              R: DeserializeOwned,
+    {
+        let stream = Rc::new(RefCell::new(StreamState::default()));
+        let id = self.request(method, args, Pending::Stream(stream.clone()))?;
+
+        Ok(ResponseStream {
+            state: self.state.clone(),
+            id,
+            stream,
+            _item: PhantomData,
+        })
+    }
+}
+
+fn disconnected(state: &Rc<RefCell<ClientState>>) {
+    let (pending, listeners) = {
+        let mut state = state.borrow_mut();
+        state.port = None;
+        (std::mem::take(&mut state.pending), std::mem::take(&mut state.listeners))
+    };
+
+    for pending in pending.into_values() {
+        pending.fail(Error::Disconnected);
+    }
+
+    drop_later(listeners);
+}
+
+fn handle_response(state: &Rc<RefCell<ClientState>>, frame: JsValue) {
+    let (header, payload) = match read(frame) {
+        Some(frame) => frame,
+        None => return,
+    };
+    let id = header.id;
+
+    let pending = match header.kind {
+        FrameKind::Item => match state.borrow().pending.get(&id) {
+            Some(Pending::Stream(stream)) => Pending::Stream(stream.clone()),
+            _ => return,
+        },
+        FrameKind::Response | FrameKind::End | FrameKind::Error => match state.borrow_mut().pending.remove(&id) {
+            Some(pending) => pending,
+            None => return,
+        },
+        _ => return,
+    };
+
+    if let Some(message) = header.error {
+        pending.fail(Error::from_message(&message));
+        return;
+    }
+
+    let waker = match pending {
+        Pending::Call(call) => {
+            let mut call = call.borrow_mut();
+            call.result = Some(Ok(payload));
+            call.waker.take()
+        }
+        Pending::Stream(stream) => {
+            let mut stream = stream.borrow_mut();
+
+            match header.kind {
+                FrameKind::Item => stream.items.push_back(Ok(payload)),
+                _ => stream.done = true,
+            }
+
+            stream.waker.take()
+        }
+    };
+
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+struct ResponseFuture {
+    state: Rc<RefCell<ClientState>>,
+    id: u32,
+    call: Rc<RefCell<CallState>>,
+}
+
+impl Future for ResponseFuture {
+    type Output = Result<JsValue, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut call = self.call.borrow_mut();
+
+        match call.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                call.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for ResponseFuture {
+    fn drop(&mut self) {
+        self.state.borrow_mut().pending.remove(&self.id);
+    }
+}
+
+/// The items of a streaming call, as they arrive.
+pub struct ResponseStream<R> {
+    state: Rc<RefCell<ClientState>>,
+    id: u32,
+    stream: Rc<RefCell<StreamState>>,
+    _item: PhantomData<fn() -> R>,
+}
+
+impl<R: DeserializeOwned> Stream for ResponseStream<R> {
+    type Item = Result<R, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut stream = self.stream.borrow_mut();
+
+        match stream.items.pop_front() {
+            Some(item) => Poll::Ready(Some(item.and_then(decode))),
+            None if stream.done => Poll::Ready(None),
+            None => {
+                stream.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<R> Drop for ResponseStream<R> {
+    fn drop(&mut self) {
+        if self.stream.borrow().done {
+            return;
+        }
+
+        let port = {
+            let mut state = self.state.borrow_mut();
+            state.pending.remove(&self.id);
+            state.port.clone()
+        };
+
+        if let Some(port) = port {
+            let _ = post(&port, &Header::new(self.id, FrameKind::Cancel), &JsValue::UNDEFINED);
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Function, Object, Reflect};
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use crate::tabs::Tab;
+use crate::utils::{resolve, to_json_value};
+use crate::events::{Event, EventHandle};
+use crate::error::Error;
+
+pub use web_extension_sys_macros::ExtensionMessage;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageSender {
+    pub tab: Option<Tab>,
+    pub frame_id: Option<i32>,
+    pub id: Option<String>,
+    pub url: Option<String>,
+    pub origin: Option<String>,
+    pub document_id: Option<String>,
+    pub native_application: Option<String>,
+    pub tls_channel_id: Option<String>,
+    pub user_script_world_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SendResponse(Function);
+
+impl SendResponse {
+    pub fn send<T: Serialize>(&self, response: &T) -> Result<(), Error> {
+        self.send_value(&to_json_value(response)?)
+    }
+
+    pub fn send_value(&self, response: &JsValue) -> Result<(), Error> {
+        self.0.call1(&JsValue::NULL, response)?;
+
+        Ok(())
+    }
+
+    /// Answers with a `Reply`, so the sender of an `ExtensionMessage` hears about failures too.
+    #[doc(hidden)]
+    pub fn send_result(&self, result: Result<JsValue, Error>) -> Result<(), Error> {
+        let (status, value) = match result {
+            Ok(value) => ("ok", value),
+            Err(error) => ("error", error.message().map(str::to_owned).unwrap_or_else(|| error.to_string()).into()),
+        };
+        // The browser drops `undefined` properties, and `Reply` needs the key, e.g. for a `()` response.
+        let value = if value.is_undefined() { JsValue::NULL } else { value };
+        let reply = Object::new();
+        Reflect::set(&reply, &"status".into(), &status.into())?;
+        Reflect::set(&reply, &"value".into(), &value)?;
+
+        self.send_value(&reply)
+    }
+}
+
+/// What `ExtensionMessage` listeners answer with.
+#[doc(hidden)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "value", rename_all = "camelCase")]
+pub enum Reply<T> {
+    Ok(T),
+    Error(String),
+}
+
+impl<T> Reply<T> {
+    pub fn into_result(self) -> Result<T, Error> {
+        match self {
+            Reply::Ok(value) => Ok(value),
+            Reply::Error(message) => Err(Error::from_message(&message)),
+        }
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "runtime"], js_name = sendMessage)]
+    fn _send_message(message: JsValue) -> Promise;
+}
+
+pub async fn send_message<M, R>(message: &M) -> Result<R, Error>
+    where M: Serialize,
+          R: DeserializeOwned,
+{
+    resolve(_send_message(to_json_value(message)?)).await
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectInfo {
+    pub name: Option<String>,
+    pub include_tls_channel_id: Option<bool>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// A long-lived connection to another context, or to a native application.
+    #[derive(Debug, Clone)]
+    pub type Port;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &Port) -> String;
+
+    #[wasm_bindgen(method, getter, js_name = sender)]
+    fn _sender(this: &Port) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = postMessage)]
+    pub fn post_message(this: &Port, message: &JsValue);
+
+    #[wasm_bindgen(catch, method, js_name = postMessage)]
+    fn _post_message(this: &Port, message: &JsValue) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method)]
+    pub fn disconnect(this: &Port);
+
+    #[wasm_bindgen(method, getter, js_name = onMessage)]
+    pub fn on_message(this: &Port) -> Event;
+
+    #[wasm_bindgen(method, getter, js_name = onDisconnect)]
+    pub fn on_disconnect(this: &Port) -> Event;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "runtime"], js_name = connect)]
+    fn _connect(extension_id: Option<&str>, connect_info: JsValue) -> Port;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "runtime"], js_name = connectNative)]
+    pub fn connect_native(application: &str) -> Port;
+}
+
+impl Port {
+    /// Only set on ports passed to `on_connect` listeners.
+    pub fn sender(&self) -> Option<MessageSender> {
+        serde_wasm_bindgen::from_value(self._sender()).ok()
+    }
+
+    pub fn send<T: Serialize>(&self, message: &T) -> Result<(), Error> {
+        self.send_value(&to_json_value(message)?)
+    }
+
+    /// Like `post_message`, but fails instead of throwing once the port is disconnected.
+    pub fn send_value(&self, message: &JsValue) -> Result<(), Error> {
+        Ok(self._post_message(message)?)
+    }
+
+    pub fn listen_message<T>(&self, mut callback: T) -> EventHandle
+        where T: FnMut(JsValue) + 'static,
+    {
+        let listener = Closure::wrap(Box::new(move |message, _port: JsValue| {
+            callback(message);
+        }) as Box<dyn FnMut(JsValue, JsValue)>);
+
+        EventHandle::new(self.on_message(), listener)
+    }
+
+    /// The callback gets the disconnect reason from `runtime.lastError`, if any.
+    pub fn listen_disconnect<T>(&self, mut callback: T) -> EventHandle
+        where T: FnMut(Option<Error>) + 'static,
+    {
+        let listener = Closure::wrap(Box::new(move |_port: JsValue| {
+            callback(Error::last_error());
+        }) as Box<dyn FnMut(JsValue)>);
+
+        EventHandle::new(self.on_disconnect(), listener)
+    }
+}
+
+/// Pass `None` as `extension_id` to connect to this extension.
+pub fn connect(extension_id: Option<&str>, connect_info: &ConnectInfo) -> Result<Port, Error> {
+    Ok(_connect(extension_id, serde_wasm_bindgen::to_value(connect_info)?))
+}
+
+pub mod on_connect {
+    use wasm_bindgen::prelude::*;
+    use super::Port;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "runtime", "onConnect"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(Port)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "runtime"], js_name = onConnect)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(Port)>
+        where T: FnMut(Port) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(Port) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<Port> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_message {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{MessageSender, SendResponse};
+    use crate::events::{Event, EventHandle, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "runtime", "onMessage"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, JsValue, Function) -> bool>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "runtime"], js_name = onMessage)]
+        static EVENT: Event;
+    }
+
+    /// The callback returns `true` to keep `send_response` valid after it returns.
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, JsValue, Function) -> bool>
+        where T: FnMut(JsValue, MessageSender, SendResponse) -> bool + 'static,
+    {
+        Closure::wrap(Box::new(move |message, sender: JsValue, send_response| {
+            callback(message, event_arg!(sender), SendResponse(send_response))
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(JsValue, MessageSender, SendResponse) -> bool + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+}
+
+pub mod on_user_script_message {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{MessageSender, SendResponse};
+    use crate::events::{Event, EventHandle, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "runtime", "onUserScriptMessage"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, JsValue, Function) -> bool>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "runtime"], js_name = onUserScriptMessage)]
+        static EVENT: Event;
+    }
+
+    /// The callback returns `true` to keep `send_response` valid after it returns.
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, JsValue, Function) -> bool>
+        where T: FnMut(JsValue, MessageSender, SendResponse) -> bool + 'static,
+    {
+        Closure::wrap(Box::new(move |message, sender: JsValue, send_response| {
+            callback(message, event_arg!(sender), SendResponse(send_response))
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(JsValue, MessageSender, SendResponse) -> bool + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reply;
+
+    #[test]
+    fn unit_reply_round_trips() {
+        let reply: Reply<()> = serde_json::from_str(r#"{"status":"ok","value":null}"#).unwrap();
+
+        assert_eq!(reply, Reply::Ok(()));
+    }
+
+    #[test]
+    fn reply_carries_errors() {
+        let reply: Reply<u32> = serde_json::from_str(r#"{"status":"error","value":"no handler"}"#).unwrap();
+
+        assert_eq!(reply, Reply::Error("no handler".to_owned()));
+    }
+}
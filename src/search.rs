@@ -0,0 +1,31 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Disposition {
+    CurrentTab,
+    NewTab,
+    NewWindow,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryInfo {
+    pub text: String,
+    pub disposition: Option<Disposition>,
+    pub tab_id: Option<i32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "search"], js_name = query)]
+    fn _query(query_info: JsValue) -> Promise;
+}
+
+pub async fn query(query_info: &QueryInfo) -> Result<(), Error> {
+    resolve(_query(serde_wasm_bindgen::to_value(query_info)?)).await
+}
@@ -0,0 +1,84 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::tabs::Tab;
+use crate::windows::Window;
+use crate::utils::resolve;
+use crate::error::Error;
+
+pub const MAX_SESSION_RESULTS: u32 = 25;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Filter {
+    pub max_results: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Session {
+    pub last_modified: f64,
+    pub tab: Option<Tab>,
+    pub window: Option<Window>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Device {
+    pub device_name: String,
+    pub sessions: Vec<Session>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "sessions"], js_name = getRecentlyClosed)]
+    fn _get_recently_closed(filter: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sessions"], js_name = getDevices)]
+    fn _get_devices(filter: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sessions"], js_name = restore)]
+    fn _restore(session_id: Option<&str>) -> Promise;
+}
+
+pub async fn get_recently_closed(filter: &Filter) -> Result<Vec<Session>, Error> {
+    resolve(_get_recently_closed(serde_wasm_bindgen::to_value(filter)?)).await
+}
+
+pub async fn get_devices(filter: &Filter) -> Result<Vec<Device>, Error> {
+    resolve(_get_devices(serde_wasm_bindgen::to_value(filter)?)).await
+}
+
+pub async fn restore(session_id: Option<&str>) -> Result<Session, Error> {
+    resolve(_restore(session_id)).await
+}
+
+pub mod on_changed {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "sessions", "onChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "sessions"], js_name = onChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
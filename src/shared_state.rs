@@ -0,0 +1,413 @@
+//! A value owned by the background and kept in sync across popups, options
+//! pages and content scripts.
+//!
+//! The background opens the value with `Synced::own`, which loads it from a
+//! storage area and serves it over a port. Every other context opens it with
+//! `Synced::connect`, and sends its updates to the background rather than
+//! writing storage itself. The background applies updates one at a time in
+//! the order they arrive, saves the result, and broadcasts each update to
+//! every connected context, so no write is lost.
+//!
+//! Every context applies the same updates to its own copy, so `State::apply`
+//! must be deterministic.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use futures_core::Stream;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use crate::future::sleep;
+use crate::rpc::{self, Client, Deferred, Dispatch, Reply, ResponseStream};
+use crate::storage;
+use crate::error::Error;
+
+/// A value that changes through updates which can be sent between contexts.
+pub trait State: Clone + Serialize + DeserializeOwned + 'static {
+    type Update: Clone + Serialize + DeserializeOwned + 'static;
+
+    fn apply(&mut self, update: Self::Update);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Area {
+    Local,
+    Sync,
+    Session,
+}
+
+impl Area {
+    async fn load<T: DeserializeOwned>(self, key: &str) -> Result<T, Error> {
+        match self {
+            Area::Local => storage::local::get_key(key).await,
+            Area::Sync => storage::sync::get_key(key).await,
+            Area::Session => storage::session::get_key(key).await,
+        }
+    }
+
+    async fn store<T: Serialize>(self, key: &str, value: &T) -> Result<(), Error> {
+        let data = HashMap::from([(key, value)]);
+
+        match self {
+            Area::Local => storage::local::set(&data).await,
+            Area::Sync => storage::sync::set(&data).await,
+            Area::Session => storage::session::set(&data).await,
+        }
+    }
+}
+
+fn service_name(key: &str) -> String {
+    format!("shared_state:{}", key)
+}
+
+/// How long a replica waits after each failed attempt to reach the background
+/// again. It gives up after the last one.
+const RETRY_DELAYS_MS: [u64; 5] = [100, 500, 2_000, 5_000, 10_000];
+
+/// What the background sends to each connected context: the whole value once,
+/// then every update in the order it was applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Frame<T, U> {
+    Snapshot(T),
+    Update(U),
+}
+
+struct Channel<T> {
+    items: VecDeque<T>,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+/// The changes to a shared value, as they happen. Ends when the `Synced` it
+/// came from is dropped, or when a replica can no longer reach the background.
+pub struct Changes<T> {
+    channel: Rc<RefCell<Channel<T>>>,
+}
+
+impl<T> Stream for Changes<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut channel = self.channel.borrow_mut();
+
+        match channel.items.pop_front() {
+            Some(item) => Poll::Ready(Some(item)),
+            None if channel.closed => Poll::Ready(None),
+            None => {
+                channel.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+struct Subscribers<T> {
+    channels: Vec<Weak<RefCell<Channel<T>>>>,
+}
+
+impl<T: Clone> Subscribers<T> {
+    fn new() -> Self {
+        Self { channels: Vec::new() }
+    }
+
+    fn subscribe(&mut self, first: Option<T>) -> Changes<T> {
+        let channel = Rc::new(RefCell::new(Channel {
+            items: first.into_iter().collect(),
+            closed: false,
+            waker: None,
+        }));
+        self.channels.push(Rc::downgrade(&channel));
+
+        Changes { channel }
+    }
+
+    fn send(&mut self, item: &T) {
+        self.channels.retain(|channel| match channel.upgrade() {
+            Some(channel) => {
+                let mut channel = channel.borrow_mut();
+                channel.items.push_back(item.clone());
+
+                if let Some(waker) = channel.waker.take() {
+                    waker.wake();
+                }
+
+                true
+            }
+            None => false,
+        });
+    }
+
+    fn close(&mut self) {
+        for channel in self.channels.drain(..).filter_map(|channel| channel.upgrade()) {
+            let mut channel = channel.borrow_mut();
+            channel.closed = true;
+
+            if let Some(waker) = channel.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+struct Shared<T> {
+    value: T,
+    changes: Subscribers<T>,
+}
+
+impl<T: Clone> Shared<T> {
+    fn new(value: T) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self { value, changes: Subscribers::new() }))
+    }
+
+    fn changed(&mut self) {
+        let value = self.value.clone();
+        self.changes.send(&value);
+    }
+}
+
+struct Owner<T: State> {
+    key: String,
+    area: Area,
+    shared: Rc<RefCell<Shared<T>>>,
+    frames: RefCell<Subscribers<Frame<T, T::Update>>>,
+}
+
+impl<T: State> Owner<T> {
+    /// Applies and broadcasts `update` straight away, so updates keep the
+    /// order they arrived in. The returned future saves the new value.
+    fn apply(&self, update: T::Update) -> impl Future<Output = Result<(), Error>> {
+        let value = {
+            let mut shared = self.shared.borrow_mut();
+            shared.value.apply(update.clone());
+            shared.changed();
+            shared.value.clone()
+        };
+
+        self.frames.borrow_mut().send(&Frame::Update(update));
+
+        let (key, area) = (self.key.clone(), self.area);
+
+        async move { area.store(&key, &value).await }
+    }
+}
+
+struct Service<T: State>(Rc<Owner<T>>);
+
+impl<T: State> Dispatch for Service<T> {
+    fn dispatch(self: Rc<Self>, method: &str, payload: JsValue) -> Result<Reply, Error> {
+        match method {
+            "update" => {
+                let (update,): (T::Update,) = rpc::decode(payload)?;
+                let saved = self.0.apply(update);
+
+                Ok(Reply::Unary(Box::pin(async move {
+                    saved.await?;
+                    rpc::encode(&())
+                })))
+            }
+            "subscribe" => {
+                let snapshot = Frame::Snapshot(self.0.shared.borrow().value.clone());
+                let frames = self.0.frames.borrow_mut().subscribe(Some(snapshot));
+
+                Ok(Reply::Stream(rpc::encode_stream(Box::pin(frames))))
+            }
+            _ => Err(Error::runtime(format!("shared state has no method {}", method))),
+        }
+    }
+}
+
+enum Role<T: State> {
+    Owner {
+        owner: Rc<Owner<T>>,
+        _server: Deferred,
+    },
+    Replica(Client),
+}
+
+/// One context's handle on a shared value.
+pub struct Synced<T: State> {
+    key: String,
+    shared: Rc<RefCell<Shared<T>>>,
+    role: Role<T>,
+}
+
+impl<T: State> Synced<T> {
+    /// Takes ownership of the value stored under `key`, storing `initial` if
+    /// there is none yet. Call this from the background, once per key.
+    ///
+    /// It starts listening for other contexts when called, before the value
+    /// has loaded, so the connection that woke the service worker isn't
+    /// missed. Their requests are answered once the value has loaded.
+    pub fn own(key: &str, area: Area, initial: T) -> impl Future<Output = Result<Self, Error>> {
+        let server = Deferred::new(&service_name(key));
+        let key = key.to_owned();
+
+        async move {
+            let value = match area.load(&key).await {
+                Ok(value) => value,
+                Err(Error::NoSuchKey(_)) => {
+                    area.store(&key, &initial).await?;
+                    initial
+                }
+                Err(error) => return Err(error),
+            };
+
+            let shared = Shared::new(value);
+            let owner = Rc::new(Owner {
+                key: key.clone(),
+                area,
+                shared: shared.clone(),
+                frames: RefCell::new(Subscribers::new()),
+            });
+            server.start(Service(owner.clone()));
+
+            Ok(Self {
+                key,
+                shared,
+                role: Role::Owner { owner, _server: server },
+            })
+        }
+    }
+
+    /// Follows the value the background owns under `key`. If the background
+    /// restarts, the value is fetched again once it is serving. If it can't
+    /// be reached after a few attempts, `changes` ends and `read` keeps
+    /// returning the last value seen.
+    pub async fn connect(key: &str) -> Result<Self, Error> {
+        let client = Client::new(&service_name(key));
+        let (value, frames) = subscribe::<T>(&client).await?;
+        let shared = Shared::new(value);
+
+        spawn_local(follow(client.clone(), Rc::downgrade(&shared), frames));
+
+        Ok(Self {
+            key: key.to_owned(),
+            shared,
+            role: Role::Replica(client),
+        })
+    }
+
+    /// The latest value this context has seen.
+    pub fn read(&self) -> T {
+        self.shared.borrow().value.clone()
+    }
+
+    /// Has the background apply `update`. Resolves once it has been applied and saved.
+    pub async fn update(&self, update: T::Update) -> Result<(), Error> {
+        match &self.role {
+            Role::Owner { owner, .. } => owner.apply(update).await,
+            Role::Replica(client) => client.call("update", &(update,)).await,
+        }
+    }
+
+    /// Every later value, from updates made in any context including this one.
+    pub fn changes(&self) -> Changes<T> {
+        self.shared.borrow_mut().changes.subscribe(None)
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn is_owner(&self) -> bool {
+        matches!(self.role, Role::Owner { .. })
+    }
+}
+
+impl<T: State> Drop for Synced<T> {
+    fn drop(&mut self) {
+        self.shared.borrow_mut().changes.close();
+
+        match &self.role {
+            Role::Owner { owner, .. } => owner.frames.borrow_mut().close(),
+            Role::Replica(client) => client.disconnect(),
+        }
+    }
+}
+
+type Frames<T> = ResponseStream<Frame<T, <T as State>::Update>>;
+
+async fn next<T: State>(frames: &mut Frames<T>) -> Option<Result<Frame<T, T::Update>, Error>> {
+    std::future::poll_fn(|cx| Pin::new(&mut *frames).poll_next(cx)).await
+}
+
+async fn subscribe<T: State>(client: &Client) -> Result<(T, Frames<T>), Error> {
+    let mut frames = client.call_stream("subscribe", &())?;
+
+    match next::<T>(&mut frames).await {
+        Some(Ok(Frame::Snapshot(value))) => Ok((value, frames)),
+        Some(Err(error)) => Err(error),
+        _ => Err(Error::Disconnected),
+    }
+}
+
+/// Applies the background's updates to this context's copy until the `Synced` is dropped.
+async fn follow<T: State>(client: Client, shared: Weak<RefCell<Shared<T>>>, mut frames: Frames<T>) {
+    loop {
+        while let Some(Ok(frame)) = next::<T>(&mut frames).await {
+            let shared = match shared.upgrade() {
+                Some(shared) => shared,
+                None => return,
+            };
+            let mut shared = shared.borrow_mut();
+
+            match frame {
+                Frame::Snapshot(value) => shared.value = value,
+                Frame::Update(update) => shared.value.apply(update),
+            }
+
+            shared.changed();
+        }
+
+        if shared.strong_count() == 0 {
+            return;
+        }
+
+        // The background went away, e.g. because the service worker was
+        // stopped. Connecting again starts it, and it sends a fresh snapshot.
+        let resubscribed = resubscribe::<T>(&client, &shared).await;
+        let shared = match shared.upgrade() {
+            Some(shared) => shared,
+            None => return,
+        };
+        let mut shared = shared.borrow_mut();
+
+        match resubscribed {
+            Some((value, next_frames)) => {
+                shared.value = value;
+                shared.changed();
+
+                frames = next_frames;
+            }
+            None => {
+                shared.changes.close();
+                return;
+            }
+        }
+    }
+}
+
+/// Subscribes again, backing off between attempts. `None` once every attempt
+/// has failed, or once the `Synced` is dropped.
+async fn resubscribe<T: State>(client: &Client, shared: &Weak<RefCell<Shared<T>>>) -> Option<(T, Frames<T>)> {
+    let mut delays = RETRY_DELAYS_MS.iter();
+
+    loop {
+        if shared.strong_count() == 0 {
+            return None;
+        }
+
+        if let Ok(subscribed) = subscribe::<T>(client).await {
+            return Some(subscribed);
+        }
+
+        sleep(Duration::from_millis(*delays.next()?)).await;
+    }
+}
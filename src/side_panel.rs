@@ -0,0 +1,70 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PanelOptions {
+    pub tab_id: Option<i32>,
+    pub path: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPanelOptions {
+    pub tab_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PanelBehavior {
+    pub open_panel_on_action_click: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOptions {
+    pub window_id: Option<i32>,
+    pub tab_id: Option<i32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "sidePanel"], js_name = setOptions)]
+    fn _set_options(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidePanel"], js_name = getOptions)]
+    fn _get_options(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidePanel"], js_name = setPanelBehavior)]
+    fn _set_panel_behavior(behavior: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidePanel"], js_name = getPanelBehavior)]
+    fn _get_panel_behavior() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidePanel"], js_name = open)]
+    fn _open(options: JsValue) -> Promise;
+}
+
+pub async fn set_options(options: &PanelOptions) -> Result<(), Error> {
+    resolve(_set_options(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn get_options(options: &GetPanelOptions) -> Result<PanelOptions, Error> {
+    resolve(_get_options(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+pub async fn set_panel_behavior(behavior: &PanelBehavior) -> Result<(), Error> {
+    resolve(_set_panel_behavior(serde_wasm_bindgen::to_value(behavior)?)).await
+}
+
+pub async fn get_panel_behavior() -> Result<PanelBehavior, Error> {
+    resolve(_get_panel_behavior()).await
+}
+
+pub async fn open(options: &OpenOptions) -> Result<(), Error> {
+    resolve(_open(serde_wasm_bindgen::to_value(options)?)).await
+}
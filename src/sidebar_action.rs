@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+use js_sys::{Promise, Reflect};
+use serde::{Serialize, Deserialize};
+use serde_wasm_bindgen::Serializer;
+use crate::utils::resolve;
+use crate::error::Error;
+
+/// Selects which sidebar a call applies to. With neither field set, the
+/// global value is used.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Details {
+    pub tab_id: Option<i32>,
+    pub window_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IconPath {
+    Single(String),
+    /// Paths keyed by icon size in pixels.
+    Sizes(BTreeMap<u32, String>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetIconDetails {
+    pub path: IconPath,
+    pub tab_id: Option<i32>,
+    pub window_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsOpenDetails {
+    pub window_id: Option<i32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "sidebarAction"], js_name = setPanel)]
+    fn _set_panel(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidebarAction"], js_name = getPanel)]
+    fn _get_panel(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidebarAction"], js_name = setTitle)]
+    fn _set_title(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidebarAction"], js_name = getTitle)]
+    fn _get_title(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidebarAction"], js_name = setIcon)]
+    fn _set_icon(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidebarAction"], js_name = open)]
+    fn _open() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidebarAction"], js_name = close)]
+    fn _close() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidebarAction"], js_name = toggle)]
+    fn _toggle() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "sidebarAction"], js_name = isOpen)]
+    fn _is_open(details: JsValue) -> Promise;
+}
+
+// `None` resets the value, which Firefox expects as an explicit `null`.
+fn details_with(details: &Details, key: &str, value: Option<&str>) -> Result<JsValue, Error> {
+    let js_details = serde_wasm_bindgen::to_value(details)?;
+    let value = value.map(JsValue::from).unwrap_or(JsValue::NULL);
+    Reflect::set(&js_details, &key.into(), &value)?;
+
+    Ok(js_details)
+}
+
+pub async fn set_panel(panel: Option<&str>, details: &Details) -> Result<(), Error> {
+    resolve(_set_panel(details_with(details, "panel", panel)?)).await
+}
+
+pub async fn get_panel(details: &Details) -> Result<String, Error> {
+    resolve(_get_panel(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn set_title(title: Option<&str>, details: &Details) -> Result<(), Error> {
+    resolve(_set_title(details_with(details, "title", title)?)).await
+}
+
+pub async fn get_title(details: &Details) -> Result<String, Error> {
+    resolve(_get_title(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn set_icon(details: &SetIconDetails) -> Result<(), Error> {
+    let details = details.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+
+    resolve(_set_icon(details)).await
+}
+
+/// Must be called from a user action handler.
+pub async fn open() -> Result<(), Error> {
+    resolve(_open()).await
+}
+
+pub async fn close() -> Result<(), Error> {
+    resolve(_close()).await
+}
+
+pub async fn toggle() -> Result<(), Error> {
+    resolve(_toggle()).await
+}
+
+pub async fn is_open(details: &IsOpenDetails) -> Result<bool, Error> {
+    resolve(_is_open(serde_wasm_bindgen::to_value(details)?)).await
+}
@@ -0,0 +1,4 @@
+pub mod cpu;
+pub mod memory;
+pub mod storage;
+pub mod display;
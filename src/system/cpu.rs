@@ -0,0 +1,41 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuTime {
+    pub user: f64,
+    pub kernel: f64,
+    pub idle: f64,
+    pub total: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessorInfo {
+    pub usage: CpuTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuInfo {
+    pub num_of_processors: u32,
+    pub arch_name: String,
+    pub model_name: String,
+    pub features: Vec<String>,
+    pub processors: Vec<ProcessorInfo>,
+    pub temperatures: Vec<f64>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "system", "cpu"], js_name = getInfo)]
+    fn _get_info() -> Promise;
+}
+
+pub async fn get_info() -> Result<CpuInfo, Error> {
+    resolve(_get_info()).await
+}
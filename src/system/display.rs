@@ -0,0 +1,170 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bounds {
+    pub left: i32,
+    pub top: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Insets {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActiveState {
+    Active,
+    Inactive,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LayoutPosition {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Edid {
+    pub manufacturer_id: String,
+    pub product_id: String,
+    pub year_of_manufacture: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayMode {
+    pub width: i32,
+    pub height: i32,
+    pub width_in_native_pixels: i32,
+    pub height_in_native_pixels: i32,
+    pub device_scale_factor: f64,
+    pub refresh_rate: f64,
+    pub is_native: bool,
+    pub is_selected: bool,
+    pub is_interlaced: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayUnitInfo {
+    pub id: String,
+    pub name: String,
+    pub edid: Option<Edid>,
+    pub mirroring_source_id: String,
+    pub mirroring_destination_ids: Vec<String>,
+    pub is_primary: bool,
+    pub is_internal: bool,
+    pub is_enabled: bool,
+    pub is_unified: bool,
+    pub active_state: ActiveState,
+    pub dpi_x: f64,
+    pub dpi_y: f64,
+    pub rotation: i32,
+    pub bounds: Bounds,
+    pub overscan: Insets,
+    pub work_area: Bounds,
+    pub modes: Vec<DisplayMode>,
+    pub has_touch_support: bool,
+    pub has_accelerometer: bool,
+    pub available_display_zoom_factors: Vec<f64>,
+    pub display_zoom_factor: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayLayout {
+    pub id: String,
+    pub parent_id: String,
+    pub position: LayoutPosition,
+    pub offset: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayProperties {
+    pub is_unified: Option<bool>,
+    pub mirroring_source_id: Option<String>,
+    pub is_primary: Option<bool>,
+    pub overscan: Option<Insets>,
+    pub rotation: Option<i32>,
+    pub bounds_origin_x: Option<i32>,
+    pub bounds_origin_y: Option<i32>,
+    pub display_mode: Option<DisplayMode>,
+    pub display_zoom_factor: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetInfoFlags {
+    pub single_unified: Option<bool>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "system", "display"], js_name = getInfo)]
+    fn _get_info(flags: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "system", "display"], js_name = getDisplayLayout)]
+    fn _get_display_layout() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "system", "display"], js_name = setDisplayProperties)]
+    fn _set_display_properties(id: &str, info: JsValue) -> Promise;
+}
+
+pub async fn get_info(flags: &GetInfoFlags) -> Result<Vec<DisplayUnitInfo>, Error> {
+    resolve(_get_info(serde_wasm_bindgen::to_value(flags)?)).await
+}
+
+pub async fn get_display_layout() -> Result<Vec<DisplayLayout>, Error> {
+    resolve(_get_display_layout()).await
+}
+
+pub async fn set_display_properties(id: &str, info: &DisplayProperties) -> Result<(), Error> {
+    resolve(_set_display_properties(id, serde_wasm_bindgen::to_value(info)?)).await
+}
+
+pub mod on_display_changed {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "system", "display", "onDisplayChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "system", "display"], js_name = onDisplayChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
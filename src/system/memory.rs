@@ -0,0 +1,22 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryInfo {
+    pub capacity: f64,
+    pub available_capacity: f64,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "system", "memory"], js_name = getInfo)]
+    fn _get_info() -> Promise;
+}
+
+pub async fn get_info() -> Result<MemoryInfo, Error> {
+    resolve(_get_info()).await
+}
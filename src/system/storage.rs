@@ -0,0 +1,126 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StorageUnitType {
+    Fixed,
+    Removable,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EjectDeviceResultCode {
+    Success,
+    InUse,
+    NoSuchDevice,
+    Failure,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageUnitInfo {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub unit_type: StorageUnitType,
+    pub capacity: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageAvailableCapacityInfo {
+    pub id: String,
+    pub available_capacity: f64,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "system", "storage"], js_name = getInfo)]
+    fn _get_info() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "system", "storage"], js_name = ejectDevice)]
+    fn _eject_device(id: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "system", "storage"], js_name = getAvailableCapacity)]
+    fn _get_available_capacity(id: &str) -> Promise;
+}
+
+pub async fn get_info() -> Result<Vec<StorageUnitInfo>, Error> {
+    resolve(_get_info()).await
+}
+
+pub async fn eject_device(id: &str) -> Result<EjectDeviceResultCode, Error> {
+    resolve(_eject_device(id)).await
+}
+
+pub async fn get_available_capacity(id: &str) -> Result<StorageAvailableCapacityInfo, Error> {
+    resolve(_get_available_capacity(id)).await
+}
+
+pub mod on_attached {
+    use wasm_bindgen::prelude::*;
+    use super::StorageUnitInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "system", "storage", "onAttached"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "system", "storage"], js_name = onAttached)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(StorageUnitInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |info: JsValue| {
+            callback(event_arg!(info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(StorageUnitInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<StorageUnitInfo> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_detached {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "system", "storage", "onDetached"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "system", "storage"], js_name = onDetached)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(String)>
+        where T: FnMut(String) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<String> {
+        stream_of(listen)
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use js_sys::{Function, Object, Promise};
+use serde::{Serialize, Deserialize};
+use serde_wasm_bindgen::Serializer;
+use crate::utils::{promise_from_callback, resolve};
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TabCaptureState {
+    Pending,
+    Active,
+    Stopped,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureInfo {
+    pub tab_id: i32,
+    pub status: TabCaptureState,
+    pub fullscreen: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ConstraintValue {
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaStreamConstraint {
+    pub mandatory: BTreeMap<String, ConstraintValue>,
+    pub optional: Option<BTreeMap<String, ConstraintValue>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureOptions {
+    pub audio: Option<bool>,
+    pub video: Option<bool>,
+    pub audio_constraints: Option<MediaStreamConstraint>,
+    pub video_constraints: Option<MediaStreamConstraint>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMediaStreamOptions {
+    pub target_tab_id: Option<i32>,
+    pub consumer_tab_id: Option<i32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "tabCapture"], js_name = getMediaStreamId)]
+    fn _get_media_stream_id(options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tabCapture"], js_name = capture)]
+    fn _capture(options: JsValue, callback: &Function);
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tabCapture"], js_name = getCapturedTabs)]
+    fn _get_captured_tabs() -> Promise;
+}
+
+pub async fn get_media_stream_id(options: &GetMediaStreamOptions) -> Result<String, Error> {
+    resolve(_get_media_stream_id(serde_wasm_bindgen::to_value(options)?)).await
+}
+
+/// Resolves to the `MediaStream`, or `None` if capture failed.
+pub async fn capture(options: &CaptureOptions) -> Result<Option<Object>, Error> {
+    // Constraints are plain objects, not ES2015 Maps.
+    let options = options.serialize(&Serializer::new().serialize_maps_as_objects(true))?;
+
+    let stream = JsFuture::from(promise_from_callback(|callback| _capture(options, &callback))).await?;
+
+    if stream.is_null() || stream.is_undefined() {
+        Ok(None)
+    } else {
+        Ok(Some(stream.unchecked_into()))
+    }
+}
+
+pub async fn get_captured_tabs() -> Result<Vec<CaptureInfo>, Error> {
+    resolve(_get_captured_tabs()).await
+}
+
+pub mod on_status_changed {
+    use wasm_bindgen::prelude::*;
+    use super::CaptureInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "tabCapture", "onStatusChanged"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "tabCapture"], js_name = onStatusChanged)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(CaptureInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |info: JsValue| {
+            callback(event_arg!(info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(CaptureInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<CaptureInfo> {
+        stream_of(listen)
+    }
+}
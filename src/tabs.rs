@@ -0,0 +1,183 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use crate::windows::WindowType;
+use crate::utils::{resolve, to_json_value};
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TabStatus {
+    Unloaded,
+    Loading,
+    Complete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MutedInfoReason {
+    User,
+    Capture,
+    Extension,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MutedInfo {
+    pub muted: bool,
+    pub reason: Option<MutedInfoReason>,
+    pub extension_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tab {
+    pub id: Option<i32>,
+    pub index: i32,
+    pub window_id: i32,
+    pub opener_tab_id: Option<i32>,
+    pub group_id: Option<i32>,
+    pub active: bool,
+    pub highlighted: bool,
+    pub pinned: bool,
+    pub incognito: bool,
+    pub audible: Option<bool>,
+    pub discarded: Option<bool>,
+    pub auto_discardable: Option<bool>,
+    pub muted_info: Option<MutedInfo>,
+    pub url: Option<String>,
+    pub pending_url: Option<String>,
+    pub title: Option<String>,
+    pub fav_icon_url: Option<String>,
+    pub status: Option<TabStatus>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub session_id: Option<String>,
+    pub last_accessed: Option<f64>,
+    /// Firefox only.
+    pub cookie_store_id: Option<String>,
+    /// Firefox only.
+    pub is_article: Option<bool>,
+    /// Firefox only.
+    pub is_in_reader_mode: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProperties {
+    pub window_id: Option<i32>,
+    pub index: Option<i32>,
+    pub url: Option<String>,
+    pub active: Option<bool>,
+    pub pinned: Option<bool>,
+    pub opener_tab_id: Option<i32>,
+    /// Firefox only.
+    pub cookie_store_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryInfo {
+    pub active: Option<bool>,
+    pub pinned: Option<bool>,
+    pub audible: Option<bool>,
+    pub muted: Option<bool>,
+    pub highlighted: Option<bool>,
+    pub discarded: Option<bool>,
+    pub auto_discardable: Option<bool>,
+    pub current_window: Option<bool>,
+    pub last_focused_window: Option<bool>,
+    pub status: Option<TabStatus>,
+    pub title: Option<String>,
+    pub url: Option<Vec<String>>,
+    pub group_id: Option<i32>,
+    pub window_id: Option<i32>,
+    pub window_type: Option<WindowType>,
+    pub index: Option<i32>,
+    /// Firefox only.
+    pub cookie_store_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageSettings {
+    pub to_file_name: Option<String>,
+    /// 0 for inches, 1 for millimeters.
+    pub paper_size_unit: Option<i32>,
+    pub paper_width: Option<f64>,
+    pub paper_height: Option<f64>,
+    /// 0 for portrait, 1 for landscape.
+    pub orientation: Option<i32>,
+    pub scaling: Option<f64>,
+    pub shrink_to_fit: Option<bool>,
+    pub show_background_colors: Option<bool>,
+    pub show_background_images: Option<bool>,
+    pub edge_left: Option<f64>,
+    pub edge_right: Option<f64>,
+    pub edge_top: Option<f64>,
+    pub edge_bottom: Option<f64>,
+    pub margin_left: Option<f64>,
+    pub margin_right: Option<f64>,
+    pub margin_top: Option<f64>,
+    pub margin_bottom: Option<f64>,
+    pub header_left: Option<String>,
+    pub header_center: Option<String>,
+    pub header_right: Option<String>,
+    pub footer_left: Option<String>,
+    pub footer_center: Option<String>,
+    pub footer_right: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SaveAsPdfStatus {
+    Saved,
+    Replaced,
+    Canceled,
+    NotSaved,
+    NotReplaced,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "tabs"], js_name = create)]
+    fn _create(create_properties: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tabs"], js_name = query)]
+    fn _query(query_info: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tabs"], js_name = sendMessage)]
+    fn _send_message(tab_id: i32, message: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tabs"], js_name = saveAsPDF)]
+    fn _save_as_pdf(page_settings: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tabs"], js_name = toggleReaderMode)]
+    fn _toggle_reader_mode(tab_id: Option<i32>) -> Promise;
+}
+
+pub async fn create(create_properties: &CreateProperties) -> Result<Tab, Error> {
+    resolve(_create(serde_wasm_bindgen::to_value(create_properties)?)).await
+}
+
+pub async fn query(query_info: &QueryInfo) -> Result<Vec<Tab>, Error> {
+    resolve(_query(serde_wasm_bindgen::to_value(query_info)?)).await
+}
+
+pub async fn send_message<M, R>(tab_id: i32, message: &M) -> Result<R, Error>
+    where M: Serialize,
+          R: DeserializeOwned,
+{
+    resolve(_send_message(tab_id, to_json_value(message)?)).await
+}
+
+/// Saves the active tab as a PDF, prompting the user for a location. Firefox only.
+pub async fn save_as_pdf(page_settings: &PageSettings) -> Result<SaveAsPdfStatus, Error> {
+    resolve(_save_as_pdf(serde_wasm_bindgen::to_value(page_settings)?)).await
+}
+
+/// Toggles Reader View for the given tab, or the active tab if `None`. Firefox only.
+pub async fn toggle_reader_mode(tab_id: Option<i32>) -> Result<(), Error> {
+    resolve(_toggle_reader_mode(tab_id)).await
+}
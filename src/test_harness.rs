@@ -0,0 +1,239 @@
+//! A fake `chrome` global for wasm-level tests, e.g. under `wasm-pack test --headless --chrome`.
+//!
+//! Call [`install`] at the start of each test. It sets `globalThis.chrome` (and `browser`) to
+//! in-memory `storage`, `runtime` and `tabs` stubs that record every call. Promise-returning stubs
+//! resolve to whatever was set with [`respond`], or `undefined`.
+//! `runtime.connect` hands the other end of the port it returns to `runtime.onConnect` listeners.
+//!
+//! The fake is created once per thread and reset in place by later calls, so bindings that already
+//! looked up an event or namespace keep reaching the current test's harness.
+
+use wasm_bindgen::prelude::*;
+use js_sys::Array;
+
+#[wasm_bindgen(inline_js = r#"
+function createEvent() {
+    const listeners = [];
+    return {
+        __reset() { listeners.length = 0; },
+        addListener(callback) { listeners.push(callback); },
+        removeListener(callback) {
+            const index = listeners.indexOf(callback);
+            if (index !== -1) listeners.splice(index, 1);
+        },
+        hasListener(callback) { return listeners.includes(callback); },
+        hasListeners() { return listeners.length > 0; },
+        __emit(args) { return listeners.map((listener) => listener(...args)); },
+    };
+}
+
+function createHarness() {
+    const calls = new Map();
+    const responses = new Map();
+    const events = new Map();
+
+    const record = (path, args) => {
+        if (!calls.has(path)) calls.set(path, []);
+        calls.get(path).push(Array.from(args));
+    };
+
+    const event = (path) => {
+        const e = createEvent();
+        events.set(path, e);
+        return e;
+    };
+
+    // Supports both the callback form and the promise form.
+    const stub = (path) => function (...args) {
+        const callback = typeof args[args.length - 1] === "function" ? args.pop() : null;
+        record(path, args);
+        const response = responses.get(path);
+        const value = typeof response === "function" ? response(...args) : response;
+        if (callback) {
+            Promise.resolve(value).then(callback);
+            return undefined;
+        }
+        return Promise.resolve(value);
+    };
+
+    const onChanged = event("storage.onChanged");
+    const onConnect = event("runtime.onConnect");
+
+    // Two linked ends of a port. Messages are copied as JSON and arrive on a later task, and
+    // disconnecting one end fires the other's onDisconnect, as in the browser.
+    const portPair = (name) => {
+        const end = () => ({
+            name,
+            sender: { id: "test-extension-id" },
+            onMessage: createEvent(),
+            onDisconnect: createEvent(),
+        });
+        const ends = [end(), end()];
+        let connected = true;
+        ends.forEach((from, index) => {
+            const to = ends[1 - index];
+            from.postMessage = (message) => {
+                if (!connected) throw new Error("Attempting to use a disconnected port object");
+                const copy = JSON.parse(JSON.stringify(message));
+                setTimeout(() => { if (connected) to.onMessage.__emit([copy, to]); });
+            };
+            from.disconnect = () => {
+                if (!connected) return;
+                connected = false;
+                setTimeout(() => to.onDisconnect.__emit([to]));
+            };
+        });
+        return ends;
+    };
+
+    const area = (name) => {
+        const items = new Map();
+        const pick = (keys) => {
+            const result = {};
+            if (keys === null || keys === undefined) {
+                for (const [key, value] of items) result[key] = value;
+            } else if (typeof keys === "string" || Array.isArray(keys)) {
+                for (const key of [].concat(keys)) if (items.has(key)) result[key] = items.get(key);
+            } else {
+                for (const key of Object.keys(keys)) result[key] = items.has(key) ? items.get(key) : keys[key];
+            }
+            return result;
+        };
+        const change = (updates) => {
+            const changes = {};
+            for (const [key, newValue] of Object.entries(updates)) {
+                const oldValue = items.get(key);
+                if (newValue === undefined) items.delete(key); else items.set(key, newValue);
+                changes[key] = { oldValue, newValue };
+            }
+            onChanged.__emit([changes, name]);
+        };
+        const respond = (path, args, compute) => {
+            const callback = typeof args[args.length - 1] === "function" ? args.pop() : null;
+            record(path, args);
+            const value = compute(...args);
+            if (callback) {
+                callback(value);
+                return undefined;
+            }
+            return Promise.resolve(value);
+        };
+        const path = `storage.${name}`;
+        return {
+            get: (...args) => respond(`${path}.get`, args, (keys) => pick(keys)),
+            set: (...args) => respond(`${path}.set`, args, (data) => { change(data); }),
+            remove: (...args) => respond(`${path}.remove`, args, (keys) => {
+                change(Object.fromEntries([].concat(keys).map((key) => [key, undefined])));
+            }),
+            clear: (...args) => respond(`${path}.clear`, args, () => {
+                change(Object.fromEntries([...items.keys()].map((key) => [key, undefined])));
+            }),
+            getBytesInUse: (...args) => respond(`${path}.getBytesInUse`, args, (keys) =>
+                Object.entries(pick(keys)).reduce((total, [key, value]) => total + key.length + JSON.stringify(value).length, 0)),
+            __contents: () => pick(null),
+            __reset: () => items.clear(),
+        };
+    };
+
+    const chrome = {
+        storage: {
+            local: area("local"),
+            sync: area("sync"),
+            session: area("session"),
+            managed: area("managed"),
+            onChanged,
+        },
+        runtime: {
+            id: "test-extension-id",
+            lastError: undefined,
+            sendMessage: stub("runtime.sendMessage"),
+            connect: (...args) => {
+                record("runtime.connect", args);
+                const info = args.find((arg) => arg !== null && typeof arg === "object") || {};
+                const [client, server] = portPair(info.name ?? "");
+                setTimeout(() => onConnect.__emit([server]));
+                return client;
+            },
+            getURL: (path) => `chrome-extension://test-extension-id/${path.replace(/^\//, "")}`,
+            onMessage: event("runtime.onMessage"),
+            onConnect,
+            onInstalled: event("runtime.onInstalled"),
+            onStartup: event("runtime.onStartup"),
+        },
+        tabs: {
+            create: stub("tabs.create"),
+            query: stub("tabs.query"),
+            get: stub("tabs.get"),
+            update: stub("tabs.update"),
+            remove: stub("tabs.remove"),
+            sendMessage: stub("tabs.sendMessage"),
+            onCreated: event("tabs.onCreated"),
+            onUpdated: event("tabs.onUpdated"),
+            onRemoved: event("tabs.onRemoved"),
+            onActivated: event("tabs.onActivated"),
+        },
+    };
+
+    const reset = () => {
+        calls.clear();
+        responses.clear();
+        for (const event of events.values()) event.__reset();
+        for (const name of ["local", "sync", "session", "managed"]) chrome.storage[name].__reset();
+        chrome.runtime.lastError = undefined;
+    };
+
+    return { chrome, calls, responses, events, reset };
+}
+
+let harness = null;
+
+export function install() {
+    if (harness === null) {
+        harness = createHarness();
+    } else {
+        harness.reset();
+    }
+    globalThis.chrome = harness.chrome;
+    globalThis.browser = harness.chrome;
+}
+
+function current() {
+    if (harness === null) throw new Error("test_harness::install() has not been called");
+    return harness;
+}
+
+export function respond(path, value) {
+    current().responses.set(path, value);
+}
+
+export function calls(path) {
+    return current().calls.get(path) || [];
+}
+
+export function emit(path, args) {
+    const event = current().events.get(path);
+    if (!event) throw new Error(`the test harness has no event ${path}`);
+    return event.__emit(Array.from(args));
+}
+
+export function storage_contents(area) {
+    return current().chrome.storage[area].__contents();
+}
+"#)]
+extern "C" {
+    /// Installs the fake `chrome`, dropping all stored data, recorded calls, responses and listeners.
+    pub fn install();
+
+    /// Sets what the stub at `path` (e.g. `"tabs.query"`) resolves to. A JS function is called with the
+    /// stub's arguments to compute the response.
+    pub fn respond(path: &str, value: &JsValue);
+
+    /// The arguments of every call to `path` so far, e.g. `"storage.local.set"`.
+    pub fn calls(path: &str) -> Vec<Array>;
+
+    /// Fires the event at `path` (e.g. `"runtime.onMessage"`) and returns what each listener returned.
+    pub fn emit(path: &str, args: &Array) -> Array;
+
+    /// A plain object of everything in a storage area.
+    pub fn storage_contents(area: &str) -> JsValue;
+}
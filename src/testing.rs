@@ -0,0 +1,598 @@
+//! In-memory stand-ins for the browser, so code built on this crate can be tested natively with `cargo test`.
+//!
+//! The fakes have the same signatures as the bindings they stand in for, and
+//! nothing switches to them on its own. Import them in place of the real ones
+//! where a test should use them:
+//!
+//! ```ignore
+//! #[cfg(not(test))]
+//! use web_extension_sys::storage::local;
+//! #[cfg(test)]
+//! use web_extension_sys::testing::storage::local;
+//! ```
+//!
+//! Fakes cover the promise-based `storage` functions and `storage.onChanged`,
+//! `tabs::create`, `tabs::query`, `tabs::send_message` and `tabs.onCreated`,
+//! and `runtime::send_message`.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+#[cfg(any(feature = "tabs", feature = "runtime"))]
+use crate::error::Error;
+
+/// Clears all storage areas, scripted responses and event history.
+pub fn reset() {
+    #[cfg(feature = "storage")]
+    {
+        storage::AREAS.with(|areas| areas.borrow_mut().clear());
+        storage::on_changed::EVENT.with(EventEmitter::reset);
+    }
+    #[cfg(feature = "tabs")]
+    {
+        tabs::SCRIPT.with(|script| *script.borrow_mut() = Default::default());
+        tabs::on_created::EVENT.with(EventEmitter::reset);
+    }
+    #[cfg(feature = "runtime")]
+    runtime::SCRIPT.with(|script| *script.borrow_mut() = Default::default());
+}
+
+#[cfg(feature = "storage")]
+pub mod storage {
+    use std::cell::RefCell;
+    use std::collections::{BTreeMap, HashMap};
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+    use serde_json::{Map, Value};
+    use crate::storage::AreaName;
+    use crate::error::Error;
+
+    thread_local! {
+        pub(super) static AREAS: RefCell<HashMap<&'static str, BTreeMap<String, Value>>> = RefCell::new(HashMap::new());
+    }
+
+    /// Everything currently stored in `area` (`"local"`, `"sync"`, ...).
+    pub fn contents(area: &str) -> BTreeMap<String, Value> {
+        AREAS.with(|areas| areas.borrow().get(area).cloned().unwrap_or_default())
+    }
+
+    /// What a fake `storage.onChanged` reports for one key.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct StorageChange {
+        pub old_value: Option<Value>,
+        pub new_value: Option<Value>,
+    }
+
+    pub mod on_changed {
+        use std::collections::HashMap;
+        use crate::storage::AreaName;
+        use crate::testing::{EventEmitter, ListenerHandle};
+        use super::StorageChange;
+
+        thread_local! {
+            pub(in crate::testing) static EVENT: EventEmitter<(HashMap<String, StorageChange>, AreaName)> = EventEmitter::new();
+        }
+
+        pub fn listen<T>(mut callback: T) -> ListenerHandle
+            where T: FnMut(HashMap<String, StorageChange>, AreaName) + 'static,
+        {
+            EVENT.with(|event| event.listen(move |(changes, area_name)| callback(changes, area_name)))
+        }
+
+        /// Every change reported so far, including ones passed to `emit`.
+        pub fn emitted() -> Vec<(HashMap<String, StorageChange>, AreaName)> {
+            EVENT.with(|event| event.emitted())
+        }
+
+        /// Reports a change that didn't come from the fake storage functions, e.g. one made by another context.
+        pub fn emit(changes: HashMap<String, StorageChange>, area_name: AreaName) {
+            EVENT.with(|event| event.emit((changes, area_name)));
+        }
+    }
+
+    fn get<T: DeserializeOwned>(area: &'static str, keys: Option<Vec<String>>) -> Result<T, Error> {
+        let items: Map<String, Value> = AREAS.with(|areas| {
+            let areas = areas.borrow();
+            let stored = areas.get(area).into_iter().flatten();
+
+            match &keys {
+                Some(keys) => stored.filter(|(key, _)| keys.contains(key)).map(|(k, v)| (k.clone(), v.clone())).collect(),
+                None => stored.map(|(k, v)| (k.clone(), v.clone())).collect(),
+            }
+        });
+
+        Ok(serde_json::from_value(Value::Object(items))?)
+    }
+
+    /// Applies `edit` to the stored contents of `area`, and reports the keys it changed.
+    fn write<F: FnOnce(&mut BTreeMap<String, Value>)>(area: &'static str, edit: F) {
+        let changes: HashMap<String, StorageChange> = AREAS.with(|areas| {
+            let mut areas = areas.borrow_mut();
+            let stored = areas.entry(area).or_default();
+            let old = stored.clone();
+
+            edit(stored);
+
+            old.keys()
+                .chain(stored.keys())
+                .filter(|key| old.get(*key) != stored.get(*key))
+                .map(|key| (key.clone(), StorageChange {
+                    old_value: old.get(key).cloned(),
+                    new_value: stored.get(key).cloned(),
+                }))
+                .collect()
+        });
+
+        if !changes.is_empty() {
+            on_changed::emit(changes, AreaName::from(area));
+        }
+    }
+
+    fn set<T: Serialize>(area: &'static str, data: &T) -> Result<(), Error> {
+        let items = match serde_json::to_value(data)? {
+            Value::Object(items) => items,
+            other => return Err(Error::runtime(format!("storage.{}.set expects an object, got {}", area, other))),
+        };
+
+        write(area, |stored| stored.extend(items));
+
+        Ok(())
+    }
+
+    fn remove(area: &'static str, keys: Vec<String>) {
+        write(area, |stored| {
+            for key in keys {
+                stored.remove(&key);
+            }
+        });
+    }
+
+    fn clear(area: &'static str) {
+        write(area, BTreeMap::clear);
+    }
+
+    /// Approximated as the JSON length of each key and value, as Chrome does.
+    fn get_bytes_in_use(area: &'static str, keys: Option<Vec<String>>) -> f64 {
+        contents(area)
+            .iter()
+            .filter(|(key, _)| keys.as_ref().is_none_or(|keys| keys.contains(key)))
+            .map(|(key, value)| key.len() + value.to_string().len())
+            .sum::<usize>() as f64
+    }
+
+    macro_rules! define_fake_storage_area {
+        ($name:ident) => {
+            pub mod $name {
+                use std::collections::HashMap;
+                use serde::Serialize;
+                use serde::de::DeserializeOwned;
+                use crate::error::Error;
+
+                /// Like `get`, but for a single key, with `Error::NoSuchKey` if it isn't set.
+                pub async fn get_key<T: DeserializeOwned>(key: &str) -> Result<T, Error> {
+                    let mut items: HashMap<String, T> = get(vec![key.to_owned()]).await?;
+
+                    items.remove(key).ok_or_else(|| Error::NoSuchKey(key.to_owned()))
+                }
+
+                /// Missing keys are absent from the result, so `T` should use `Option` fields or a map.
+                pub async fn get<T: DeserializeOwned>(keys: Vec<String>) -> Result<T, Error> {
+                    super::get(stringify!($name), Some(keys))
+                }
+
+                pub async fn get_all<T: DeserializeOwned>() -> Result<T, Error> {
+                    super::get(stringify!($name), None)
+                }
+
+                pub async fn set<T: Serialize>(data: &T) -> Result<(), Error> {
+                    super::set(stringify!($name), data)
+                }
+
+                pub async fn remove(keys: Vec<String>) -> Result<(), Error> {
+                    super::remove(stringify!($name), keys);
+
+                    Ok(())
+                }
+
+                pub async fn clear() -> Result<(), Error> {
+                    super::clear(stringify!($name));
+
+                    Ok(())
+                }
+
+                /// Pass `None` to get the total for the whole storage area.
+                pub async fn get_bytes_in_use(keys: Option<Vec<String>>) -> Result<f64, Error> {
+                    Ok(super::get_bytes_in_use(stringify!($name), keys))
+                }
+            }
+        };
+    }
+
+    define_fake_storage_area!(local);
+
+    define_fake_storage_area!(sync);
+
+    define_fake_storage_area!(session);
+}
+
+#[cfg(feature = "tabs")]
+pub mod tabs {
+    use std::cell::RefCell;
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+    use serde_json::Value;
+    use crate::tabs::{CreateProperties, QueryInfo, Tab};
+    use crate::error::Error;
+    use super::Script;
+
+    #[derive(Default)]
+    pub(super) struct TabsScript {
+        pub(super) create: Option<Script<CreateProperties, Tab>>,
+        pub(super) query: Option<Script<QueryInfo, Vec<Tab>>>,
+        pub(super) send_message: Option<Script<(i32, Value), Value>>,
+    }
+
+    thread_local! {
+        pub(super) static SCRIPT: RefCell<TabsScript> = RefCell::new(TabsScript::default());
+    }
+
+    pub mod on_created {
+        use crate::tabs::Tab;
+        use crate::testing::{EventEmitter, ListenerHandle};
+
+        thread_local! {
+            pub(in crate::testing) static EVENT: EventEmitter<Tab> = EventEmitter::new();
+        }
+
+        pub fn listen<T: FnMut(Tab) + 'static>(callback: T) -> ListenerHandle {
+            EVENT.with(|event| event.listen(callback))
+        }
+
+        /// Every tab created so far, including ones passed to `emit`.
+        pub fn emitted() -> Vec<Tab> {
+            EVENT.with(|event| event.emitted())
+        }
+
+        /// Reports a tab that wasn't created through the fake `create`, e.g. one the user opened.
+        pub fn emit(tab: Tab) {
+            EVENT.with(|event| event.emit(tab));
+        }
+    }
+
+    pub fn on_create<F: FnMut(&CreateProperties) -> Tab + 'static>(mut respond: F) {
+        SCRIPT.with(|script| script.borrow_mut().create = Some(Script::new(move |properties| respond(properties))));
+    }
+
+    pub fn on_query<F: FnMut(&QueryInfo) -> Vec<Tab> + 'static>(mut respond: F) {
+        SCRIPT.with(|script| script.borrow_mut().query = Some(Script::new(move |query| respond(query))));
+    }
+
+    /// Scripts the reply to `send_message`. The message and reply are JSON.
+    pub fn on_send_message<F: FnMut(i32, &Value) -> Value + 'static>(mut respond: F) {
+        SCRIPT.with(|script| {
+            script.borrow_mut().send_message = Some(Script::new(move |(tab_id, message): &(i32, Value)| respond(*tab_id, message)))
+        });
+    }
+
+    /// Every `(tab_id, message)` passed to `send_message` so far.
+    pub fn sent_messages() -> Vec<(i32, Value)> {
+        SCRIPT.with(|script| script.borrow().send_message.as_ref().map(Script::calls).unwrap_or_default())
+    }
+
+    /// Fires `on_created` with the scripted tab.
+    pub async fn create(create_properties: &CreateProperties) -> Result<Tab, Error> {
+        let tab = SCRIPT.with(|script| super::call(&mut script.borrow_mut().create, "tabs.create", create_properties.clone()))?;
+
+        on_created::emit(tab.clone());
+
+        Ok(tab)
+    }
+
+    pub async fn query(query_info: &QueryInfo) -> Result<Vec<Tab>, Error> {
+        SCRIPT.with(|script| super::call(&mut script.borrow_mut().query, "tabs.query", query_info.clone()))
+    }
+
+    pub async fn send_message<M, R>(tab_id: i32, message: &M) -> Result<R, Error>
+        where M: Serialize,
+              R: DeserializeOwned,
+    {
+        let message = serde_json::to_value(message)?;
+        let response = SCRIPT.with(|script| super::call(&mut script.borrow_mut().send_message, "tabs.sendMessage", (tab_id, message)))?;
+
+        Ok(serde_json::from_value(response)?)
+    }
+}
+
+#[cfg(feature = "runtime")]
+pub mod runtime {
+    use std::cell::RefCell;
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+    use serde_json::Value;
+    use crate::error::Error;
+    use super::Script;
+
+    #[derive(Default)]
+    pub(super) struct RuntimeScript {
+        pub(super) send_message: Option<Script<Value, Value>>,
+    }
+
+    thread_local! {
+        pub(super) static SCRIPT: RefCell<RuntimeScript> = RefCell::new(RuntimeScript::default());
+    }
+
+    /// Scripts the reply to `send_message`. The message and reply are JSON.
+    pub fn on_send_message<F: FnMut(&Value) -> Value + 'static>(respond: F) {
+        SCRIPT.with(|script| script.borrow_mut().send_message = Some(Script::new(respond)));
+    }
+
+    /// Every message passed to `send_message` so far.
+    pub fn sent_messages() -> Vec<Value> {
+        SCRIPT.with(|script| script.borrow().send_message.as_ref().map(Script::calls).unwrap_or_default())
+    }
+
+    pub async fn send_message<M, R>(message: &M) -> Result<R, Error>
+        where M: Serialize,
+              R: DeserializeOwned,
+    {
+        let message = serde_json::to_value(message)?;
+        let response = SCRIPT.with(|script| super::call(&mut script.borrow_mut().send_message, "runtime.sendMessage", message))?;
+
+        Ok(serde_json::from_value(response)?)
+    }
+}
+
+#[cfg(any(feature = "tabs", feature = "runtime"))]
+/// A scripted response that also records what it was called with.
+pub(crate) struct Script<A, R> {
+    respond: Box<dyn FnMut(&A) -> R>,
+    calls: Vec<A>,
+}
+
+#[cfg(any(feature = "tabs", feature = "runtime"))]
+impl<A: Clone, R> Script<A, R> {
+    fn new<F: FnMut(&A) -> R + 'static>(respond: F) -> Self {
+        Self {
+            respond: Box::new(respond),
+            calls: Vec::new(),
+        }
+    }
+
+    fn calls(&self) -> Vec<A> {
+        self.calls.clone()
+    }
+}
+
+#[cfg(any(feature = "tabs", feature = "runtime"))]
+/// Fails like a browser without the API would, if no response was scripted.
+fn call<A, R>(script: &mut Option<Script<A, R>>, api: &str, args: A) -> Result<R, Error> {
+    let script = script
+        .as_mut()
+        .ok_or_else(|| Error::runtime(format!("no response scripted for {}", api)))?;
+    let response = (script.respond)(&args);
+    script.calls.push(args);
+
+    Ok(response)
+}
+
+type Listener<T> = Box<dyn FnMut(T)>;
+
+struct Listeners<T> {
+    next_id: u32,
+    listeners: Vec<(u32, Listener<T>)>,
+}
+
+/// A plain Rust event that records everything emitted through it. The fakes
+/// above fire theirs, and it can be used directly for code that reacts to events.
+pub struct EventEmitter<T> {
+    listeners: Rc<RefCell<Listeners<T>>>,
+    history: RefCell<Vec<T>>,
+}
+
+impl<T: Clone + 'static> EventEmitter<T> {
+    pub fn new() -> Self {
+        Self {
+            listeners: Rc::new(RefCell::new(Listeners { next_id: 0, listeners: Vec::new() })),
+            history: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn listen<F: FnMut(T) + 'static>(&self, callback: F) -> ListenerHandle {
+        let id = {
+            let mut listeners = self.listeners.borrow_mut();
+            let id = listeners.next_id;
+            listeners.next_id += 1;
+            listeners.listeners.push((id, Box::new(callback)));
+            id
+        };
+
+        ListenerHandle {
+            remove: Some(Box::new(remove_listener(Rc::downgrade(&self.listeners), id))),
+        }
+    }
+
+    pub fn emit(&self, value: T) {
+        self.history.borrow_mut().push(value.clone());
+
+        // Listeners may listen or drop their handles while being called.
+        let mut called = std::mem::take(&mut self.listeners.borrow_mut().listeners);
+
+        for (_, listener) in called.iter_mut() {
+            listener(value.clone());
+        }
+
+        let mut listeners = self.listeners.borrow_mut();
+        called.append(&mut listeners.listeners);
+        listeners.listeners = called;
+    }
+
+    pub fn emitted(&self) -> Vec<T> {
+        self.history.borrow().clone()
+    }
+
+    #[cfg(any(feature = "storage", feature = "tabs"))]
+    fn reset(&self) {
+        self.listeners.borrow_mut().listeners.clear();
+        self.history.borrow_mut().clear();
+    }
+}
+
+fn remove_listener<T: 'static>(listeners: Weak<RefCell<Listeners<T>>>, id: u32) -> impl FnOnce() {
+    move || {
+        if let Some(listeners) = listeners.upgrade() {
+            listeners.borrow_mut().listeners.retain(|(listener_id, _)| *listener_id != id);
+        }
+    }
+}
+
+impl<T: Clone + 'static> Default for EventEmitter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Like `events::EventHandle`: removes the listener from its `EventEmitter` when dropped.
+#[must_use = "the listener is removed as soon as the handle is dropped"]
+pub struct ListenerHandle {
+    remove: Option<Box<dyn FnOnce()>>,
+}
+
+impl ListenerHandle {
+    /// Leaves the listener attached for the rest of the program.
+    pub fn forget(mut self) {
+        self.remove.take();
+    }
+
+    pub fn remove(self) {
+        drop(self);
+    }
+}
+
+impl Drop for ListenerHandle {
+    fn drop(&mut self) {
+        if let Some(remove) = self.remove.take() {
+            remove();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    #[cfg(any(feature = "storage", feature = "tabs", feature = "runtime"))]
+    use serde_json::{json, Value};
+    #[cfg(any(feature = "storage", feature = "tabs", feature = "runtime"))]
+    use crate::error::Error;
+    use super::*;
+
+    #[cfg(any(feature = "storage", feature = "tabs", feature = "runtime"))]
+    /// The fakes never wait, so one poll is enough.
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("fake call didn't resolve straight away"),
+        }
+    }
+
+    #[test]
+    fn emitter_records_and_removes_listeners() {
+        let emitter = EventEmitter::new();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let log = seen.clone();
+        let handle = emitter.listen(move |value: i32| log.borrow_mut().push(value));
+        emitter.emit(1);
+        handle.remove();
+        emitter.emit(2);
+
+        assert_eq!(*seen.borrow(), vec![1]);
+        assert_eq!(emitter.emitted(), vec![1, 2]);
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn storage_round_trips_and_reports_changes() {
+        use std::collections::HashMap;
+        use crate::storage::AreaName;
+
+        reset();
+
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let log = changes.clone();
+        let _listener = storage::on_changed::listen(move |changes, area_name| log.borrow_mut().push((changes, area_name)));
+
+        block_on(storage::local::set(&json!({ "count": 1 }))).unwrap();
+        block_on(storage::local::set(&json!({ "count": 2 }))).unwrap();
+
+        let count: u32 = block_on(storage::local::get_key("count")).unwrap();
+        assert_eq!(count, 2);
+        assert!(matches!(block_on(storage::sync::get_key::<u32>("count")), Err(Error::NoSuchKey(_))));
+
+        block_on(storage::local::remove(vec!["count".to_owned()])).unwrap();
+        let all: HashMap<String, Value> = block_on(storage::local::get_all()).unwrap();
+        assert!(all.is_empty());
+
+        let changes = changes.borrow();
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[1].1, AreaName::Local);
+        assert_eq!(changes[1].0["count"], storage::StorageChange { old_value: Some(json!(1)), new_value: Some(json!(2)) });
+        assert_eq!(changes[2].0["count"].new_value, None);
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn storage_set_rejects_non_objects() {
+        reset();
+
+        assert!(matches!(block_on(storage::local::set(&3)), Err(Error::Runtime(_))));
+        assert!(storage::contents("local").is_empty());
+    }
+
+    #[cfg(feature = "tabs")]
+    #[test]
+    fn tabs_use_scripted_responses() {
+        use crate::tabs::{CreateProperties, Tab};
+
+        reset();
+
+        assert!(matches!(block_on(tabs::create(&CreateProperties::default())), Err(Error::Runtime(_))));
+
+        tabs::on_create(|properties| serde_json::from_value::<Tab>(json!({
+            "id": 7,
+            "index": 0,
+            "windowId": 1,
+            "highlighted": false,
+            "active": properties.active.unwrap_or(false),
+            "pinned": false,
+            "incognito": false,
+            "discarded": false,
+            "autoDiscardable": true,
+            "groupId": -1,
+        })).unwrap());
+        tabs::on_send_message(|tab_id, message| json!({ "tab": tab_id, "echo": message }));
+
+        let tab = block_on(tabs::create(&CreateProperties { active: Some(true), ..CreateProperties::default() })).unwrap();
+        assert!(tab.active);
+        assert_eq!(tabs::on_created::emitted().len(), 1);
+
+        let reply: Value = block_on(tabs::send_message(7, &"ping")).unwrap();
+        assert_eq!(reply, json!({ "tab": 7, "echo": "ping" }));
+        assert_eq!(tabs::sent_messages(), vec![(7, json!("ping"))]);
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn runtime_send_message_fails_without_a_script() {
+        reset();
+
+        assert!(matches!(block_on(runtime::send_message::<_, Value>(&"ping")), Err(Error::Runtime(_))));
+
+        runtime::on_send_message(|message| json!([message]));
+        let reply: Value = block_on(runtime::send_message(&"ping")).unwrap();
+
+        assert_eq!(reply, json!(["ping"]));
+    }
+}
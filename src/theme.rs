@@ -0,0 +1,153 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ColorScheme {
+    Auto,
+    Light,
+    Dark,
+    System,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThemeImages {
+    pub theme_frame: Option<String>,
+    pub additional_backgrounds: Option<Vec<String>>,
+}
+
+/// Colors are CSS color strings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThemeColors {
+    pub bookmark_text: Option<String>,
+    pub button_background_active: Option<String>,
+    pub button_background_hover: Option<String>,
+    pub frame: Option<String>,
+    pub frame_inactive: Option<String>,
+    pub icons: Option<String>,
+    pub icons_attention: Option<String>,
+    pub ntp_background: Option<String>,
+    pub ntp_card_background: Option<String>,
+    pub ntp_text: Option<String>,
+    pub popup: Option<String>,
+    pub popup_border: Option<String>,
+    pub popup_highlight: Option<String>,
+    pub popup_highlight_text: Option<String>,
+    pub popup_text: Option<String>,
+    pub sidebar: Option<String>,
+    pub sidebar_border: Option<String>,
+    pub sidebar_highlight: Option<String>,
+    pub sidebar_highlight_text: Option<String>,
+    pub sidebar_text: Option<String>,
+    pub tab_background_separator: Option<String>,
+    pub tab_background_text: Option<String>,
+    pub tab_line: Option<String>,
+    pub tab_loading: Option<String>,
+    pub tab_selected: Option<String>,
+    pub tab_text: Option<String>,
+    pub toolbar: Option<String>,
+    pub toolbar_bottom_separator: Option<String>,
+    pub toolbar_field: Option<String>,
+    pub toolbar_field_border: Option<String>,
+    pub toolbar_field_border_focus: Option<String>,
+    pub toolbar_field_focus: Option<String>,
+    pub toolbar_field_highlight: Option<String>,
+    pub toolbar_field_highlight_text: Option<String>,
+    pub toolbar_field_separator: Option<String>,
+    pub toolbar_field_text: Option<String>,
+    pub toolbar_field_text_focus: Option<String>,
+    pub toolbar_text: Option<String>,
+    pub toolbar_top_separator: Option<String>,
+    pub toolbar_vertical_separator: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThemeProperties {
+    pub additional_backgrounds_alignment: Option<Vec<String>>,
+    pub additional_backgrounds_tiling: Option<Vec<String>>,
+    pub color_scheme: Option<ColorScheme>,
+    pub content_color_scheme: Option<ColorScheme>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Theme {
+    pub images: Option<ThemeImages>,
+    pub colors: Option<ThemeColors>,
+    pub properties: Option<ThemeProperties>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeUpdateInfo {
+    pub theme: Theme,
+    pub window_id: Option<i32>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "theme"], js_name = getCurrent)]
+    fn _get_current(window_id: Option<i32>) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "theme"], js_name = update)]
+    fn _update(theme: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "theme"], js_name = update)]
+    fn _update_window(window_id: i32, theme: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "theme"], js_name = reset)]
+    fn _reset(window_id: Option<i32>) -> Promise;
+}
+
+/// Gets the theme of the given window, or the global theme for `None`.
+pub async fn get_current(window_id: Option<i32>) -> Result<Theme, Error> {
+    resolve(_get_current(window_id)).await
+}
+
+pub async fn update(window_id: Option<i32>, theme: &Theme) -> Result<(), Error> {
+    let theme = serde_wasm_bindgen::to_value(theme)?;
+
+    match window_id {
+        Some(window_id) => resolve(_update_window(window_id, theme)).await,
+        None => resolve(_update(theme)).await,
+    }
+}
+
+pub async fn reset(window_id: Option<i32>) -> Result<(), Error> {
+    resolve(_reset(window_id)).await
+}
+
+pub mod on_updated {
+    use wasm_bindgen::prelude::*;
+    use super::ThemeUpdateInfo;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "theme", "onUpdated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "theme"], js_name = onUpdated)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ThemeUpdateInfo) + 'static,
+    {
+        Closure::wrap(Box::new(move |update_info: JsValue| {
+            callback(event_arg!(update_info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ThemeUpdateInfo) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ThemeUpdateInfo> {
+        stream_of(listen)
+    }
+}
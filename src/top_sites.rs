@@ -0,0 +1,22 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MostVisitedURL {
+    pub url: String,
+    pub title: String,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "topSites"], js_name = get)]
+    fn _get() -> Promise;
+}
+
+pub async fn get() -> Result<Vec<MostVisitedURL>, Error> {
+    resolve(_get()).await
+}
@@ -0,0 +1,245 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+use wasm_bindgen::prelude::*;
+use js_sys::{Promise, Reflect};
+use serde::{Serialize, Deserialize};
+use crate::events::event_arg;
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EventType {
+    Start,
+    End,
+    Word,
+    Sentence,
+    Marker,
+    Interrupted,
+    Cancelled,
+    Error,
+    Pause,
+    Resume,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TtsEvent {
+    Start { char_index: Option<u32> },
+    End { char_index: Option<u32> },
+    Word { char_index: Option<u32>, length: Option<u32> },
+    Sentence { char_index: Option<u32>, length: Option<u32> },
+    Marker { char_index: Option<u32> },
+    Interrupted,
+    Cancelled,
+    Error { error_message: Option<String> },
+    Pause { char_index: Option<u32> },
+    Resume { char_index: Option<u32> },
+}
+
+const FINAL_EVENT_TYPES: [EventType; 4] = [EventType::End, EventType::Interrupted, EventType::Cancelled, EventType::Error];
+
+impl TtsEvent {
+    pub fn is_final(&self) -> bool {
+        FINAL_EVENT_TYPES.contains(&self.event_type())
+    }
+
+    pub fn event_type(&self) -> EventType {
+        match self {
+            TtsEvent::Start { .. } => EventType::Start,
+            TtsEvent::End { .. } => EventType::End,
+            TtsEvent::Word { .. } => EventType::Word,
+            TtsEvent::Sentence { .. } => EventType::Sentence,
+            TtsEvent::Marker { .. } => EventType::Marker,
+            TtsEvent::Interrupted => EventType::Interrupted,
+            TtsEvent::Cancelled => EventType::Cancelled,
+            TtsEvent::Error { .. } => EventType::Error,
+            TtsEvent::Pause { .. } => EventType::Pause,
+            TtsEvent::Resume { .. } => EventType::Resume,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TtsVoice {
+    pub voice_name: Option<String>,
+    pub lang: Option<String>,
+    pub remote: Option<bool>,
+    pub extension_id: Option<String>,
+    pub event_types: Option<Vec<EventType>>,
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TtsOptions {
+    voice_name: Option<String>,
+    extension_id: Option<String>,
+    lang: Option<String>,
+    rate: Option<f64>,
+    pitch: Option<f64>,
+    volume: Option<f64>,
+    enqueue: Option<bool>,
+    required_event_types: Option<Vec<EventType>>,
+    desired_event_types: Option<Vec<EventType>>,
+    #[serde(skip)]
+    on_event: Option<Box<dyn FnMut(TtsEvent)>>,
+}
+
+impl TtsOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn voice_name(mut self, voice_name: &str) -> Self {
+        self.voice_name = Some(voice_name.to_owned());
+        self
+    }
+
+    pub fn extension_id(mut self, extension_id: &str) -> Self {
+        self.extension_id = Some(extension_id.to_owned());
+        self
+    }
+
+    pub fn lang(mut self, lang: &str) -> Self {
+        self.lang = Some(lang.to_owned());
+        self
+    }
+
+    pub fn rate(mut self, rate: f64) -> Self {
+        self.rate = Some(rate);
+        self
+    }
+
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = Some(pitch);
+        self
+    }
+
+    pub fn volume(mut self, volume: f64) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+
+    pub fn enqueue(mut self, enqueue: bool) -> Self {
+        self.enqueue = Some(enqueue);
+        self
+    }
+
+    pub fn required_event_types(mut self, event_types: Vec<EventType>) -> Self {
+        self.required_event_types = Some(event_types);
+        self
+    }
+
+    pub fn desired_event_types(mut self, event_types: Vec<EventType>) -> Self {
+        self.desired_event_types = Some(event_types);
+        self
+    }
+
+    pub fn on_event<T>(mut self, callback: T) -> Self
+        where T: FnMut(TtsEvent) + 'static,
+    {
+        self.on_event = Some(Box::new(callback));
+        self
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "tts"], js_name = speak)]
+    fn _speak(utterance: &str, options: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tts"], js_name = stop)]
+    pub fn stop();
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tts"], js_name = pause)]
+    pub fn pause();
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tts"], js_name = resume)]
+    pub fn resume();
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tts"], js_name = getVoices)]
+    fn _get_voices() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "tts"], js_name = isSpeaking)]
+    fn _is_speaking() -> Promise;
+}
+
+#[derive(Default)]
+struct Progress {
+    finished: bool,
+    waker: Option<Waker>,
+}
+
+/// Resolves once the utterance is queued. With an `on_event` callback it
+/// resolves once the utterance has finished instead, and holds the callback
+/// until then, so dropping the future early stops the callback.
+pub async fn speak(utterance: &str, mut options: TtsOptions) -> Result<(), Error> {
+    let mut callback = match options.on_event.take() {
+        Some(callback) => callback,
+        None => return resolve(_speak(utterance, serde_wasm_bindgen::to_value(&options)?)).await,
+    };
+
+    // The final events are what tell this when to let go of the callback, so
+    // ask for them even if the caller didn't, and keep them from the callback.
+    let wanted = options.desired_event_types.clone();
+    if let Some(desired) = &mut options.desired_event_types {
+        for event_type in FINAL_EVENT_TYPES {
+            if !desired.contains(&event_type) {
+                desired.push(event_type);
+            }
+        }
+    }
+
+    let progress = Rc::new(RefCell::new(Progress::default()));
+    let on_event = {
+        let progress = progress.clone();
+
+        Closure::wrap(Box::new(move |event: JsValue| {
+            let event: TtsEvent = event_arg!(event);
+            let is_final = event.is_final();
+
+            if wanted.as_ref().is_none_or(|wanted| wanted.contains(&event.event_type())) {
+                callback(event);
+            }
+
+            if is_final {
+                let mut progress = progress.borrow_mut();
+                progress.finished = true;
+
+                if let Some(waker) = progress.waker.take() {
+                    waker.wake();
+                }
+            }
+        }) as Box<dyn FnMut(JsValue)>)
+    };
+
+    let value = serde_wasm_bindgen::to_value(&options)?;
+    Reflect::set(&value, &"onEvent".into(), on_event.as_ref())?;
+
+    resolve::<()>(_speak(utterance, value)).await?;
+
+    std::future::poll_fn(|cx| {
+        let mut progress = progress.borrow_mut();
+
+        if progress.finished {
+            Poll::Ready(())
+        } else {
+            progress.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }).await;
+
+    drop(on_event);
+
+    Ok(())
+}
+
+pub async fn get_voices() -> Result<Vec<TtsVoice>, Error> {
+    resolve(_get_voices()).await
+}
+
+pub async fn is_speaking() -> Result<bool, Error> {
+    resolve(_is_speaking()).await
+}
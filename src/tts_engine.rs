@@ -0,0 +1,166 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Function;
+use serde::{Serialize, Deserialize};
+use crate::tts::{TtsEvent, TtsVoice};
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeakOptions {
+    pub voice_name: Option<String>,
+    pub lang: Option<String>,
+    pub rate: Option<f64>,
+    pub pitch: Option<f64>,
+    pub volume: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SendTtsEvent(Function);
+
+impl SendTtsEvent {
+    pub fn send(&self, event: &TtsEvent) -> Result<(), Error> {
+        self.0.call1(&JsValue::NULL, &serde_wasm_bindgen::to_value(event)?)?;
+
+        Ok(())
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "ttsEngine"], js_name = updateVoices)]
+    fn _update_voices(voices: JsValue);
+}
+
+pub fn update_voices(voices: &[TtsVoice]) -> Result<(), Error> {
+    _update_voices(serde_wasm_bindgen::to_value(voices)?);
+
+    Ok(())
+}
+
+pub mod on_speak {
+    use wasm_bindgen::prelude::*;
+    use js_sys::Function;
+    use super::{SendTtsEvent, SpeakOptions};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "ttsEngine", "onSpeak"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, JsValue, Function)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "ttsEngine"], js_name = onSpeak)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, JsValue, Function)>
+        where T: FnMut(String, SpeakOptions, SendTtsEvent) + 'static,
+    {
+        Closure::wrap(Box::new(move |utterance, options: JsValue, send_tts_event| {
+            callback(
+                utterance,
+                event_arg!(options),
+                SendTtsEvent(send_tts_event),
+            );
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, SpeakOptions, SendTtsEvent) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, SpeakOptions, SendTtsEvent)> {
+        stream_of(|mut callback| listen(move |utterance, options, send_tts_event| callback((utterance, options, send_tts_event))))
+    }
+}
+
+pub mod on_stop {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "ttsEngine", "onStop"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "ttsEngine"], js_name = onStop)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
+
+pub mod on_pause {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "ttsEngine", "onPause"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "ttsEngine"], js_name = onPause)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
+
+pub mod on_resume {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "ttsEngine", "onResume"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "ttsEngine"], js_name = onResume)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
@@ -0,0 +1,137 @@
+use std::marker::PhantomData;
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use crate::events::{EventHandle, event_arg};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChromeSettingScope {
+    Regular,
+    RegularOnly,
+    IncognitoPersistent,
+    IncognitoSessionOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LevelOfControl {
+    NotControllable,
+    ControlledByOtherExtensions,
+    ControllableByThisExtension,
+    ControlledByThisExtension,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", bound = "T: DeserializeOwned")]
+pub struct ChromeSettingValue<T> {
+    pub value: T,
+    pub level_of_control: LevelOfControl,
+    pub incognito_specific: Option<bool>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GetDetails {
+    incognito: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SetDetails<'a, T> {
+    value: &'a T,
+    scope: Option<ChromeSettingScope>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ClearDetails {
+    scope: Option<ChromeSettingScope>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    type JsChromeSetting;
+
+    #[wasm_bindgen(method, js_name = get)]
+    fn get(this: &JsChromeSetting, details: JsValue) -> Promise;
+
+    #[wasm_bindgen(method, js_name = set)]
+    fn set(this: &JsChromeSetting, details: JsValue) -> Promise;
+
+    #[wasm_bindgen(method, js_name = clear)]
+    fn clear(this: &JsChromeSetting, details: JsValue) -> Promise;
+
+    #[wasm_bindgen(method, getter, js_name = onChange)]
+    fn on_change(this: &JsChromeSetting) -> JsChromeSettingEvent;
+
+    type JsChromeSettingEvent;
+
+    #[wasm_bindgen(method, js_name = addListener)]
+    fn add_listener(this: &JsChromeSettingEvent, callback: &Closure<dyn FnMut(JsValue)>);
+}
+
+pub struct ChromeSetting<T> {
+    setting: JsChromeSetting,
+    value: PhantomData<T>,
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> ChromeSetting<T> {
+    #[cfg(any(feature = "privacy", feature = "accessibility_features", feature = "browser_settings", feature = "captive_portal", feature = "proxy"))]
+    pub(crate) fn new(setting: JsValue) -> Self {
+        Self {
+            setting: setting.unchecked_into(),
+            value: PhantomData,
+        }
+    }
+
+    #[cfg(any(feature = "privacy", feature = "accessibility_features", feature = "browser_settings", feature = "captive_portal", feature = "proxy"))]
+    /// The setting at `path` under the resolved namespace, or `Error::Unsupported` if it's
+    /// missing, e.g. because its permission wasn't granted.
+    pub(crate) fn from_path(path: &str) -> Result<Self, Error> {
+        crate::namespace::get(path)
+            .map(Self::new)
+            .ok_or_else(|| Error::Unsupported(path.to_owned()))
+    }
+
+    pub async fn get(&self, incognito: bool) -> Result<ChromeSettingValue<T>, Error> {
+        let details = GetDetails { incognito };
+
+        resolve(self.setting.get(serde_wasm_bindgen::to_value(&details)?)).await
+    }
+
+    pub async fn set(&self, value: &T, scope: Option<ChromeSettingScope>) -> Result<(), Error> {
+        let details = SetDetails { value, scope };
+
+        resolve(self.setting.set(serde_wasm_bindgen::to_value(&details)?)).await
+    }
+
+    pub async fn clear(&self, scope: Option<ChromeSettingScope>) -> Result<(), Error> {
+        let details = ClearDetails { scope };
+
+        resolve(self.setting.clear(serde_wasm_bindgen::to_value(&details)?)).await
+    }
+
+    pub fn add_change_listener(&self, callback: &Closure<dyn FnMut(JsValue)>) {
+        self.setting.on_change().add_listener(callback);
+    }
+
+    pub fn create_change_listener<F>(mut callback: F) -> Closure<dyn FnMut(JsValue)>
+        where F: FnMut(ChromeSettingValue<T>) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<F>(&self, callback: F) -> EventHandle
+        where F: FnMut(ChromeSettingValue<T>) + 'static,
+    {
+        let event = self.setting.on_change().unchecked_into();
+
+        EventHandle::new(event, Self::create_change_listener(callback))
+    }
+}
@@ -0,0 +1,145 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use crate::match_pattern::MatchPattern;
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScriptSource {
+    Code(String),
+    File(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunAt {
+    DocumentStart,
+    DocumentEnd,
+    DocumentIdle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExecutionWorld {
+    Main,
+    UserScript,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisteredUserScript {
+    pub id: String,
+    pub matches: Option<Vec<MatchPattern>>,
+    pub exclude_matches: Option<Vec<MatchPattern>>,
+    pub include_globs: Option<Vec<String>>,
+    pub exclude_globs: Option<Vec<String>>,
+    pub js: Option<Vec<ScriptSource>>,
+    pub all_frames: Option<bool>,
+    pub run_at: Option<RunAt>,
+    pub world: Option<ExecutionWorld>,
+    pub world_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserScriptFilter {
+    pub ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldProperties {
+    pub world_id: Option<String>,
+    pub csp: Option<String>,
+    pub messaging: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectionTarget {
+    pub tab_id: i32,
+    pub frame_ids: Option<Vec<i32>>,
+    pub document_ids: Option<Vec<String>>,
+    pub all_frames: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserScriptInjection {
+    pub target: InjectionTarget,
+    pub js: Vec<ScriptSource>,
+    pub inject_immediately: Option<bool>,
+    pub world: Option<ExecutionWorld>,
+    pub world_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", bound = "T: DeserializeOwned")]
+pub struct InjectionResult<T> {
+    pub document_id: String,
+    pub frame_id: i32,
+    pub result: Option<T>,
+    pub error: Option<String>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "userScripts"], js_name = register)]
+    fn _register(scripts: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "userScripts"], js_name = getScripts)]
+    fn _get_scripts(filter: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "userScripts"], js_name = update)]
+    fn _update(scripts: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "userScripts"], js_name = unregister)]
+    fn _unregister(filter: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "userScripts"], js_name = configureWorld)]
+    fn _configure_world(properties: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "userScripts"], js_name = getWorldConfigurations)]
+    fn _get_world_configurations() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "userScripts"], js_name = resetWorldConfiguration)]
+    fn _reset_world_configuration(world_id: Option<&str>) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "userScripts"], js_name = execute)]
+    fn _execute(injection: JsValue) -> Promise;
+}
+
+pub async fn register(scripts: &[RegisteredUserScript]) -> Result<(), Error> {
+    resolve(_register(serde_wasm_bindgen::to_value(scripts)?)).await
+}
+
+pub async fn get_scripts(filter: &UserScriptFilter) -> Result<Vec<RegisteredUserScript>, Error> {
+    resolve(_get_scripts(serde_wasm_bindgen::to_value(filter)?)).await
+}
+
+pub async fn update(scripts: &[RegisteredUserScript]) -> Result<(), Error> {
+    resolve(_update(serde_wasm_bindgen::to_value(scripts)?)).await
+}
+
+pub async fn unregister(filter: &UserScriptFilter) -> Result<(), Error> {
+    resolve(_unregister(serde_wasm_bindgen::to_value(filter)?)).await
+}
+
+pub async fn configure_world(properties: &WorldProperties) -> Result<(), Error> {
+    resolve(_configure_world(serde_wasm_bindgen::to_value(properties)?)).await
+}
+
+pub async fn get_world_configurations() -> Result<Vec<WorldProperties>, Error> {
+    resolve(_get_world_configurations()).await
+}
+
+pub async fn reset_world_configuration(world_id: Option<&str>) -> Result<(), Error> {
+    resolve(_reset_world_configuration(world_id)).await
+}
+
+pub async fn execute<T: DeserializeOwned>(injection: &UserScriptInjection) -> Result<Vec<InjectionResult<T>>, Error> {
+    resolve(_execute(serde_wasm_bindgen::to_value(injection)?)).await
+}
@@ -0,0 +1,244 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::{resolve, to_array_buffer};
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VpnConnectionState {
+    Connected,
+    Failure,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlatformMessage {
+    Connected,
+    Disconnected,
+    Error,
+    LinkDown,
+    LinkChanged,
+    LinkUp,
+    Suspend,
+    Resume,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UIEvent {
+    ShowAddDialog,
+    ShowConfigureDialog,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// IP address in CIDR notation.
+    pub address: String,
+    pub broadcast_address: Option<String>,
+    pub mtu: Option<String>,
+    pub exclusion_list: Vec<String>,
+    pub inclusion_list: Vec<String>,
+    pub domain_search: Option<Vec<String>>,
+    pub dns_servers: Vec<String>,
+    pub reconnect: Option<String>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "vpnProvider"], js_name = createConfig)]
+    fn _create_config(name: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "vpnProvider"], js_name = destroyConfig)]
+    fn _destroy_config(id: &str) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "vpnProvider"], js_name = setParameters)]
+    fn _set_parameters(parameters: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "vpnProvider"], js_name = sendPacket)]
+    fn _send_packet(data: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "vpnProvider"], js_name = notifyConnectionStateChanged)]
+    fn _notify_connection_state_changed(state: JsValue) -> Promise;
+}
+
+/// Resolves to the ID of the new configuration.
+pub async fn create_config(name: &str) -> Result<String, Error> {
+    resolve(_create_config(name)).await
+}
+
+pub async fn destroy_config(id: &str) -> Result<(), Error> {
+    resolve(_destroy_config(id)).await
+}
+
+pub async fn set_parameters(parameters: &Parameters) -> Result<(), Error> {
+    resolve(_set_parameters(serde_wasm_bindgen::to_value(parameters)?)).await
+}
+
+pub async fn send_packet(data: &[u8]) -> Result<(), Error> {
+    resolve(_send_packet(to_array_buffer(data).into())).await
+}
+
+pub async fn notify_connection_state_changed(state: VpnConnectionState) -> Result<(), Error> {
+    resolve(_notify_connection_state_changed(serde_wasm_bindgen::to_value(&state)?)).await
+}
+
+pub mod on_packet_received {
+    use wasm_bindgen::prelude::*;
+    use crate::utils::from_array_buffer;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "vpnProvider", "onPacketReceived"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "vpnProvider"], js_name = onPacketReceived)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(Vec<u8>) + 'static,
+    {
+        Closure::wrap(Box::new(move |data: JsValue| {
+            callback(from_array_buffer(&data));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(Vec<u8>) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<Vec<u8>> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_platform_message {
+    use wasm_bindgen::prelude::*;
+    use super::PlatformMessage;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "vpnProvider", "onPlatformMessage"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, JsValue, String)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "vpnProvider"], js_name = onPlatformMessage)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(String, JsValue, String)>
+        where T: FnMut(String, PlatformMessage, String) + 'static,
+    {
+        Closure::wrap(Box::new(move |id, message: JsValue, error| {
+            callback(id, event_arg!(message), error);
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, PlatformMessage, String) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, PlatformMessage, String)> {
+        stream_of(|mut callback| listen(move |id, message, error| callback((id, message, error))))
+    }
+}
+
+pub mod on_config_removed {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "vpnProvider", "onConfigRemoved"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "vpnProvider"], js_name = onConfigRemoved)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(String)>
+        where T: FnMut(String) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<String> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_config_created {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "vpnProvider", "onConfigCreated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(String, String, JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "vpnProvider"], js_name = onConfigCreated)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(String, String, JsValue)>
+        where T: FnMut(String, String, JsValue) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(String, String, JsValue) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(String, String, JsValue)> {
+        stream_of(|mut callback| listen(move |id, name, data| callback((id, name, data))))
+    }
+}
+
+pub mod on_ui_event {
+    use wasm_bindgen::prelude::*;
+    use super::UIEvent;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "vpnProvider", "onUIEvent"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue, Option<String>)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "vpnProvider"], js_name = onUIEvent)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue, Option<String>)>
+        where T: FnMut(UIEvent, Option<String>) + 'static,
+    {
+        Closure::wrap(Box::new(move |event: JsValue, id| {
+            callback(event_arg!(event), id);
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(UIEvent, Option<String>) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<(UIEvent, Option<String>)> {
+        stream_of(|mut callback| listen(move |event, id| callback((event, id))))
+    }
+}
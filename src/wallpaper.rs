@@ -0,0 +1,65 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use js_sys::{ArrayBuffer, Promise, Reflect, Uint8Array};
+use serde::{Serialize, Deserialize};
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WallpaperLayout {
+    Stretch,
+    Center,
+    CenterCropped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WallpaperSource {
+    Url(String),
+    Data(Vec<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetWallpaperDetails {
+    pub source: WallpaperSource,
+    pub layout: WallpaperLayout,
+    pub filename: String,
+    pub thumbnail: Option<bool>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SetWallpaperFields<'a> {
+    url: Option<&'a str>,
+    layout: WallpaperLayout,
+    filename: &'a str,
+    thumbnail: Option<bool>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "wallpaper"], js_name = setWallpaper)]
+    fn _set_wallpaper(details: JsValue) -> Promise;
+}
+
+/// Resolves to the encoded thumbnail if `thumbnail` was requested.
+pub async fn set_wallpaper(details: &SetWallpaperDetails) -> Result<Option<Vec<u8>>, Error> {
+    let url = match &details.source {
+        WallpaperSource::Url(url) => Some(url.as_str()),
+        WallpaperSource::Data(_) => None,
+    };
+
+    let js_details = serde_wasm_bindgen::to_value(&SetWallpaperFields {
+        url,
+        layout: details.layout,
+        filename: &details.filename,
+        thumbnail: details.thumbnail,
+    })?;
+
+    if let WallpaperSource::Data(data) = &details.source {
+        Reflect::set(&js_details, &"data".into(), &Uint8Array::from(data.as_slice()).buffer())?;
+    }
+
+    let thumbnail = JsFuture::from(_set_wallpaper(js_details)).await?;
+
+    Ok(thumbnail.dyn_into::<ArrayBuffer>().ok().map(|buffer| Uint8Array::new(&buffer).to_vec()))
+}
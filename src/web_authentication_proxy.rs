@@ -0,0 +1,255 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DOMExceptionDetails {
+    pub name: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub request_id: i32,
+    /// `PublicKeyCredentialCreationOptions` serialized as JSON.
+    pub request_details_json: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRequest {
+    pub request_id: i32,
+    /// `PublicKeyCredentialRequestOptions` serialized as JSON.
+    pub request_details_json: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsUvpaaRequest {
+    pub request_id: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResponseDetails {
+    pub request_id: i32,
+    pub error: Option<DOMExceptionDetails>,
+    pub response_json: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetResponseDetails {
+    pub request_id: i32,
+    pub error: Option<DOMExceptionDetails>,
+    pub response_json: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsUvpaaResponseDetails {
+    pub request_id: i32,
+    pub is_uvpaa: bool,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "webAuthenticationProxy"], js_name = attach)]
+    fn _attach() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "webAuthenticationProxy"], js_name = detach)]
+    fn _detach() -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "webAuthenticationProxy"], js_name = completeCreateRequest)]
+    fn _complete_create_request(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "webAuthenticationProxy"], js_name = completeGetRequest)]
+    fn _complete_get_request(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "webAuthenticationProxy"], js_name = completeIsUvpaaRequest)]
+    fn _complete_is_uvpaa_request(details: JsValue) -> Promise;
+}
+
+/// Resolves to an error message if another extension is already attached.
+pub async fn attach() -> Result<Option<String>, Error> {
+    resolve(_attach()).await
+}
+
+pub async fn detach() -> Result<Option<String>, Error> {
+    resolve(_detach()).await
+}
+
+pub async fn complete_create_request(details: &CreateResponseDetails) -> Result<(), Error> {
+    resolve(_complete_create_request(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn complete_get_request(details: &GetResponseDetails) -> Result<(), Error> {
+    resolve(_complete_get_request(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn complete_is_uvpaa_request(details: &IsUvpaaResponseDetails) -> Result<(), Error> {
+    resolve(_complete_is_uvpaa_request(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub mod on_create_request {
+    use wasm_bindgen::prelude::*;
+    use super::CreateRequest;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webAuthenticationProxy", "onCreateRequest"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webAuthenticationProxy"], js_name = onCreateRequest)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(CreateRequest) + 'static,
+    {
+        Closure::wrap(Box::new(move |request_info: JsValue| {
+            callback(event_arg!(request_info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(CreateRequest) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<CreateRequest> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_get_request {
+    use wasm_bindgen::prelude::*;
+    use super::GetRequest;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webAuthenticationProxy", "onGetRequest"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webAuthenticationProxy"], js_name = onGetRequest)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(GetRequest) + 'static,
+    {
+        Closure::wrap(Box::new(move |request_info: JsValue| {
+            callback(event_arg!(request_info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(GetRequest) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<GetRequest> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_is_uvpaa_request {
+    use wasm_bindgen::prelude::*;
+    use super::IsUvpaaRequest;
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webAuthenticationProxy", "onIsUvpaaRequest"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webAuthenticationProxy"], js_name = onIsUvpaaRequest)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(IsUvpaaRequest) + 'static,
+    {
+        Closure::wrap(Box::new(move |request_info: JsValue| {
+            callback(event_arg!(request_info));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(IsUvpaaRequest) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<IsUvpaaRequest> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_remote_session_state_change {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webAuthenticationProxy", "onRemoteSessionStateChange"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut()>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webAuthenticationProxy"], js_name = onRemoteSessionStateChange)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut()>
+        where T: FnMut() + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut() + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<()> {
+        stream_of(|mut callback| listen(move || callback(())))
+    }
+}
+
+pub mod on_request_canceled {
+    use wasm_bindgen::prelude::*;
+    use crate::events::{Event, EventHandle, EventStream, stream_of};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webAuthenticationProxy", "onRequestCanceled"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(i32)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webAuthenticationProxy"], js_name = onRequestCanceled)]
+        static EVENT: Event;
+    }
+
+    pub fn create_listener<T>(callback: T) -> Closure<dyn FnMut(i32)>
+        where T: FnMut(i32) + 'static,
+    {
+        Closure::wrap(Box::new(callback))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(i32) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<i32> {
+        stream_of(listen)
+    }
+}
@@ -0,0 +1,481 @@
+use wasm_bindgen::prelude::*;
+use js_sys::Promise;
+use serde::{Serialize, Deserialize};
+use crate::events::UrlFilter;
+use crate::utils::resolve;
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionType {
+    Link,
+    Typed,
+    AutoBookmark,
+    AutoSubframe,
+    ManualSubframe,
+    Generated,
+    StartPage,
+    FormSubmit,
+    Reload,
+    Keyword,
+    KeywordGenerated,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionQualifier {
+    ClientRedirect,
+    ServerRedirect,
+    ForwardBack,
+    FromAddressBar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentLifecycle {
+    Prerender,
+    Active,
+    Cached,
+    PendingDeletion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrameType {
+    OutermostFrame,
+    FencedFrame,
+    SubFrame,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventFilter {
+    pub url: Vec<UrlFilter>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BeforeNavigateDetails {
+    pub tab_id: i32,
+    pub frame_id: i32,
+    pub parent_frame_id: i32,
+    pub url: String,
+    pub time_stamp: f64,
+    pub parent_document_id: Option<String>,
+    pub document_lifecycle: Option<DocumentLifecycle>,
+    pub frame_type: Option<FrameType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommittedDetails {
+    pub tab_id: i32,
+    pub frame_id: i32,
+    pub parent_frame_id: Option<i32>,
+    pub url: String,
+    pub time_stamp: f64,
+    pub transition_type: TransitionType,
+    #[serde(default)]
+    pub transition_qualifiers: Vec<TransitionQualifier>,
+    pub document_id: Option<String>,
+    pub parent_document_id: Option<String>,
+    pub document_lifecycle: Option<DocumentLifecycle>,
+    pub frame_type: Option<FrameType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NavigationDetails {
+    pub tab_id: i32,
+    pub frame_id: i32,
+    pub parent_frame_id: Option<i32>,
+    pub url: String,
+    pub time_stamp: f64,
+    pub document_id: Option<String>,
+    pub parent_document_id: Option<String>,
+    pub document_lifecycle: Option<DocumentLifecycle>,
+    pub frame_type: Option<FrameType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorOccurredDetails {
+    pub tab_id: i32,
+    pub frame_id: i32,
+    pub parent_frame_id: Option<i32>,
+    pub url: String,
+    pub time_stamp: f64,
+    pub error: String,
+    pub document_id: Option<String>,
+    pub parent_document_id: Option<String>,
+    pub document_lifecycle: Option<DocumentLifecycle>,
+    pub frame_type: Option<FrameType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFrameDetails {
+    pub tab_id: Option<i32>,
+    pub frame_id: Option<i32>,
+    pub document_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GetAllFramesDetails {
+    tab_id: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameDetails {
+    pub frame_id: Option<i32>,
+    pub parent_frame_id: i32,
+    pub url: String,
+    pub error_occurred: bool,
+    pub document_id: Option<String>,
+    pub parent_document_id: Option<String>,
+    pub document_lifecycle: Option<DocumentLifecycle>,
+    pub frame_type: Option<FrameType>,
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["chrome", "webNavigation"], js_name = getFrame)]
+    fn _get_frame(details: JsValue) -> Promise;
+
+    #[wasm_bindgen(js_namespace = ["chrome", "webNavigation"], js_name = getAllFrames)]
+    fn _get_all_frames(details: JsValue) -> Promise;
+}
+
+pub async fn get_frame(details: &GetFrameDetails) -> Result<Option<FrameDetails>, Error> {
+    resolve(_get_frame(serde_wasm_bindgen::to_value(details)?)).await
+}
+
+pub async fn get_all_frames(tab_id: i32) -> Result<Option<Vec<FrameDetails>>, Error> {
+    let details = GetAllFramesDetails { tab_id };
+
+    resolve(_get_all_frames(serde_wasm_bindgen::to_value(&details)?)).await
+}
+
+pub mod on_before_navigate {
+    use wasm_bindgen::prelude::*;
+    use crate::error::Error;
+    use super::{BeforeNavigateDetails, EventFilter};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onBeforeNavigate"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webNavigation"], js_name = onBeforeNavigate)]
+        static EVENT: Event;
+
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onBeforeNavigate"], js_name = addListener)]
+        fn _add_listener_with_filters(callback: &Closure<dyn FnMut(JsValue)>, filters: JsValue);
+    }
+
+    pub fn add_listener_with_filters(
+        callback: &Closure<dyn FnMut(JsValue)>,
+        filters: &EventFilter,
+    ) -> Result<(), Error> {
+        _add_listener_with_filters(callback, serde_wasm_bindgen::to_value(filters)?);
+
+        Ok(())
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(BeforeNavigateDetails) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(BeforeNavigateDetails) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<BeforeNavigateDetails> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_committed {
+    use wasm_bindgen::prelude::*;
+    use crate::error::Error;
+    use super::{CommittedDetails, EventFilter};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onCommitted"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webNavigation"], js_name = onCommitted)]
+        static EVENT: Event;
+
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onCommitted"], js_name = addListener)]
+        fn _add_listener_with_filters(callback: &Closure<dyn FnMut(JsValue)>, filters: JsValue);
+    }
+
+    pub fn add_listener_with_filters(
+        callback: &Closure<dyn FnMut(JsValue)>,
+        filters: &EventFilter,
+    ) -> Result<(), Error> {
+        _add_listener_with_filters(callback, serde_wasm_bindgen::to_value(filters)?);
+
+        Ok(())
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(CommittedDetails) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(CommittedDetails) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<CommittedDetails> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_dom_content_loaded {
+    use wasm_bindgen::prelude::*;
+    use crate::error::Error;
+    use super::{NavigationDetails, EventFilter};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onDOMContentLoaded"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webNavigation"], js_name = onDOMContentLoaded)]
+        static EVENT: Event;
+
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onDOMContentLoaded"], js_name = addListener)]
+        fn _add_listener_with_filters(callback: &Closure<dyn FnMut(JsValue)>, filters: JsValue);
+    }
+
+    pub fn add_listener_with_filters(
+        callback: &Closure<dyn FnMut(JsValue)>,
+        filters: &EventFilter,
+    ) -> Result<(), Error> {
+        _add_listener_with_filters(callback, serde_wasm_bindgen::to_value(filters)?);
+
+        Ok(())
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(NavigationDetails) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(NavigationDetails) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<NavigationDetails> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_completed {
+    use wasm_bindgen::prelude::*;
+    use crate::error::Error;
+    use super::{NavigationDetails, EventFilter};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onCompleted"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webNavigation"], js_name = onCompleted)]
+        static EVENT: Event;
+
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onCompleted"], js_name = addListener)]
+        fn _add_listener_with_filters(callback: &Closure<dyn FnMut(JsValue)>, filters: JsValue);
+    }
+
+    pub fn add_listener_with_filters(
+        callback: &Closure<dyn FnMut(JsValue)>,
+        filters: &EventFilter,
+    ) -> Result<(), Error> {
+        _add_listener_with_filters(callback, serde_wasm_bindgen::to_value(filters)?);
+
+        Ok(())
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(NavigationDetails) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(NavigationDetails) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<NavigationDetails> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_error_occurred {
+    use wasm_bindgen::prelude::*;
+    use crate::error::Error;
+    use super::{ErrorOccurredDetails, EventFilter};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onErrorOccurred"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webNavigation"], js_name = onErrorOccurred)]
+        static EVENT: Event;
+
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onErrorOccurred"], js_name = addListener)]
+        fn _add_listener_with_filters(callback: &Closure<dyn FnMut(JsValue)>, filters: JsValue);
+    }
+
+    pub fn add_listener_with_filters(
+        callback: &Closure<dyn FnMut(JsValue)>,
+        filters: &EventFilter,
+    ) -> Result<(), Error> {
+        _add_listener_with_filters(callback, serde_wasm_bindgen::to_value(filters)?);
+
+        Ok(())
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(ErrorOccurredDetails) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(ErrorOccurredDetails) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<ErrorOccurredDetails> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_history_state_updated {
+    use wasm_bindgen::prelude::*;
+    use crate::error::Error;
+    use super::{CommittedDetails, EventFilter};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onHistoryStateUpdated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webNavigation"], js_name = onHistoryStateUpdated)]
+        static EVENT: Event;
+
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onHistoryStateUpdated"], js_name = addListener)]
+        fn _add_listener_with_filters(callback: &Closure<dyn FnMut(JsValue)>, filters: JsValue);
+    }
+
+    pub fn add_listener_with_filters(
+        callback: &Closure<dyn FnMut(JsValue)>,
+        filters: &EventFilter,
+    ) -> Result<(), Error> {
+        _add_listener_with_filters(callback, serde_wasm_bindgen::to_value(filters)?);
+
+        Ok(())
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(CommittedDetails) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(CommittedDetails) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<CommittedDetails> {
+        stream_of(listen)
+    }
+}
+
+pub mod on_reference_fragment_updated {
+    use wasm_bindgen::prelude::*;
+    use crate::error::Error;
+    use super::{CommittedDetails, EventFilter};
+    use crate::events::{Event, EventHandle, EventStream, stream_of, event_arg};
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onReferenceFragmentUpdated"], js_name = addListener)]
+        pub fn add_listener(callback: &Closure<dyn FnMut(JsValue)>);
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = ["chrome", "webNavigation"], js_name = onReferenceFragmentUpdated)]
+        static EVENT: Event;
+
+        #[wasm_bindgen(js_namespace = ["chrome", "webNavigation", "onReferenceFragmentUpdated"], js_name = addListener)]
+        fn _add_listener_with_filters(callback: &Closure<dyn FnMut(JsValue)>, filters: JsValue);
+    }
+
+    pub fn add_listener_with_filters(
+        callback: &Closure<dyn FnMut(JsValue)>,
+        filters: &EventFilter,
+    ) -> Result<(), Error> {
+        _add_listener_with_filters(callback, serde_wasm_bindgen::to_value(filters)?);
+
+        Ok(())
+    }
+
+    pub fn create_listener<T>(mut callback: T) -> Closure<dyn FnMut(JsValue)>
+        where T: FnMut(CommittedDetails) + 'static,
+    {
+        Closure::wrap(Box::new(move |details: JsValue| {
+            callback(event_arg!(details));
+        }))
+    }
+
+    pub fn listen<T>(callback: T) -> EventHandle
+        where T: FnMut(CommittedDetails) + 'static,
+    {
+        EVENT.with(|event| EventHandle::new(event.clone(), create_listener(callback)))
+    }
+
+    pub fn stream() -> EventStream<CommittedDetails> {
+        stream_of(listen)
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Serialize, Deserialize};
+use crate::tabs::Tab;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WindowType {
+    Normal,
+    Popup,
+    Panel,
+    App,
+    Devtools,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WindowState {
+    Normal,
+    Minimized,
+    Maximized,
+    Fullscreen,
+    LockedFullscreen,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Window {
+    pub id: Option<i32>,
+    pub focused: bool,
+    pub incognito: bool,
+    pub always_on_top: bool,
+    pub top: Option<i32>,
+    pub left: Option<i32>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub tabs: Option<Vec<Tab>>,
+    #[serde(rename = "type")]
+    pub window_type: Option<WindowType>,
+    pub state: Option<WindowState>,
+    pub session_id: Option<String>,
+}
@@ -0,0 +1,68 @@
+#![cfg(all(feature = "runtime", feature = "tabs"))]
+
+use serde_json::json;
+use web_extension_sys::runtime::{ExtensionMessage, MessageSender};
+
+#[derive(Debug, PartialEq, ExtensionMessage)]
+#[serde(rename_all = "snake_case")]
+enum Message {
+    Ping,
+    #[serde(rename = "say")]
+    #[response(String)]
+    Echo {
+        #[serde(rename = "msg")]
+        text: String,
+    },
+    // Named like `dispatch`'s own arguments.
+    #[response(u32)]
+    Forward { handler: u32, sender: String },
+    Pair(i32, bool),
+}
+
+struct Handler;
+
+impl MessageHandler for Handler {
+    async fn ping(&self, _sender: MessageSender) {}
+
+    async fn echo(&self, text: String, _sender: MessageSender) -> String {
+        text
+    }
+
+    async fn forward(&self, handler: u32, _sender: String, _from: MessageSender) -> u32 {
+        handler
+    }
+
+    async fn pair(&self, _arg0: i32, _arg1: bool, _sender: MessageSender) {}
+}
+
+fn round_trip(message: Message, wire: serde_json::Value) {
+    assert_eq!(serde_json::to_value(&message).unwrap(), wire);
+    assert_eq!(serde_json::from_value::<Message>(wire).unwrap(), message);
+}
+
+#[test]
+fn variants_are_tagged_with_their_serde_names() {
+    round_trip(Message::Ping, json!({"type": "ping"}));
+    round_trip(Message::Pair(1, true), json!({"type": "pair", "payload": [1, true]}));
+}
+
+#[test]
+fn variant_and_field_renames_reach_the_wire() {
+    round_trip(Message::Echo { text: "hi".to_owned() }, json!({"type": "say", "payload": {"msg": "hi"}}));
+}
+
+#[test]
+fn fields_may_share_names_with_dispatch_arguments() {
+    let _ = Message::dispatch::<Handler>;
+
+    round_trip(
+        Message::Forward { handler: 1, sender: "tab".to_owned() },
+        json!({"type": "forward", "payload": {"handler": 1, "sender": "tab"}}),
+    );
+}
+
+#[test]
+fn other_payloads_are_rejected() {
+    assert!(serde_json::from_value::<Message>(json!({"type": "Ping"})).is_err());
+    assert!(serde_json::from_value::<Message>(json!({"type": "say", "payload": {"text": "hi"}})).is_err());
+}
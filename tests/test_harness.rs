@@ -0,0 +1,106 @@
+#![cfg(all(target_arch = "wasm32", feature = "test_harness"))]
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use futures_core::Stream;
+use js_sys::{Array, Object};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_extension_sys::error::Error;
+use web_extension_sys::future::sleep;
+use web_extension_sys::rpc::{self, Dispatch, Reply};
+use web_extension_sys::storage::{self, AreaName};
+use web_extension_sys::{runtime, test_harness};
+
+#[wasm_bindgen_test]
+async fn storage_calls_go_through_the_fake() {
+    test_harness::install();
+
+    storage::local::set(&HashMap::from([("count", 1)])).await.unwrap();
+    let count: i32 = storage::local::get_key("count").await.unwrap();
+
+    assert_eq!(count, 1);
+    assert_eq!(test_harness::calls("storage.local.set").len(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn install_clears_the_previous_test() {
+    test_harness::install();
+    storage::local::set(&HashMap::from([("count", 1)])).await.unwrap();
+
+    test_harness::install();
+
+    assert!(storage::local::get_key::<i32>("count").await.is_err());
+    assert!(test_harness::calls("storage.local.set").is_empty());
+}
+
+#[wasm_bindgen_test]
+fn events_reach_listeners_after_reinstalling() {
+    for _ in 0..2 {
+        test_harness::install();
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let log = seen.clone();
+        let _listener = storage::on_changed::listen(move |_, area_name| log.borrow_mut().push(area_name));
+
+        test_harness::emit("storage.onChanged", &Array::of2(&Object::new(), &"sync".into()));
+
+        assert_eq!(*seen.borrow(), vec![AreaName::Sync]);
+    }
+}
+
+#[wasm_bindgen_test]
+async fn runtime_send_message_resolves_to_the_response() {
+    test_harness::install();
+    test_harness::respond("runtime.sendMessage", &"pong".into());
+
+    let reply: String = runtime::send_message(&"ping").await.unwrap();
+
+    assert_eq!(reply, "pong");
+    assert_eq!(test_harness::calls("runtime.sendMessage")[0].get(0).as_string().unwrap(), "ping");
+}
+
+/// A stream that never yields, and records when the server drops it.
+struct Idle(Rc<Cell<bool>>);
+
+impl Stream for Idle {
+    type Item = Result<JsValue, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Pending
+    }
+}
+
+impl Drop for Idle {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
+struct IdleService(Rc<Cell<bool>>);
+
+impl Dispatch for IdleService {
+    fn dispatch(self: Rc<Self>, _method: &str, _payload: JsValue) -> Result<Reply, Error> {
+        Ok(Reply::Stream(Box::pin(Idle(self.0.clone()))))
+    }
+}
+
+#[wasm_bindgen_test]
+async fn cancelling_an_idle_stream_stops_it_on_the_server() {
+    test_harness::install();
+    let dropped = Rc::new(Cell::new(false));
+    let _server = rpc::serve("idle", IdleService(dropped.clone()));
+    let client = rpc::Client::new("idle");
+
+    let stream = client.call_stream::<_, u32>("ticks", &()).unwrap();
+    sleep(Duration::from_millis(20)).await;
+    assert!(!dropped.get());
+
+    drop(stream);
+    sleep(Duration::from_millis(20)).await;
+    assert!(dropped.get());
+}